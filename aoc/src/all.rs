@@ -0,0 +1,233 @@
+use std::any::Any;
+use std::fmt::Write as _;
+use std::panic::{self, AssertUnwindSafe};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use util::answers::{AnswerKey, Verdict};
+use util::{input, Part, Puzzle, Report, Timer};
+
+pub struct DayReport {
+    pub day: u8,
+    /// Everything that went wrong before the answers came back, including panics
+    pub outcome: Result<DayRun, String>,
+}
+
+pub struct DayRun {
+    pub parse: Duration,
+    pub parts: Vec<PartRun>,
+}
+
+pub struct PartRun {
+    pub part: Part,
+    pub answer: String,
+    pub elapsed: Duration,
+    pub verdict: Verdict,
+}
+
+impl DayReport {
+    pub fn failed(&self) -> bool {
+        match &self.outcome {
+            Ok(run) => run.parts.iter().any(|p| matches!(p.verdict, Verdict::Fail { .. })),
+            Err(_) => true,
+        }
+    }
+}
+
+/// Runs each day with its own input, one thread per day when `parallel` is set.
+pub fn run_all(puzzles: &[Puzzle], key: &AnswerKey, parallel: bool) -> Vec<DayReport> {
+    if !parallel {
+        return puzzles.iter().map(|p| run_day(p, key)).collect();
+    }
+
+    thread::scope(|scope| {
+        let handles = puzzles.iter()
+            .map(|p| scope.spawn(move || run_day(p, key)))
+            .collect::<Vec<_>>();
+
+        // run_day catches panics, so joining cannot fail
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    })
+}
+
+pub fn run_day(puzzle: &Puzzle, key: &AnswerKey) -> DayReport {
+    let outcome = input::load(None, puzzle.day(), puzzle.manifest_dir())
+        .map_err(|e| e.to_string())
+        .and_then(|input| solve_day(puzzle, &input, key));
+
+    DayReport { day: puzzle.day(), outcome }
+}
+
+pub fn solve_day(puzzle: &Puzzle, input: &str, key: &AnswerKey) -> Result<DayRun, String> {
+    let timer = Timer::with_report(Report::Quiet);
+
+    let answers = panic::catch_unwind(AssertUnwindSafe(|| puzzle.solve(input, &Part::all(), &timer)))
+        .map_err(|payload| format!("panicked: {}", panic_message(payload.as_ref())))?
+        .map_err(|e| e.to_string())?;
+
+    let records = timer.records();
+    let elapsed = |name: &str| records.iter()
+        .find(|r| r.name == name)
+        .map(|r| r.elapsed)
+        .unwrap_or_default();
+
+    Ok(DayRun {
+        parse: elapsed("parse"),
+        parts: answers.into_iter()
+            .map(|(part, answer)| PartRun {
+                part,
+                elapsed: elapsed(&format!("part {}", part)),
+                verdict: key.check(puzzle.day(), part, &answer),
+                answer,
+            })
+            .collect(),
+    })
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+const ANSWER_WIDTH: usize = 20;
+
+pub fn summary(reports: &[DayReport], total: Duration) -> String {
+    let mut table = String::new();
+    writeln!(table, "{:<4} {:<6} {:<ANSWER_WIDTH$} {:<8} {:>10}", "day", "part", "answer", "verdict", "ms").unwrap();
+
+    for report in reports {
+        match &report.outcome {
+            Ok(run) => {
+                writeln!(table, "{:<4} {:<6} {:<ANSWER_WIDTH$} {:<8} {:>10.3}", format!("{:02}", report.day), "parse", "", "", millis(run.parse)).unwrap();
+                for part in &run.parts {
+                    let verdict = match part.verdict {
+                        Verdict::Pass => "pass",
+                        Verdict::Fail { .. } => "FAIL",
+                        Verdict::Unknown => "?",
+                    };
+                    writeln!(table, "{:<4} {:<6} {:<ANSWER_WIDTH$} {:<8} {:>10.3}",
+                        "", part.part.to_string(), abbreviate(&part.answer), verdict, millis(part.elapsed)).unwrap();
+                }
+            },
+            Err(e) => writeln!(table, "{:02}   ERROR  {}", report.day, e).unwrap(),
+        }
+    }
+
+    let parts = reports.iter()
+        .filter_map(|r| r.outcome.as_ref().ok())
+        .map(|run| run.parts.len())
+        .sum::<usize>();
+    let failed = reports.iter().filter(|r| r.failed()).count();
+    write!(table, "Total {:.3} seconds for {} days, {} parts, {} failed", total.as_secs_f64(), reports.len(), parts, failed).unwrap();
+
+    table
+}
+
+/// First line of the answer, cut to fit the column.
+fn abbreviate(answer: &str) -> String {
+    let first_line = answer.lines().next().unwrap_or("");
+    if first_line.len() == answer.len() && answer.chars().count() <= ANSWER_WIDTH {
+        answer.to_string()
+    } else {
+        format!("{}...", first_line.chars().take(ANSWER_WIDTH - 3).collect::<String>())
+    }
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+/// Prints the summary and returns whether every day ran and matched its known answers.
+pub fn run(puzzles: &[Puzzle], key: &AnswerKey, parallel: bool) -> bool {
+    let start = Instant::now();
+    let reports = run_all(puzzles, key, parallel);
+
+    println!("{}", summary(&reports, start.elapsed()));
+    for report in reports.iter().filter(|r| r.failed()) {
+        if let Ok(run) = &report.outcome {
+            for part in &run.parts {
+                if let Verdict::Fail { expected } = &part.verdict {
+                    eprintln!("Day {:02} part {} answered {} but expected {}", report.day, part.part, part.answer, expected);
+                }
+            }
+        }
+    }
+
+    !reports.iter().any(DayReport::failed)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Display;
+
+    use util::{NoAnswer, ParseError, Solution};
+
+    use super::*;
+
+    struct Answers;
+
+    impl Solution for Answers {
+        const DAY: u8 = 24;
+        const MANIFEST_DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+        type Input = u32;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            input.trim().parse().map_err(|_| ParseError::new(input, "a number"))
+        }
+
+        fn part_1(input: &u32) -> Result<impl Display, NoAnswer> {
+            Ok(input + 1)
+        }
+
+        fn part_2(input: &u32) -> Result<impl Display, NoAnswer> {
+            if *input == 0 {
+                panic!("part 2 needs a positive number");
+            }
+            Ok("line one\nline two")
+        }
+    }
+
+    fn key() -> AnswerKey {
+        "[day_24]\npart_1 = 2\npart_2 = '''line one\nline two'''\n".parse().unwrap()
+    }
+
+    #[test]
+    fn verdicts() {
+        let puzzle = Puzzle::of::<Answers>();
+
+        let run = solve_day(&puzzle, "1", &key()).unwrap();
+        assert_eq!(vec![Verdict::Pass, Verdict::Pass], run.parts.iter().map(|p| p.verdict.clone()).collect::<Vec<_>>());
+
+        let report = DayReport { day: 24, outcome: solve_day(&puzzle, "5", &key()) };
+        assert!(report.failed());
+        assert!(summary(&[report], Duration::ZERO).contains("FAIL"));
+    }
+
+    #[test]
+    fn failures() {
+        let puzzle = Puzzle::of::<Answers>();
+
+        let panicked = solve_day(&puzzle, "0", &key()).err().unwrap();
+        assert_eq!("panicked: part 2 needs a positive number", panicked);
+
+        let invalid = solve_day(&puzzle, "x", &key()).err().unwrap();
+        assert!(invalid.contains("expected a number"));
+
+        let reports = run_all(&[puzzle], &key(), true);
+        assert!(reports[0].failed());
+        assert!(summary(&reports, Duration::ZERO).ends_with("1 days, 0 parts, 1 failed"));
+    }
+
+    #[test]
+    fn answers() {
+        assert_eq!("67622", abbreviate("67622"));
+        assert_eq!("line one...", abbreviate("line one\nline two"));
+        assert_eq!("abcdefghijklmnopq...", abbreviate("abcdefghijklmnopqrstuvwxyz"));
+    }
+}
@@ -0,0 +1,44 @@
+use util::Puzzle;
+
+pub fn puzzles() -> Vec<Puzzle> {
+    vec![
+        Puzzle::of::<day_01::Day01>(),
+        Puzzle::of::<day_02::Day02>(),
+        Puzzle::of::<day_03::Day03>(),
+        Puzzle::of::<day_04::Day04>(),
+        Puzzle::of::<day_05::Day05>(),
+        Puzzle::of::<day_06::Day06>(),
+        Puzzle::of::<day_08::Day08>(),
+        Puzzle::of::<day_09::Day09>(),
+        Puzzle::of::<day_10::Day10>(),
+        Puzzle::of::<day_11::Day11>(),
+        Puzzle::of::<day_12::Day12>(),
+        Puzzle::of::<day_13::Day13>(),
+        Puzzle::of::<day_16::Day16>(),
+    ]
+}
+
+pub fn find(day: u8) -> Option<Puzzle> {
+    puzzles().into_iter().find(|p| p.day() == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unique_days() {
+        let mut days = puzzles().iter().map(Puzzle::day).collect::<Vec<_>>();
+        let count = days.len();
+        days.dedup();
+
+        assert_eq!(count, days.len());
+        assert!(days.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn missing_day() {
+        assert!(find(9).is_some());
+        assert!(find(7).is_none());
+    }
+}
@@ -0,0 +1,433 @@
+mod all;
+mod days;
+mod scaffold;
+mod stress;
+mod watch;
+
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Duration;
+
+use clap::{Args, Parser, Subcommand};
+use util::answers::{AnswerKey, ANSWERS_FILE};
+use util::bench::{self, Baseline, BenchOptions};
+use util::cli::{self, RunArgs};
+use util::inputs::{HttpFetcher, InputCache};
+use util::viz::{Viewer, VizOptions};
+use util::{input, Part, Puzzle};
+
+#[derive(Parser, Debug)]
+#[command(about = "Advent of Code 2022 runner")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Solve one day of the calendar
+    Run {
+        /// Day of the calendar to solve
+        #[arg(short, long)]
+        day: u8,
+
+        #[command(flatten)]
+        run: RunArgs,
+    },
+    /// Re-run a day and its tests whenever its sources, fixtures or input change
+    Watch {
+        /// Day of the calendar to watch
+        #[arg(short, long)]
+        day: u8,
+
+        /// Only run this part of the puzzle (runs both when omitted)
+        #[arg(short, long)]
+        part: Option<Part>,
+
+        /// Puzzle input file to run against and watch
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+
+        /// Milliseconds between checks for changes
+        #[arg(long, default_value_t = 500)]
+        interval: u64,
+
+        /// Don't run the day's tests after each change
+        #[arg(long)]
+        no_tests: bool,
+    },
+    /// Watch a day's simulation play out in the terminal, one frame at a time
+    Viz {
+        /// Day of the calendar to watch
+        #[arg(short, long)]
+        day: u8,
+
+        /// Puzzle input file (defaults to the day's input)
+        #[arg(short, long)]
+        input: Option<String>,
+
+        /// Frames per second, as fast as possible when 0
+        #[arg(long, default_value_t = VizOptions::default().fps)]
+        fps: f64,
+
+        /// Start paused, stepping a frame each time Enter is pressed
+        #[arg(long)]
+        paused: bool,
+
+        /// Print frames one after another without colours, for terminals without ANSI support
+        #[arg(long)]
+        plain: bool,
+    },
+    /// Draw a day's result as a PNG or PPM image
+    Image {
+        /// Day of the calendar to draw
+        #[arg(short, long)]
+        day: u8,
+
+        /// Puzzle input file (defaults to the day's input)
+        #[arg(short, long)]
+        input: Option<String>,
+
+        /// Image file to write, a PNG or PPM going by its extension
+        #[arg(short, long)]
+        output: PathBuf,
+
+        /// Width and height in pixels of each cell
+        #[arg(long, default_value_t = 4)]
+        scale: usize,
+    },
+    /// Solve every day and summarise the answers and timings
+    All {
+        /// Run the days on separate threads
+        #[arg(short, long)]
+        parallel: bool,
+
+        /// Answers file to check against (defaults to answers.toml at the workspace root)
+        #[arg(long)]
+        answers: Option<PathBuf>,
+    },
+    /// Print a random input for a day, generated from a seed
+    Generate {
+        /// Day of the calendar to generate an input for
+        #[arg(short, long)]
+        day: u8,
+
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+
+        /// Roughly how many items the input holds (lines, trees, moves, ...)
+        #[arg(long, default_value_t = 100)]
+        scale: usize,
+    },
+    /// Solve generated inputs to look for panics and scales where a day gets slow
+    Stress(StressArgs),
+    /// Time parsing and both parts of each day over repeated runs
+    Bench(BenchArgs),
+    /// List the days that have solutions
+    List,
+    /// Download puzzle inputs into the inputs directory, skipping any already there
+    Fetch {
+        /// Days to fetch, every solved day when omitted
+        #[arg(short, long)]
+        day: Vec<u8>,
+
+        /// Where to cache inputs (defaults to $AOC_INPUTS, then inputs/ in the workspace)
+        #[arg(long)]
+        dir: Option<PathBuf>,
+    },
+    /// Create a crate for a new day and register it with the runner
+    New {
+        /// Day of the calendar to start
+        #[arg(short, long)]
+        day: u8,
+    },
+}
+
+#[derive(Args, Debug)]
+struct StressArgs {
+    /// Days to stress, every day with a generator when omitted
+    #[arg(short, long)]
+    day: Vec<u8>,
+
+    /// Seed of the first input at each scale
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+
+    /// Inputs to generate at each scale
+    #[arg(short = 'n', long, default_value_t = 5)]
+    runs: u64,
+
+    /// Sizes of input to generate, repeat to compare how the days scale
+    #[arg(long, default_values_t = [10, 100])]
+    scale: Vec<usize>,
+
+    /// Save the inputs that fail to this directory
+    #[arg(long)]
+    save_failures: Option<PathBuf>,
+}
+
+#[derive(Args, Debug)]
+struct BenchArgs {
+    /// Days to benchmark, all of them when omitted
+    #[arg(short, long)]
+    day: Vec<u8>,
+
+    /// Timed runs of each phase
+    #[arg(short = 'n', long, default_value_t = BenchOptions::default().iterations)]
+    iterations: usize,
+
+    /// Untimed runs of each phase before timing starts
+    #[arg(short, long, default_value_t = BenchOptions::default().warmup)]
+    warmup: usize,
+
+    /// Compare the medians against a baseline saved by an earlier run
+    #[arg(short, long)]
+    baseline: Option<PathBuf>,
+
+    /// Save the medians of this run as a baseline
+    #[arg(long)]
+    save_baseline: Option<PathBuf>,
+
+    /// Percentage slowdown against the baseline that counts as a regression
+    #[arg(short, long, default_value_t = 10.0)]
+    threshold: f64,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run { day, run } => {
+            let Some(puzzle) = days::find(day) else {
+                eprintln!("Day {} has not been solved yet", day);
+                return ExitCode::FAILURE;
+            };
+            if let Err(e) = cli::run(&puzzle, &run) {
+                eprintln!("Failed to run day {}: {}", day, e);
+                return ExitCode::FAILURE;
+            }
+        },
+        Command::Watch { day, part, input, interval, no_tests } => {
+            let options = watch::WatchOptions { day, part, input, interval: Duration::from_millis(interval), tests: !no_tests };
+            if let Err(e) = watch::watch(&scaffold::workspace_root(), &options) {
+                eprintln!("Failed to watch day {}: {}", day, e);
+                return ExitCode::FAILURE;
+            }
+        },
+        Command::Viz { day, input, fps, paused, plain } => {
+            let Some(puzzle) = days::find(day) else {
+                eprintln!("Day {} has not been solved yet", day);
+                return ExitCode::FAILURE;
+            };
+            let options = VizOptions { fps, paused, ansi: !plain };
+            match viz(&puzzle, input.as_deref(), options) {
+                Ok(true) => {},
+                Ok(false) => {
+                    eprintln!("Day {} has no animation", day);
+                    return ExitCode::FAILURE;
+                },
+                Err(e) => {
+                    eprintln!("Failed to animate day {}: {}", day, e);
+                    return ExitCode::FAILURE;
+                },
+            }
+        },
+        Command::Image { day, input, output, scale } => {
+            let Some(puzzle) = days::find(day) else {
+                eprintln!("Day {} has not been solved yet", day);
+                return ExitCode::FAILURE;
+            };
+            match image(&puzzle, input.as_deref(), &output, scale) {
+                Ok(true) => println!("Wrote {}", output.display()),
+                Ok(false) => {
+                    eprintln!("Day {} has no image", day);
+                    return ExitCode::FAILURE;
+                },
+                Err(e) => {
+                    eprintln!("Failed to draw day {}: {}", day, e);
+                    return ExitCode::FAILURE;
+                },
+            }
+        },
+        Command::All { parallel, answers } => {
+            let path = answers.unwrap_or_else(|| scaffold::workspace_root().join(ANSWERS_FILE));
+            let key = match AnswerKey::load(&path) {
+                Ok(key) => key,
+                Err(e) => {
+                    eprintln!("Failed to load answers: {}", e);
+                    return ExitCode::FAILURE;
+                },
+            };
+            if !all::run(&days::puzzles(), &key, parallel) {
+                return ExitCode::FAILURE;
+            }
+        },
+        Command::Generate { day, seed, scale } => {
+            let Some(puzzle) = days::find(day) else {
+                eprintln!("Day {} has not been solved yet", day);
+                return ExitCode::FAILURE;
+            };
+            match puzzle.generate(seed, scale) {
+                Some(input) => print!("{}", input),
+                None => {
+                    eprintln!("Day {} has no input generator", day);
+                    return ExitCode::FAILURE;
+                },
+            }
+        },
+        Command::Stress(args) => {
+            match run_stress(args) {
+                Ok(true) => {},
+                Ok(false) => return ExitCode::FAILURE,
+                Err(e) => {
+                    eprintln!("Failed to stress test: {}", e);
+                    return ExitCode::FAILURE;
+                },
+            }
+        },
+        Command::Bench(args) => {
+            match run_bench(&args) {
+                Ok(true) => {},
+                Ok(false) => return ExitCode::FAILURE,
+                Err(e) => {
+                    eprintln!("Failed to benchmark: {}", e);
+                    return ExitCode::FAILURE;
+                },
+            }
+        },
+        Command::Fetch { day, dir } => {
+            if let Err(e) = fetch(day, dir) {
+                eprintln!("Failed to fetch: {}", e);
+                return ExitCode::FAILURE;
+            }
+        },
+        Command::New { day } => {
+            match scaffold::new_day(&scaffold::workspace_root(), day) {
+                Ok(changed) => {
+                    for path in changed {
+                        println!("Wrote {}", path.display());
+                    }
+                },
+                Err(e) => {
+                    eprintln!("Failed to create day {}: {}", day, e);
+                    return ExitCode::FAILURE;
+                },
+            }
+        },
+        Command::List => {
+            for puzzle in days::puzzles() {
+                println!("Day {:02}", puzzle.day());
+            }
+        },
+    }
+
+    ExitCode::SUCCESS
+}
+
+/// The requested days, or every day when none were.
+fn select_days(days: &[u8]) -> Result<Vec<Puzzle>, String> {
+    if days.is_empty() {
+        return Ok(days::puzzles());
+    }
+    days.iter()
+        .map(|day| days::find(*day).ok_or_else(|| format!("day {} has not been solved yet", day)))
+        .collect()
+}
+
+/// Returns false when the day has no animation.
+fn viz(puzzle: &Puzzle, path: Option<&str>, options: VizOptions) -> Result<bool, Box<dyn Error>> {
+    let input = input::load(path, puzzle.day(), puzzle.manifest_dir())?;
+    Ok(puzzle.animate(&input, &mut Viewer::terminal(options))?)
+}
+
+/// Returns false when the day has no image.
+fn image(puzzle: &Puzzle, path: Option<&str>, output: &Path, scale: usize) -> Result<bool, Box<dyn Error>> {
+    let input = input::load(path, puzzle.day(), puzzle.manifest_dir())?;
+    let Some(image) = puzzle.image(&input)? else {
+        return Ok(false);
+    };
+    image.scaled(scale.max(1)).save(output)?;
+    Ok(true)
+}
+
+/// Returns false when any generated input failed to solve.
+fn run_stress(args: StressArgs) -> Result<bool, Box<dyn Error>> {
+    let options = stress::StressOptions {
+        seed: args.seed,
+        runs: args.runs,
+        scales: args.scale,
+        save_failures: args.save_failures,
+    };
+    let runs = stress::stress(&select_days(&args.day)?, &options);
+    println!("{}", stress::report(&runs));
+
+    if let Some(dir) = &options.save_failures {
+        if runs.iter().any(|r| r.outcome.is_err()) {
+            println!("Failing inputs saved to {}", dir.display());
+        }
+    }
+
+    Ok(runs.iter().all(|r| r.outcome.is_ok()))
+}
+
+/// Returns false when a measurement regressed past the threshold.
+fn run_bench(args: &BenchArgs) -> Result<bool, Box<dyn Error>> {
+    let puzzles = select_days(&args.day)?;
+    let baseline = args.baseline.as_deref().map(Baseline::load).transpose()?;
+    let options = BenchOptions { warmup: args.warmup, iterations: args.iterations };
+
+    let mut measurements = vec![];
+    for puzzle in puzzles {
+        let input = match input::load(None, puzzle.day(), puzzle.manifest_dir()) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("Skipping day {:02}: {}", puzzle.day(), e);
+                continue;
+            },
+        };
+        measurements.extend(puzzle.bench(&input, &Part::all(), &options)?);
+    }
+
+    print!("{}", bench::report(&measurements, baseline.as_ref()));
+
+    if let Some(path) = &args.save_baseline {
+        Baseline::from_measurements(&measurements).save(path)?;
+    }
+
+    let Some(baseline) = baseline else {
+        return Ok(true);
+    };
+    let regressions = baseline.regressions(&measurements, args.threshold / 100.0);
+    for m in &regressions {
+        eprintln!("Regression: {} is more than {}% slower than the baseline", m.name, args.threshold);
+    }
+
+    Ok(regressions.is_empty())
+}
+
+fn fetch(days: Vec<u8>, dir: Option<PathBuf>) -> Result<(), Box<dyn Error>> {
+    let dir = dir
+        .or_else(|| std::env::var_os(input::INPUTS_DIR_VAR).map(PathBuf::from))
+        .unwrap_or_else(|| scaffold::workspace_root().join("inputs"));
+    let days = if days.is_empty() {
+        days::puzzles().iter().map(Puzzle::day).collect()
+    } else {
+        days
+    };
+
+    let cache = InputCache::new(&dir, HttpFetcher::from_env()?);
+    for day in days {
+        if cache.is_cached(day) {
+            println!("Day {:02} already cached", day);
+            continue;
+        }
+        cache.get(day)?;
+        println!("Day {:02} saved to {}", day, cache.path(day).display());
+    }
+    if std::env::var_os(input::INPUTS_DIR_VAR).is_none() {
+        println!("Set {}={} to run against these inputs", input::INPUTS_DIR_VAR, cache.dir().display());
+    }
+
+    Ok(())
+}
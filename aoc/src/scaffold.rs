@@ -0,0 +1,230 @@
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The workspace this binary was built from.
+pub fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap().to_path_buf()
+}
+
+/// Creates `day_XX` with a `Solution` stub and an empty fixture, then registers it with the
+/// workspace and the `aoc` runner. Returns the files that were created or changed.
+pub fn new_day(root: &Path, day: u8) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("day {} is not in the calendar (1-25)", day).into());
+    }
+    let name = format!("day_{:02}", day);
+    let dir = root.join(&name);
+    if dir.exists() {
+        return Err(format!("'{}' already exists", dir.display()).into());
+    }
+
+    let files = [
+        (dir.join("Cargo.toml"), cargo_toml(&name)),
+        (dir.join("src/lib.rs"), lib_rs(day)),
+        (dir.join("src/main.rs"), main_rs(day)),
+        (dir.join("fixtures/example.txt"), String::new()),
+        (dir.join("fixtures/example.toml"), EXAMPLE_TOML.to_string()),
+    ];
+    let mut changed = vec![];
+    for (path, contents) in files {
+        fs::create_dir_all(path.parent().unwrap())?;
+        fs::write(&path, contents)?;
+        changed.push(path);
+    }
+
+    let registrations = [
+        (root.join("Cargo.toml"), register_member as fn(&str, u8) -> Result<String, String>),
+        (root.join("aoc/Cargo.toml"), register_dependency),
+        (root.join("aoc/src/days.rs"), register_puzzle),
+    ];
+    for (path, register) in registrations {
+        let text = fs::read_to_string(&path)?;
+        let updated = register(&text, day).map_err(|e| format!("{}: {}", path.display(), e))?;
+        if updated != text {
+            fs::write(&path, updated)?;
+            changed.push(path);
+        }
+    }
+
+    Ok(changed)
+}
+
+fn cargo_toml(name: &str) -> String {
+    format!(r#"[package]
+name = "{}"
+version = "0.1.0"
+edition = "2021"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+util = {{ path = "../util", version = "0.0.0" }}
+"#, name)
+}
+
+fn lib_rs(day: u8) -> String {
+    format!(r#"//! Day {day}.
+
+use std::fmt::Display;
+
+use util::{{NoAnswer, ParseError, Solution}};
+
+pub struct Day{day:02};
+
+impl Solution for Day{day:02} {{
+    const DAY: u8 = {day};
+    const MANIFEST_DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {{
+        Ok(input.lines().map(str::to_string).collect())
+    }}
+
+    fn part_1(_input: &Self::Input) -> Result<impl Display, NoAnswer> {{
+        Ok("unsolved")
+    }}
+
+    fn part_2(_input: &Self::Input) -> Result<impl Display, NoAnswer> {{
+        Ok("unsolved")
+    }}
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+
+    #[test]
+    fn fixtures() {{
+        util::fixtures::assert_fixtures(util::Puzzle::of::<Day{day:02}>());
+    }}
+}}
+"#)
+}
+
+fn main_rs(day: u8) -> String {
+    format!(r#"use std::process::ExitCode;
+
+use day_{day:02}::Day{day:02};
+use util::Puzzle;
+
+fn main() -> ExitCode {{
+    util::cli::main(Puzzle::of::<Day{day:02}>())
+}}
+"#)
+}
+
+const EXAMPLE_TOML: &str = "# Paste the puzzle's example into example.txt and uncomment its answers.
+# part_1 = 0
+# part_2 = 0
+";
+
+/// Adds the crate to an explicit `members` list; a `day_*` glob already covers it.
+fn register_member(text: &str, day: u8) -> Result<String, String> {
+    let Some(start) = text.find("members = [") else {
+        return Err("no workspace members list".to_string());
+    };
+    let end = start + text[start..].find(']').ok_or("unterminated members list")?;
+    let members = &text[start + "members = [".len()..end];
+    let name = format!("\"day_{:02}\"", day);
+    if members.contains("\"day_*\"") || members.contains(&name) {
+        return Ok(text.to_string());
+    }
+
+    let separator = if members.trim().is_empty() { "" } else { ", " };
+    Ok(format!("{}{}{}{}", &text[..end].trim_end(), separator, name, &text[end..]))
+}
+
+fn register_dependency(text: &str, day: u8) -> Result<String, String> {
+    let line = format!("day_{:02} = {{ path = \"../day_{:02}\" }}", day, day);
+    insert_sorted(text, &line, day, |l| {
+        l.strip_prefix("day_")?.get(..2)?.parse().ok()
+    }).ok_or_else(|| "no day dependencies to add to".to_string())
+}
+
+fn register_puzzle(text: &str, day: u8) -> Result<String, String> {
+    let line = format!("        Puzzle::of::<day_{:02}::Day{:02}>(),", day, day);
+    insert_sorted(text, &line, day, |l| {
+        l.trim_start().strip_prefix("Puzzle::of::<day_")?.get(..2)?.parse().ok()
+    }).ok_or_else(|| "no puzzle list to add to".to_string())
+}
+
+/// Inserts `line` among the lines that `day_of` recognises, keeping them in day order.
+fn insert_sorted(text: &str, line: &str, day: u8, day_of: impl Fn(&str) -> Option<u8>) -> Option<String> {
+    let mut lines = text.lines().collect::<Vec<_>>();
+    let days = lines.iter()
+        .enumerate()
+        .filter_map(|(i, l)| day_of(l).map(|d| (i, d)))
+        .collect::<Vec<_>>();
+    if days.iter().any(|(_, d)| *d == day) {
+        return Some(text.to_string());
+    }
+
+    let index = days.iter()
+        .find(|(_, d)| *d > day)
+        .map(|(i, _)| *i)
+        .or_else(|| days.last().map(|(i, _)| i + 1))?;
+    lines.insert(index, line);
+
+    let mut updated = lines.join("\n");
+    if text.ends_with('\n') {
+        updated.push('\n');
+    }
+    Some(updated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAYS_RS: &str = "pub fn puzzles() -> Vec<Puzzle> {
+    vec![
+        Puzzle::of::<day_01::Day01>(),
+        Puzzle::of::<day_13::Day13>(),
+    ]
+}
+";
+
+    #[test]
+    fn registration() {
+        assert_eq!(
+            "[workspace]\nmembers = [\"aoc\", \"util\", \"day_07\"]\n",
+            register_member("[workspace]\nmembers = [\"aoc\", \"util\"]\n", 7).unwrap()
+        );
+        let glob = "[workspace]\nmembers = [\"aoc\", \"day_*\", \"util\"]\n";
+        assert_eq!(glob, register_member(glob, 7).unwrap());
+
+        let deps = "[dependencies]\nutil = { path = \"../util\" }\nday_01 = { path = \"../day_01\" }\nday_13 = { path = \"../day_13\" }\n";
+        assert_eq!(
+            "[dependencies]\nutil = { path = \"../util\" }\nday_01 = { path = \"../day_01\" }\nday_07 = { path = \"../day_07\" }\nday_13 = { path = \"../day_13\" }\n",
+            register_dependency(deps, 7).unwrap()
+        );
+        assert!(register_dependency(deps, 14).unwrap().ends_with("day_13 = { path = \"../day_13\" }\nday_14 = { path = \"../day_14\" }\n"));
+
+        let days = register_puzzle(DAYS_RS, 7).unwrap();
+        assert!(days.contains("Day01>(),\n        Puzzle::of::<day_07::Day07>(),\n        Puzzle::of::<day_13::Day13>(),"));
+        assert_eq!(days, register_puzzle(&days, 7).unwrap());
+    }
+
+    #[test]
+    fn scaffold() {
+        let root = std::env::temp_dir().join(format!("aoc_scaffold_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("aoc/src")).unwrap();
+        fs::write(root.join("Cargo.toml"), "[workspace]\nmembers = [\"aoc\", \"day_*\", \"util\"]\n").unwrap();
+        fs::write(root.join("aoc/Cargo.toml"), "[dependencies]\nday_01 = { path = \"../day_01\" }\n").unwrap();
+        fs::write(root.join("aoc/src/days.rs"), DAYS_RS).unwrap();
+
+        let changed = new_day(&root, 14).unwrap();
+        assert_eq!(7, changed.len());
+        assert!(fs::read_to_string(root.join("day_14/src/lib.rs")).unwrap().contains("impl Solution for Day14 {\n    const DAY: u8 = 14;"));
+        assert!(fs::read_to_string(root.join("day_14/src/main.rs")).unwrap().contains("use day_14::Day14;"));
+        assert!(fs::read_to_string(root.join("aoc/src/days.rs")).unwrap().contains("Puzzle::of::<day_14::Day14>()"));
+
+        assert!(new_day(&root, 14).is_err());
+        assert!(new_day(&root, 26).is_err());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,158 @@
+use std::error::Error;
+use std::fmt::Write as _;
+use std::fs;
+use std::panic;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use util::Puzzle;
+use util::answers::AnswerKey;
+
+use crate::all::{self, DayRun};
+
+pub struct StressOptions {
+    pub seed: u64,
+    /// Inputs generated at each scale, seeded `seed`, `seed + 1`, ...
+    pub runs: u64,
+    pub scales: Vec<usize>,
+    /// Where to keep the inputs that failed, so they can be run again by hand
+    pub save_failures: Option<PathBuf>,
+}
+
+pub struct StressRun {
+    pub day: u8,
+    pub seed: u64,
+    pub scale: usize,
+    pub bytes: usize,
+    pub outcome: Result<DayRun, String>,
+}
+
+/// Generates inputs for each day that has a generator and solves them, catching panics.
+pub fn stress(puzzles: &[Puzzle], options: &StressOptions) -> Vec<StressRun> {
+    // the panic message ends up in the report, so don't also print it as it happens
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let key = AnswerKey::default();
+    let mut runs = vec![];
+    for puzzle in puzzles {
+        for &scale in &options.scales {
+            for seed in options.seed..options.seed + options.runs {
+                let Some(input) = puzzle.generate(seed, scale) else {
+                    continue;
+                };
+                runs.push(StressRun {
+                    day: puzzle.day(),
+                    seed,
+                    scale,
+                    bytes: input.len(),
+                    outcome: all::solve_day(puzzle, &input, &key),
+                });
+                if let (Some(dir), Err(_)) = (&options.save_failures, &runs.last().unwrap().outcome) {
+                    if let Err(e) = save_failure(dir, puzzle.day(), seed, scale, &input) {
+                        eprintln!("Failed to save the input for day {:02} seed {}: {}", puzzle.day(), seed, e);
+                    }
+                }
+            }
+        }
+    }
+
+    panic::set_hook(hook);
+    runs
+}
+
+fn save_failure(dir: &Path, day: u8, seed: u64, scale: usize, input: &str) -> Result<(), Box<dyn Error>> {
+    fs::create_dir_all(dir)?;
+    fs::write(dir.join(failure_file(day, seed, scale)), input)?;
+    Ok(())
+}
+
+pub fn failure_file(day: u8, seed: u64, scale: usize) -> String {
+    format!("day_{:02}_seed_{}_scale_{}.txt", day, seed, scale)
+}
+
+pub fn report(runs: &[StressRun]) -> String {
+    let mut table = String::new();
+    writeln!(table, "{:<4} {:>6} {:>8} {:>10} {:>10} {:>10} {:>10}  result", "day", "seed", "scale", "bytes", "parse ms", "part 1 ms", "part 2 ms").unwrap();
+
+    for run in runs {
+        write!(table, "{:<4} {:>6} {:>8} {:>10}", format!("{:02}", run.day), run.seed, run.scale, run.bytes).unwrap();
+        match &run.outcome {
+            Ok(day) => {
+                let part = |index: usize| day.parts.get(index).map_or(Duration::ZERO, |p| p.elapsed);
+                writeln!(table, " {:>10.3} {:>10.3} {:>10.3}  ok", millis(day.parse), millis(part(0)), millis(part(1))).unwrap();
+            },
+            Err(e) => writeln!(table, " {:>10} {:>10} {:>10}  {}", "", "", "", e.lines().next().unwrap_or("")).unwrap(),
+        }
+    }
+
+    let failed = runs.iter().filter(|r| r.outcome.is_err()).count();
+    write!(table, "{} runs, {} failed", runs.len(), failed).unwrap();
+
+    table
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Display;
+
+    use util::{NoAnswer, ParseError, Rng, Solution};
+
+    use super::*;
+
+    /// Panics on inputs that sum to a multiple of 7, like a bug only some inputs hit.
+    struct Fragile;
+
+    impl Solution for Fragile {
+        const DAY: u8 = 24;
+        const MANIFEST_DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+        type Input = Vec<u64>;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            input.lines().map(|n| util::parse_number(n, 1, "a number")).collect()
+        }
+
+        fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+            Some((0..scale).map(|_| format!("{}\n", rng.below(100))).collect())
+        }
+
+        fn part_1(input: &Vec<u64>) -> Result<impl Display, NoAnswer> {
+            let sum = input.iter().sum::<u64>();
+            assert!(!sum.is_multiple_of(7), "unlucky sum {}", sum);
+            Ok(sum)
+        }
+
+        fn part_2(input: &Vec<u64>) -> Result<impl Display, NoAnswer> {
+            Ok(input.len())
+        }
+    }
+
+    #[test]
+    fn finds_failures() {
+        let dir = std::env::temp_dir().join(format!("aoc_stress_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let options = StressOptions { seed: 0, runs: 20, scales: vec![1, 10], save_failures: Some(dir.clone()) };
+
+        let runs = stress(&[Puzzle::of::<Fragile>()], &options);
+        assert_eq!(40, runs.len());
+
+        let failures = runs.iter().filter(|r| r.outcome.is_err()).collect::<Vec<_>>();
+        assert!(!failures.is_empty());
+        for failure in &failures {
+            let saved = fs::read_to_string(dir.join(failure_file(24, failure.seed, failure.scale))).unwrap();
+            assert_eq!(Some(saved), Puzzle::of::<Fragile>().generate(failure.seed, failure.scale));
+            assert!(failure.outcome.as_ref().err().unwrap().starts_with("panicked: unlucky sum"));
+        }
+
+        let report = report(&runs);
+        assert!(report.ends_with(&format!("40 runs, {} failed", failures.len())));
+        assert!(report.contains("unlucky sum"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
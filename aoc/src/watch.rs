@@ -0,0 +1,255 @@
+use std::collections::{BTreeMap, HashMap};
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use util::{input, Part};
+
+pub struct WatchOptions {
+    pub day: u8,
+    pub part: Option<Part>,
+    pub input: Option<PathBuf>,
+    /// How often to look for changes
+    pub interval: Duration,
+    pub tests: bool,
+}
+
+/// Modification time and length of every file being watched, `None` while a file is missing.
+pub type Snapshot = BTreeMap<PathBuf, Option<(SystemTime, u64)>>;
+
+/// The day's sources, fixtures and manifest, plus every place its input could come from.
+pub fn watched_paths(root: &Path, day: u8, input: Option<&Path>) -> Vec<PathBuf> {
+    let dir = root.join(format!("day_{:02}", day));
+    let mut paths = vec![dir.join("src"), dir.join("fixtures"), dir.join("Cargo.toml")];
+    match input {
+        Some(input) => paths.push(input.to_path_buf()),
+        None => {
+            if let Some(inputs_dir) = env::var_os(input::INPUTS_DIR_VAR) {
+                paths.push(PathBuf::from(inputs_dir).join(format!("day_{:02}.txt", day)));
+            }
+            paths.push(dir.join("input.txt"));
+        },
+    }
+
+    paths
+}
+
+pub fn snapshot(paths: &[PathBuf]) -> Snapshot {
+    let mut snapshot = Snapshot::new();
+    for path in paths {
+        add_to_snapshot(&mut snapshot, path);
+    }
+    snapshot
+}
+
+fn add_to_snapshot(snapshot: &mut Snapshot, path: &Path) {
+    let Ok(metadata) = fs::metadata(path) else {
+        snapshot.insert(path.to_path_buf(), None);
+        return;
+    };
+    if metadata.is_dir() {
+        for entry in fs::read_dir(path).into_iter().flatten().flatten() {
+            add_to_snapshot(snapshot, &entry.path());
+        }
+    } else {
+        snapshot.insert(path.to_path_buf(), metadata.modified().ok().map(|m| (m, metadata.len())));
+    }
+}
+
+/// Files that were added, removed or modified between two snapshots.
+pub fn changed<'a>(before: &'a Snapshot, after: &'a Snapshot) -> Vec<&'a Path> {
+    let mut changed = after.iter()
+        .filter(|(path, state)| before.get(*path) != Some(state))
+        .map(|(path, _)| path.as_path())
+        .collect::<Vec<_>>();
+    changed.extend(before.keys().filter(|path| !after.contains_key(*path)).map(PathBuf::as_path));
+    changed
+}
+
+/// Picks the answers out of a day binary's output, including multi-line ones.
+pub fn parse_answers(output: &str) -> Vec<(Part, String)> {
+    let mut answers: Vec<(Part, String)> = vec![];
+    let mut multiline = false;
+    for line in output.lines() {
+        let header = line.strip_prefix("Day ")
+            .and_then(|rest| rest.split_once(" part "))
+            .and_then(|(_, rest)| rest.split_once(':'))
+            .and_then(|(part, answer)| {
+                let part = Part::all().into_iter().find(|p| p.to_string() == part)?;
+                Some((part, answer.trim()))
+            });
+
+        match header {
+            Some((part, "")) => {
+                answers.push((part, String::new()));
+                multiline = true;
+            },
+            Some((part, answer)) => {
+                answers.push((part, answer.to_string()));
+                multiline = false;
+            },
+            None if multiline => {
+                let (_, answer) = answers.last_mut().unwrap();
+                if !answer.is_empty() {
+                    answer.push('\n');
+                }
+                answer.push_str(line);
+            },
+            None => {},
+        }
+    }
+    answers
+}
+
+/// How an answer changed since the previous run; multi-line answers are compared line by line.
+pub fn diff(previous: Option<&str>, current: &str) -> String {
+    match previous {
+        None => current.to_string(),
+        Some(previous) if previous == current => format!("{} (unchanged)", current),
+        Some(previous) if !previous.contains('\n') && !current.contains('\n') => format!("{} (was {})", current, previous),
+        Some(previous) => {
+            let old = previous.lines().collect::<Vec<_>>();
+            let new = current.lines().collect::<Vec<_>>();
+            let mut lines = vec![];
+            for i in 0..old.len().max(new.len()) {
+                match (old.get(i), new.get(i)) {
+                    (Some(o), Some(n)) if o == n => lines.push(format!("  {}", n)),
+                    (o, n) => {
+                        lines.extend(o.map(|o| format!("- {}", o)));
+                        lines.extend(n.map(|n| format!("+ {}", n)));
+                    },
+                }
+            }
+            format!("\n{}", lines.join("\n"))
+        },
+    }
+}
+
+/// Runs the day, then its tests, every time one of its files changes. Only returns on error.
+pub fn watch(root: &Path, options: &WatchOptions) -> Result<(), Box<dyn Error>> {
+    let paths = watched_paths(root, options.day, options.input.as_deref());
+    if !root.join(format!("day_{:02}", options.day)).is_dir() {
+        return Err(format!("there is no crate for day {}", options.day).into());
+    }
+
+    let mut previous = HashMap::new();
+    let mut seen = snapshot(&paths);
+    run_once(root, options, &mut previous)?;
+
+    loop {
+        thread::sleep(options.interval);
+        let mut current = snapshot(&paths);
+        if current == seen {
+            continue;
+        }
+        // editors often write in several steps, so wait for things to settle
+        loop {
+            thread::sleep(options.interval);
+            let settled = snapshot(&paths);
+            if settled == current {
+                break;
+            }
+            current = settled;
+        }
+
+        println!();
+        for path in changed(&seen, &current) {
+            println!("Changed {}", path.strip_prefix(root).unwrap_or(path).display());
+        }
+        seen = current;
+        run_once(root, options, &mut previous)?;
+    }
+}
+
+fn run_once(root: &Path, options: &WatchOptions, previous: &mut HashMap<Part, String>) -> Result<(), Box<dyn Error>> {
+    let package = format!("day_{:02}", options.day);
+    let mut args = vec!["run".to_string(), "-q".to_string(), "-p".to_string(), package.clone(), "--".to_string()];
+    if let Some(part) = options.part {
+        args.extend(["--part".to_string(), part.to_string()]);
+    }
+    if let Some(input) = &options.input {
+        args.extend(["--input".to_string(), input.display().to_string()]);
+    }
+
+    let output = cargo(root).args(&args).stderr(Stdio::inherit()).output()?;
+    if output.status.success() {
+        for (part, answer) in parse_answers(&String::from_utf8_lossy(&output.stdout)) {
+            println!("Day {:02} part {}: {}", options.day, part, diff(previous.get(&part).map(String::as_str), &answer));
+            previous.insert(part, answer);
+        }
+    } else {
+        println!("Day {:02} failed to run", options.day);
+    }
+
+    if options.tests {
+        // only show the test output when something needs looking at
+        let output = cargo(root).args(["test", "-q", "-p", &package]).output()?;
+        if output.status.success() {
+            println!("Day {:02} tests passed", options.day);
+        } else {
+            print!("{}", String::from_utf8_lossy(&output.stdout));
+            eprint!("{}", String::from_utf8_lossy(&output.stderr));
+            println!("Day {:02} tests FAILED", options.day);
+        }
+    }
+    println!("Watching day {:02} for changes...", options.day);
+
+    Ok(())
+}
+
+fn cargo(root: &Path) -> Command {
+    let mut command = Command::new(env::var_os("CARGO").unwrap_or_else(|| "cargo".into()));
+    command.current_dir(root);
+    command
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snapshots() {
+        let dir = env::temp_dir().join(format!("aoc_watch_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("src")).unwrap();
+        fs::write(dir.join("src/lib.rs"), "fn main() {}").unwrap();
+        let paths = vec![dir.join("src"), dir.join("input.txt")];
+
+        let before = snapshot(&paths);
+        assert_eq!(2, before.len());
+        assert_eq!(Some(&None), before.get(&dir.join("input.txt")));
+        assert!(changed(&before, &snapshot(&paths)).is_empty());
+
+        fs::write(dir.join("src/lib.rs"), "fn main() { println!(); }").unwrap();
+        fs::write(dir.join("input.txt"), "1").unwrap();
+        let after = snapshot(&paths);
+        assert_eq!(vec![dir.join("input.txt").as_path(), dir.join("src/lib.rs").as_path()], changed(&before, &after));
+
+        fs::remove_file(dir.join("src/lib.rs")).unwrap();
+        assert_eq!(vec![dir.join("src/lib.rs").as_path()], changed(&after, &snapshot(&paths)));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn answers() {
+        let output = "Day 10 part 1: 13140\nDay 10 part 2:\n##..\n..##\n";
+        assert_eq!(
+            vec![(Part::One, "13140".to_string()), (Part::Two, "##..\n..##".to_string())],
+            parse_answers(output)
+        );
+        assert!(parse_answers("Compiling day_10\n").is_empty());
+    }
+
+    #[test]
+    fn diffs() {
+        assert_eq!("13", diff(None, "13"));
+        assert_eq!("13 (unchanged)", diff(Some("13"), "13"));
+        assert_eq!("14 (was 13)", diff(Some("13"), "14"));
+        assert_eq!("\n  ##..\n- ..##\n+ .##.\n+ ####", diff(Some("##..\n..##"), "##..\n.##.\n####"));
+    }
+}
@@ -0,0 +1,40 @@
+//! Plans which snacks the elves should hand to each other to share the load more evenly.
+
+use std::error::Error;
+use std::process::ExitCode;
+
+use clap::Parser;
+use day_01::{Day01, Objective, rebalance};
+use util::{Solution, input};
+
+#[derive(Parser, Debug)]
+#[command(about = "Plan snack transfers that even out the calories each elf carries")]
+struct Cli {
+    /// Puzzle input file, or '-' for stdin (the day's input when omitted)
+    #[arg(short, long)]
+    input: Option<String>,
+
+    /// What to make as small as possible
+    #[arg(short, long, value_enum, default_value_t = Objective::MaxLoad)]
+    objective: Objective,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match run(&cli) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Failed to plan: {}", e);
+            ExitCode::FAILURE
+        },
+    }
+}
+
+fn run(cli: &Cli) -> Result<(), Box<dyn Error>> {
+    let input = input::load(cli.input.as_deref(), Day01::DAY, Day01::MANIFEST_DIR)?;
+    let expedition = Day01::parse(&input)?;
+
+    println!("{}", rebalance::plan(&expedition, cli.objective));
+    Ok(())
+}
@@ -0,0 +1,50 @@
+//! Prints calorie statistics for one or more expedition inventories, to compare them.
+
+use std::error::Error;
+use std::process::ExitCode;
+
+use clap::Parser;
+use day_01::{Day01, Statistics};
+use util::{Solution, input};
+
+#[derive(Parser, Debug)]
+#[command(about = "Calorie statistics for each input")]
+struct Cli {
+    /// Puzzle input files, or '-' for stdin (the day's input when omitted)
+    inputs: Vec<String>,
+
+    /// Print one JSON object per input instead of a text report
+    #[arg(long)]
+    json: bool,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let inputs = if cli.inputs.is_empty() { vec![None] } else { cli.inputs.iter().map(|i| Some(i.as_str())).collect() };
+
+    for (index, path) in inputs.into_iter().enumerate() {
+        let name = path.unwrap_or("input");
+        match statistics(path) {
+            Ok(statistics) if cli.json => println!("{}", statistics.to_json(name)),
+            Ok(statistics) => {
+                if index > 0 {
+                    println!();
+                }
+                println!("{}\n{}", name, statistics);
+            },
+            Err(e) => {
+                eprintln!("Failed to read {}: {}", path.unwrap_or("the input"), e);
+                return ExitCode::FAILURE;
+            },
+        }
+    }
+
+    ExitCode::SUCCESS
+}
+
+fn statistics(path: Option<&str>) -> Result<Statistics, Box<dyn Error>> {
+    let input = input::load(path, Day01::DAY, Day01::MANIFEST_DIR)?;
+    let expedition = Day01::parse(&input)?;
+
+    Ok(Statistics::of(&expedition).ok_or("there are no elves")?)
+}
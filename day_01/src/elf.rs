@@ -1,4 +1,5 @@
-#[derive(Clone, Debug, Default)]
+/// An elf and the calories of each snack they carry.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct Elf {
 	snacks: Vec<u64>,
 }
@@ -14,6 +15,11 @@ impl Elf {
 		self.snacks.push(calories);
 	}
 
+	pub fn snacks(&self) -> &[u64] {
+		&self.snacks
+	}
+
+	/// Total calories of every snack.
 	pub fn calories(&self) -> u64 {
 		self.snacks.iter().sum()
 	}
@@ -1,6 +1,14 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::fmt;
+use std::str::FromStr;
+
+use util::{ParseError, parse_number};
+
 use crate::elf::Elf;
 
-#[derive(Default)]
+/// Every elf on the expedition, in the order they appear in the input.
+#[derive(Debug, Default, PartialEq, Eq)]
 pub struct Expedition {
 	elves: Vec<Elf>,
 }
@@ -14,7 +22,7 @@ impl Expedition {
 		self.elves.push(elf);
 	}
 
-	#[allow(dead_code)]
+	/// Index and calories of the elf carrying the most calories, the first one on a tie.
 	pub fn find_snack_source(&self) -> Option<(usize, u64)> {
 		let mut max_snacks_source = None;
 
@@ -33,17 +41,112 @@ impl Expedition {
 		max_snacks_source
 	}
 
-	pub fn find_top_snack_sources(&self, top_elf_count: usize) -> u64 {
-		assert!(top_elf_count <= self.elves.len(), "{} is too many top snack sources! There are only {} elves in the expedition", top_elf_count, self.elves.len());
+	/// Total calories carried by the `top_elf_count` elves carrying the most, if there are that many.
+	pub fn find_top_snack_sources(&self, top_elf_count: usize) -> Option<u64> {
+		let top = self.top(top_elf_count);
+		if top.len() < top_elf_count {
+			return None;
+		}
+
+		Some(top.iter().map(|(_, calories)| calories).sum())
+	}
+
+	pub fn elves(&self) -> &[Elf] {
+		&self.elves
+	}
+
+	/// Index and calories of the `k` elves carrying the most, most first, with the earlier elf first
+	/// on a tie. Fewer than `k` when there aren't that many elves.
+	pub fn top(&self, k: usize) -> Vec<(usize, u64)> {
+		// a min-heap of the best so far, so the one to drop when a better elf comes along is on top
+		let mut best = BinaryHeap::with_capacity(k.min(self.elves.len()) + 1);
+		for (index, elf) in self.elves.iter().enumerate() {
+			best.push(Reverse((elf.calories(), Reverse(index))));
+			if best.len() > k {
+				best.pop();
+			}
+		}
+
+		best.into_sorted_vec().into_iter()
+			.map(|Reverse((calories, Reverse(index)))| (index, calories))
+			.collect()
+	}
+
+	/// Like [`Expedition::top`], but also every elf tied with the last of the `k`, so elves
+	/// carrying the same aren't left out depending on their order.
+	pub fn top_with_ties(&self, k: usize) -> Vec<(usize, u64)> {
+		let mut top = self.top(k);
+		let Some(&(_, last)) = top.last() else {
+			return top;
+		};
+
+		let tied = self.elves.iter().enumerate()
+			.filter(|(index, elf)| elf.calories() == last && !top.iter().any(|(i, _)| i == index))
+			.map(|(index, elf)| (index, elf.calories()))
+			.collect::<Vec<_>>();
+		top.extend(tied);
+		top
+	}
+
+	/// Where the elf at `index` places by calories carried, from 1 for the most. Elves carrying the
+	/// same share a place, and the next place down is skipped for each of them, like a podium.
+	pub fn rank(&self, index: usize) -> Option<usize> {
+		let calories = self.elves.get(index)?.calories();
 
-		let mut sorted_elves = self.elves.clone();
-		sorted_elves.sort_by(|a, b| b.calories().partial_cmp(&a.calories()).unwrap());
+		Some(1 + self.elves.iter().filter(|elf| elf.calories() > calories).count())
+	}
 
-		let top_elves = &sorted_elves[0..top_elf_count];
+	/// Index and calories of every elf carrying at least `calories`, in order.
+	pub fn carrying_at_least(&self, calories: u64) -> Vec<(usize, u64)> {
+		self.elves.iter().enumerate()
+			.map(|(index, elf)| (index, elf.calories()))
+			.filter(|(_, carried)| *carried >= calories)
+			.collect()
+	}
+}
 
-		top_elves.iter()
-			.map(Elf::calories)
-			.sum()
+/// Each elf's snack calories one per line, with blank lines between elves.
+///
+/// Any run of blank lines separates two elves, and blank lines at the start or end are ignored, as
+/// is whitespace around each number and `\r\n` line endings.
+impl FromStr for Expedition {
+	type Err = ParseError;
+
+	fn from_str(input: &str) -> Result<Self, Self::Err> {
+		let mut expedition = Expedition::new();
+		let mut current_elf: Option<Elf> = None;
+
+		for (index, line) in input.lines().enumerate() {
+			let calories = line.trim();
+			if calories.is_empty() {
+				if let Some(elf) = current_elf.take() {
+					expedition.add_elf(elf);
+				}
+				continue;
+			}
+
+			let column = line.len() - line.trim_start().len() + 1;
+			let calories = parse_number(calories, column, "snack calories").map_err(|e| e.offset_lines(index))?;
+			current_elf.get_or_insert_with(Elf::new).add_snack(calories);
+		}
+		if let Some(elf) = current_elf {
+			expedition.add_elf(elf);
+		}
+
+		Ok(expedition)
+	}
+}
+
+/// Each elf's snacks one per line, with a blank line after every elf, like the puzzle input.
+impl fmt::Display for Expedition {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for elf in &self.elves {
+			for calories in elf.snacks() {
+				writeln!(f, "{}", calories)?;
+			}
+			writeln!(f)?;
+		}
+		Ok(())
 	}
 }
 
@@ -94,4 +197,94 @@ mod tests {
 
 		assert_eq!(Some((2, 1337)), expedition.find_snack_source());
 	}
+
+	fn carrying(totals: &[u64]) -> Expedition {
+		let mut expedition = Expedition::new();
+		for calories in totals {
+			let mut elf = Elf::new();
+			elf.add_snack(*calories);
+			expedition.add_elf(elf);
+		}
+		expedition
+	}
+
+	#[test]
+	fn top() {
+		let expedition = carrying(&[300, 500, 100, 500, 200]);
+
+		assert_eq!(vec![(1, 500), (3, 500), (0, 300)], expedition.top(3));
+		assert_eq!(vec![(1, 500)], expedition.top(1));
+		assert_eq!(5, expedition.top(10).len());
+		assert_eq!(expedition.top(5), expedition.top(usize::MAX));
+		assert!(expedition.top(0).is_empty());
+		assert_eq!(Some(1300), expedition.find_top_snack_sources(3));
+		assert_eq!(None, expedition.find_top_snack_sources(6));
+	}
+
+	#[test]
+	fn ties() {
+		let expedition = carrying(&[300, 500, 100, 500, 300]);
+
+		assert_eq!(vec![(1, 500)], expedition.top(1));
+		assert_eq!(vec![(1, 500), (3, 500)], expedition.top_with_ties(1));
+		assert_eq!(vec![(1, 500), (3, 500), (0, 300), (4, 300)], expedition.top_with_ties(3));
+		assert!(Expedition::new().top_with_ties(2).is_empty());
+	}
+
+	#[test]
+	fn rank() {
+		let expedition = carrying(&[300, 500, 100, 500]);
+
+		assert_eq!(Some(1), expedition.rank(1));
+		assert_eq!(Some(1), expedition.rank(3));
+		assert_eq!(Some(3), expedition.rank(0));
+		assert_eq!(Some(4), expedition.rank(2));
+		assert_eq!(None, expedition.rank(4));
+	}
+
+	#[test]
+	fn threshold() {
+		let expedition = carrying(&[300, 500, 100, 500]);
+
+		assert_eq!(vec![(0, 300), (1, 500), (3, 500)], expedition.carrying_at_least(300));
+		assert!(expedition.carrying_at_least(501).is_empty());
+	}
+
+	#[test]
+	fn display() {
+		let mut expedition = Expedition::new();
+		for snacks in [vec![1000, 2000], vec![4000]] {
+			let mut elf = Elf::new();
+			for calories in snacks {
+				elf.add_snack(calories);
+			}
+			expedition.add_elf(elf);
+		}
+
+		assert_eq!("1000\n2000\n\n4000\n\n", expedition.to_string());
+		assert_eq!(Ok(expedition), "1000\n2000\n\n4000\n\n".parse());
+	}
+
+	#[test]
+	fn parse() {
+		let expected = "1\n2\n\n3\n".parse::<Expedition>().unwrap();
+
+		assert_eq!(2, expected.elves.len());
+		assert_eq!(Ok(&expected), "1\n2\n\n3".parse().as_ref(), "keeps the last elf without a trailing blank line");
+		assert_eq!(Ok(&expected), "\n1\n2\n\n\n\n3\n\n".parse().as_ref(), "any number of blank lines");
+		assert_eq!(Ok(&expected), "1\r\n2\r\n\r\n3\r\n".parse().as_ref(), "CRLF");
+		assert_eq!(Ok(&expected), "  1\n2 \n \t\n\t3\n".parse().as_ref(), "whitespace");
+		assert_eq!(Ok(Expedition::new()), "\n\n".parse());
+	}
+
+	#[test]
+	fn invalid() {
+		let error = "1\n\n  2x\n3\n".parse::<Expedition>().unwrap_err();
+		assert_eq!((3, 3), (error.line(), error.column()));
+		assert_eq!("2x", error.text());
+		assert_eq!("snack calories", error.expected());
+
+		let error = "1\r\n-4\r\n".parse::<Expedition>().unwrap_err();
+		assert_eq!((2, 1), (error.line(), error.column()));
+	}
 }
\ No newline at end of file
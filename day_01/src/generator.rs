@@ -0,0 +1,32 @@
+use util::Rng;
+
+/// `scale` elves (at least 3, for part 2) carrying 1-10 snacks each, with a blank line after every elf.
+pub fn generate(rng: &mut Rng, scale: usize) -> String {
+	let elves = (0..scale.max(3))
+		.map(|_| {
+			let snacks = (0..rng.range(1..=10))
+				.map(|_| rng.range(1000..=60000).to_string())
+				.collect::<Vec<_>>();
+			snacks.join("\n") + "\n\n"
+		})
+		.collect::<Vec<_>>();
+
+	elves.concat()
+}
+
+#[cfg(test)]
+mod tests {
+	use util::{Part, Puzzle, Report, Timer};
+
+	use super::*;
+	use crate::Day01;
+
+	#[test]
+	fn solvable() {
+		let input = generate(&mut Rng::new(1), 50);
+
+		assert_eq!(50, input.matches("\n\n").count());
+		assert_eq!(input, generate(&mut Rng::new(1), 50));
+		assert!(Puzzle::of::<Day01>().solve(&input, &Part::all(), &Timer::with_report(Report::Quiet)).is_ok());
+	}
+}
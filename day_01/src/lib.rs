@@ -0,0 +1,84 @@
+//! Day 1: Calorie Counting - finding the elves carrying the most calories.
+
+pub mod elf;
+pub mod expedition;
+pub mod generator;
+pub mod rebalance;
+pub mod statistics;
+
+use std::fmt::Display;
+
+pub use elf::Elf;
+pub use expedition::Expedition;
+pub use rebalance::{Objective, Plan, Transfer};
+pub use statistics::Statistics;
+use util::{NoAnswer, ParseError, Rng, Solution};
+
+/// Finds the elves in the [`Expedition`] carrying the most calories, then the top three.
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const MANIFEST_DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    type Input = Expedition;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+        Some(generator::generate(rng, scale))
+    }
+
+    fn serialize(expedition: &Expedition) -> Option<String> {
+        Some(expedition.to_string())
+    }
+
+    fn part_1(expedition: &Expedition) -> Result<impl Display, NoAnswer> {
+        let (_, calories) = expedition.find_snack_source().ok_or_else(|| NoAnswer::new("there are no elves"))?;
+
+        Ok(calories)
+    }
+
+    fn part_2(expedition: &Expedition) -> Result<impl Display, NoAnswer> {
+        expedition.find_top_snack_sources(3).ok_or_else(|| NoAnswer::new("there are fewer than three elves"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::{TestCaseError, prop_assert_eq, proptest};
+    use util::{Part, round_trip};
+
+    use super::*;
+
+    #[test]
+    fn fixtures() {
+        util::fixtures::assert_fixtures(util::Puzzle::of::<Day01>());
+    }
+
+    #[test]
+    fn no_answer() {
+        let input = Day01::parse("\n").unwrap();
+
+        assert_eq!(Err(NoAnswer::new("there are no elves")), Day01::solve(&input, Part::One));
+
+        let input = Day01::parse("1\n\n2\n").unwrap();
+        assert_eq!(Err(NoAnswer::new("there are fewer than three elves")), Day01::solve(&input, Part::Two));
+    }
+
+    proptest! {
+        #[test]
+        fn parse_anything(input in "[0-9\r\n\t a]*") {
+            round_trip::check::<Day01>(&input).map_err(TestCaseError::fail)?;
+        }
+
+        #[test]
+        fn round_trip(seed: u64, scale in 1..50usize) {
+            let input = Day01::generate(&mut Rng::new(seed), scale).unwrap();
+
+            prop_assert_eq!(Ok(true), round_trip::check::<Day01>(&input));
+        }
+    }
+}
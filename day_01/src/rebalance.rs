@@ -0,0 +1,360 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::fmt;
+
+use clap::ValueEnum;
+
+use crate::elf::Elf;
+use crate::expedition::Expedition;
+
+/// What a rebalancing tries to make as small as it can.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Objective {
+	/// The calories carried by the most loaded elf
+	MaxLoad,
+	/// How far each elf's calories are from the average
+	Variance,
+}
+
+/// A snack handed from one elf to another.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Transfer {
+	pub from: usize,
+	pub to: usize,
+	pub calories: u64,
+}
+
+/// The snacks to hand over so the load is shared more evenly, and the expedition that leaves.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Plan {
+	before: Vec<u64>,
+	rebalanced: Expedition,
+	transfers: Vec<Transfer>,
+}
+
+/// Plans which snacks the elves should pass between them to meet the objective.
+///
+/// Splitting snacks evenly is a partitioning problem with no fast exact answer, so this searches
+/// locally: it keeps handing a snack over, or swapping two, between a pair of elves while that
+/// improves things. It starts once from how the snacks are carried now and once from a greedy
+/// split of every snack, largest first, and keeps the better result, preferring fewer transfers.
+pub fn plan(expedition: &Expedition, objective: Objective) -> Plan {
+	let current = expedition.elves().iter().map(|elf| elf.snacks().to_vec()).collect::<Vec<_>>();
+
+	let mut from_current = current.clone();
+	improve(&mut from_current, objective);
+	let mut from_greedy = greedy(&current);
+	improve(&mut from_greedy, objective);
+
+	let candidates = [from_current, from_greedy].map(|snacks| {
+		let transfers = transfers(&current, &snacks);
+		(cost(&loads(&snacks), objective), transfers.len(), snacks, transfers)
+	});
+	let (_, _, snacks, transfers) = candidates.into_iter().min_by_key(|(cost, count, _, _)| (*cost, *count)).unwrap();
+
+	let mut rebalanced = Expedition::new();
+	for carried in snacks {
+		let mut elf = Elf::new();
+		for calories in carried {
+			elf.add_snack(calories);
+		}
+		rebalanced.add_elf(elf);
+	}
+
+	Plan { before: loads(&current), rebalanced, transfers }
+}
+
+impl Plan {
+	pub fn transfers(&self) -> &[Transfer] {
+		&self.transfers
+	}
+
+	pub fn rebalanced(&self) -> &Expedition {
+		&self.rebalanced
+	}
+
+	/// Calories carried by each elf before the transfers.
+	pub fn loads_before(&self) -> &[u64] {
+		&self.before
+	}
+
+	/// Calories carried by each elf after the transfers.
+	pub fn loads_after(&self) -> Vec<u64> {
+		self.rebalanced.elves().iter().map(Elf::calories).collect()
+	}
+
+	/// No split can leave the most loaded elf with less than this: an even share, or the biggest
+	/// snack since snacks can't be split.
+	pub fn lower_bound(&self) -> u64 {
+		let total = self.before.iter().sum::<u64>();
+		let even_share = total.div_ceil(self.before.len().max(1) as u64);
+		let biggest = self.rebalanced.elves().iter().flat_map(|elf| elf.snacks()).max().copied().unwrap_or(0);
+
+		even_share.max(biggest)
+	}
+}
+
+/// The transfers, then how the most loaded elf and the variance changed.
+impl fmt::Display for Plan {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for t in &self.transfers {
+			writeln!(f, "elf {} gives elf {} a snack of {} calories", t.from, t.to, t.calories)?;
+		}
+		let after = self.loads_after();
+		writeln!(f, "{} transfers", self.transfers.len())?;
+		writeln!(f, "most loaded elf: {} -> {} calories (at best {})", max(&self.before), max(&after), self.lower_bound())?;
+		write!(f, "variance: {:.1} -> {:.1}", variance(&self.before), variance(&after))
+	}
+}
+
+fn loads(snacks: &[Vec<u64>]) -> Vec<u64> {
+	snacks.iter().map(|carried| carried.iter().sum()).collect()
+}
+
+fn max(loads: &[u64]) -> u64 {
+	loads.iter().max().copied().unwrap_or(0)
+}
+
+fn sum_of_squares(loads: &[u64]) -> u128 {
+	loads.iter().map(|load| *load as u128 * *load as u128).sum()
+}
+
+fn variance(loads: &[u64]) -> f64 {
+	if loads.is_empty() {
+		return 0.0;
+	}
+	let mean = loads.iter().sum::<u64>() as f64 / loads.len() as f64;
+	loads.iter().map(|load| (*load as f64 - mean).powi(2)).sum::<f64>() / loads.len() as f64
+}
+
+/// Smaller is better. The total never changes, so the sum of squares goes down exactly when the
+/// variance does. Splits leaving the most loaded elf the same are equally good, so the one needing
+/// fewer transfers wins.
+fn cost(loads: &[u64], objective: Objective) -> u128 {
+	match objective {
+		Objective::MaxLoad => max(loads) as u128,
+		Objective::Variance => sum_of_squares(loads),
+	}
+}
+
+/// Every snack, largest first, to whichever elf is carrying least so far.
+fn greedy(snacks: &[Vec<u64>]) -> Vec<Vec<u64>> {
+	let mut all = snacks.iter().flatten().copied().collect::<Vec<_>>();
+	all.sort_unstable_by(|a, b| b.cmp(a));
+
+	let mut split = vec![vec![]; snacks.len()];
+	let mut lightest = (0..snacks.len()).map(|elf| Reverse((0, elf))).collect::<BinaryHeap<_>>();
+	for calories in all {
+		let Reverse((load, elf)) = lightest.pop().unwrap();
+		split[elf].push(calories);
+		lightest.push(Reverse((load + calories, elf)));
+	}
+	split
+}
+
+/// A snack handed from the heavier elf of a pair to the lighter, in exchange for one of the
+/// lighter elf's snacks when there is a `back`.
+#[derive(Clone, Copy, Debug)]
+struct Exchange {
+	give: usize,
+	back: Option<usize>,
+	/// Calories the heavier elf ends up without
+	moved: u64,
+}
+
+/// The exchange that brings the two loads closest together, if any brings them closer at all.
+fn best_exchange(heavier: &[u64], lighter: &[u64], gap: u64) -> Option<Exchange> {
+	let mut best: Option<Exchange> = None;
+	let mut consider = |exchange: Exchange| {
+		// the loads get closer exactly when the heavier elf loses more than nothing but less than the gap
+		if exchange.moved == 0 || exchange.moved >= gap {
+			return;
+		}
+		let spread = |e: &Exchange| gap.abs_diff(2 * e.moved);
+		if best.is_none_or(|b| spread(&exchange) < spread(&b)) {
+			best = Some(exchange);
+		}
+	};
+
+	for (give, calories) in heavier.iter().enumerate() {
+		consider(Exchange { give, back: None, moved: *calories });
+		for (back, returned) in lighter.iter().enumerate() {
+			if calories > returned {
+				consider(Exchange { give, back: Some(back), moved: calories - returned });
+			}
+		}
+	}
+	best
+}
+
+fn apply(snacks: &mut [Vec<u64>], heavier: usize, lighter: usize, exchange: Exchange) {
+	let given = snacks[heavier].swap_remove(exchange.give);
+	if let Some(back) = exchange.back {
+		let returned = snacks[lighter].swap_remove(back);
+		snacks[heavier].push(returned);
+	}
+	snacks[lighter].push(given);
+}
+
+/// Exchanges snacks between pairs of elves until none would help.
+///
+/// For the most loaded elf, that means trying every other elf to find the exchange leaving the
+/// pair's heavier load lightest. For the variance, any pair that can be brought closer helps.
+/// Every exchange brings a pair closer, so the sum of squares always falls and the search ends.
+fn improve(snacks: &mut [Vec<u64>], objective: Objective) {
+	let mut loads = loads(snacks);
+	match objective {
+		Objective::MaxLoad => loop {
+			let heaviest = (0..loads.len()).max_by_key(|elf| (loads[*elf], Reverse(*elf))).unwrap_or(0);
+			let best = (0..loads.len())
+				.filter(|other| loads[*other] < loads[heaviest])
+				.filter_map(|other| {
+					let exchange = best_exchange(&snacks[heaviest], &snacks[other], loads[heaviest] - loads[other])?;
+					let pair_max = (loads[heaviest] - exchange.moved).max(loads[other] + exchange.moved);
+					Some((pair_max, other, exchange))
+				})
+				.min_by_key(|(pair_max, other, _)| (*pair_max, *other));
+
+			let Some((_, other, exchange)) = best else {
+				break;
+			};
+			apply(snacks, heaviest, other, exchange);
+			loads[heaviest] -= exchange.moved;
+			loads[other] += exchange.moved;
+		},
+		Objective::Variance => loop {
+			let mut improved = false;
+			for a in 0..loads.len() {
+				for b in a + 1..loads.len() {
+					let (heavier, lighter) = if loads[a] >= loads[b] { (a, b) } else { (b, a) };
+					if let Some(exchange) = best_exchange(&snacks[heavier], &snacks[lighter], loads[heavier] - loads[lighter]) {
+						apply(snacks, heavier, lighter, exchange);
+						loads[heavier] -= exchange.moved;
+						loads[lighter] += exchange.moved;
+						improved = true;
+					}
+				}
+			}
+			if !improved {
+				break;
+			}
+		},
+	}
+}
+
+/// The fewest snacks that have to change hands to get from `before` to `after`: a snack an elf
+/// carries in both is left where it is, and snacks of the same calories are interchangeable.
+fn transfers(before: &[Vec<u64>], after: &[Vec<u64>]) -> Vec<Transfer> {
+	let mut surplus: HashMap<u64, Vec<usize>> = HashMap::new();
+	let mut wanted = vec![];
+	for (elf, (had, has)) in before.iter().zip(after).enumerate() {
+		let mut counts = HashMap::new();
+		for calories in had {
+			*counts.entry(*calories).or_insert(0i64) += 1;
+		}
+		for calories in has {
+			*counts.entry(*calories).or_insert(0i64) -= 1;
+		}
+		for (calories, count) in counts {
+			if count > 0 {
+				surplus.entry(calories).or_default().extend(std::iter::repeat_n(elf, count as usize));
+			} else {
+				wanted.extend(std::iter::repeat_n((elf, calories), (-count) as usize));
+			}
+		}
+	}
+
+	let mut transfers = wanted.into_iter()
+		.map(|(to, calories)| {
+			let from = surplus.get_mut(&calories).and_then(Vec::pop).expect("every snack an elf gains, another loses");
+			Transfer { from, to, calories }
+		})
+		.collect::<Vec<_>>();
+	transfers.sort_unstable();
+	transfers
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn carrying(snacks: &[&[u64]]) -> Expedition {
+		let mut expedition = Expedition::new();
+		for carried in snacks {
+			let mut elf = Elf::new();
+			for calories in *carried {
+				elf.add_snack(*calories);
+			}
+			expedition.add_elf(elf);
+		}
+		expedition
+	}
+
+	#[test]
+	fn balanced() {
+		let expedition = carrying(&[&[5, 5], &[10]]);
+		let plan = plan(&expedition, Objective::MaxLoad);
+
+		assert!(plan.transfers().is_empty());
+		assert_eq!(&expedition, plan.rebalanced());
+	}
+
+	/// Checks the plan only hands snacks around: none are lost or made up, and the transfers
+	/// account for every change in what each elf carries.
+	fn assert_consistent(expedition: &Expedition, plan: &Plan) {
+		let all = |e: &Expedition| {
+			let mut snacks = e.elves().iter().flat_map(|elf| elf.snacks().to_vec()).collect::<Vec<_>>();
+			snacks.sort_unstable();
+			snacks
+		};
+		assert_eq!(all(expedition), all(plan.rebalanced()));
+
+		let mut loads = plan.loads_before().to_vec();
+		for t in plan.transfers() {
+			loads[t.from] -= t.calories;
+			loads[t.to] += t.calories;
+		}
+		assert_eq!(plan.loads_after(), loads);
+	}
+
+	#[test]
+	fn max_load() {
+		let expedition = carrying(&[&[6, 4, 2], &[], &[1]]);
+		let plan = plan(&expedition, Objective::MaxLoad);
+
+		assert_consistent(&expedition, &plan);
+		assert_eq!(vec![12, 0, 1], plan.loads_before());
+		assert_eq!(Some(6), plan.loads_after().into_iter().max());
+		assert_eq!(6, plan.lower_bound());
+		assert_eq!(2, plan.transfers().len());
+	}
+
+	#[test]
+	fn variance() {
+		let expedition = carrying(&[&[10, 10, 10, 10], &[1], &[1], &[1]]);
+		let plan = plan(&expedition, Objective::Variance);
+
+		assert_consistent(&expedition, &plan);
+		assert_eq!(vec![10, 11, 11, 11], plan.loads_after());
+		assert_eq!(3, plan.transfers().len());
+		assert!(plan.to_string().ends_with("3 transfers\nmost loaded elf: 40 -> 11 calories (at best 11)\nvariance: 285.2 -> 0.2"));
+	}
+
+	#[test]
+	fn example() {
+		let expedition = include_str!("../fixtures/example.txt").parse::<Expedition>().unwrap();
+
+		for objective in [Objective::MaxLoad, Objective::Variance] {
+			let plan = plan(&expedition, objective);
+			assert_consistent(&expedition, &plan);
+			assert_eq!(Some(plan.lower_bound()), plan.loads_after().into_iter().max());
+		}
+	}
+
+	#[test]
+	fn same_calories() {
+		let moved = transfers(&[vec![5, 5, 3], vec![]], &[vec![5, 3], vec![5]]);
+
+		assert_eq!(vec![Transfer { from: 0, to: 1, calories: 5 }], moved);
+	}
+}
@@ -0,0 +1,219 @@
+use std::fmt::{self, Write as _};
+
+use util::json::{self, Object};
+
+use crate::expedition::Expedition;
+
+/// Percentiles of the calories carried that every report includes
+pub const PERCENTILES: [u8; 5] = [10, 25, 75, 90, 99];
+/// Bars in the histogram of calories carried
+pub const BUCKETS: usize = 10;
+/// Characters in the longest histogram bar
+const BAR_WIDTH: usize = 40;
+
+/// A summary of how many calories the elves carry, to compare one expedition's inventory with another's.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Statistics {
+	/// Calories carried by each elf, least first
+	totals: Vec<u64>,
+	/// Snacks carried by each elf, in the order of the expedition
+	snack_counts: Vec<usize>,
+}
+
+/// Elves carrying from `from` up to and including `to` calories.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Bucket {
+	pub from: u64,
+	pub to: u64,
+	pub elves: usize,
+}
+
+impl Statistics {
+	/// None when there are no elves to summarise.
+	pub fn of(expedition: &Expedition) -> Option<Statistics> {
+		if expedition.elves().is_empty() {
+			return None;
+		}
+
+		let mut totals = expedition.elves().iter().map(|elf| elf.calories()).collect::<Vec<_>>();
+		totals.sort_unstable();
+		let snack_counts = expedition.elves().iter().map(|elf| elf.snacks().len()).collect();
+
+		Some(Statistics { totals, snack_counts })
+	}
+
+	pub fn elves(&self) -> usize {
+		self.totals.len()
+	}
+
+	pub fn snacks(&self) -> usize {
+		self.snack_counts.iter().sum()
+	}
+
+	/// How many snacks each elf carries, in the order of the expedition.
+	pub fn snack_counts(&self) -> &[usize] {
+		&self.snack_counts
+	}
+
+	pub fn total(&self) -> u64 {
+		self.totals.iter().sum()
+	}
+
+	pub fn min(&self) -> u64 {
+		self.totals[0]
+	}
+
+	pub fn max(&self) -> u64 {
+		self.totals[self.totals.len() - 1]
+	}
+
+	pub fn mean(&self) -> f64 {
+		self.total() as f64 / self.elves() as f64
+	}
+
+	/// The middle elf's calories, or halfway between the middle two.
+	pub fn median(&self) -> f64 {
+		let middle = self.totals.len() / 2;
+		if self.totals.len() % 2 == 1 {
+			self.totals[middle] as f64
+		} else {
+			(self.totals[middle - 1] + self.totals[middle]) as f64 / 2.0
+		}
+	}
+
+	/// The calories that at least `percent`% of the elves carry no more than (nearest rank).
+	pub fn percentile(&self, percent: u8) -> u64 {
+		let rank = (percent.min(100) as usize * self.totals.len()).div_ceil(100);
+		self.totals[rank.max(1) - 1]
+	}
+
+	/// `buckets` bars of equal width from the least to the most calories carried.
+	pub fn histogram(&self, buckets: usize) -> Vec<Bucket> {
+		let buckets = buckets.max(1) as u64;
+		let (min, max) = (self.min(), self.max());
+		let width = (max - min) / buckets + 1;
+
+		let mut histogram = (0..buckets)
+			.map(|b| Bucket { from: min + b * width, to: (min + (b + 1) * width - 1).min(max), elves: 0 })
+			.take_while(|bucket| bucket.from <= max)
+			.collect::<Vec<_>>();
+		for total in &self.totals {
+			histogram[((total - min) / width) as usize].elves += 1;
+		}
+		histogram
+	}
+
+	/// One JSON object, labelled with the `input` it was worked out from so reports can be told apart.
+	pub fn to_json(&self, input: &str) -> String {
+		let percentiles = PERCENTILES.iter()
+			.fold(Object::new(), |object, p| object.field(&p.to_string(), self.percentile(*p)));
+		let histogram = self.histogram(BUCKETS).iter()
+			.map(|b| Object::new().field("from", b.from).field("to", b.to).field("elves", b.elves))
+			.collect::<Vec<_>>();
+
+		Object::new()
+			.text("input", input)
+			.field("elves", self.elves())
+			.field("snacks", self.snacks())
+			.field("total", self.total())
+			.field("min", self.min())
+			.field("max", self.max())
+			.field("mean", self.mean())
+			.field("median", self.median())
+			.field("percentiles", percentiles)
+			.field("snack_counts", json::array(&self.snack_counts))
+			.field("histogram", json::array(histogram))
+			.to_string()
+	}
+}
+
+/// The figures one per line, then the histogram as bars of `#`.
+impl fmt::Display for Statistics {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let (fewest, most) = (self.snack_counts.iter().min().unwrap(), self.snack_counts.iter().max().unwrap());
+		writeln!(f, "elves          {}", self.elves())?;
+		writeln!(f, "snacks         {} ({} to {} per elf, {:.1} on average)", self.snacks(), fewest, most, self.snacks() as f64 / self.elves() as f64)?;
+		writeln!(f, "total          {}", self.total())?;
+		writeln!(f, "min            {}", self.min())?;
+		writeln!(f, "max            {}", self.max())?;
+		writeln!(f, "mean           {:.1}", self.mean())?;
+		writeln!(f, "median         {:.1}", self.median())?;
+		for p in PERCENTILES {
+			writeln!(f, "{:<15}{}", format!("p{}", p), self.percentile(p))?;
+		}
+
+		let histogram = self.histogram(BUCKETS);
+		let tallest = histogram.iter().map(|b| b.elves).max().unwrap_or(1);
+		let label_width = histogram.iter().map(|b| b.to.to_string().len()).max().unwrap_or(1);
+		let mut text = String::new();
+		for bucket in &histogram {
+			let bar = "#".repeat((bucket.elves * BAR_WIDTH).div_ceil(tallest));
+			writeln!(text, "{:>w$}-{:<w$} {:<BAR_WIDTH$} {}", bucket.from, bucket.to, bar, bucket.elves, w = label_width).unwrap();
+		}
+		write!(f, "\n{}", text.trim_end())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn example() -> Expedition {
+		include_str!("../fixtures/example.txt").parse().unwrap()
+	}
+
+	#[test]
+	fn summary() {
+		let statistics = Statistics::of(&example()).unwrap();
+
+		assert_eq!((5, 10), (statistics.elves(), statistics.snacks()));
+		assert_eq!(&[3, 1, 2, 3, 1], statistics.snack_counts());
+		assert_eq!((55000, 4000, 24000), (statistics.total(), statistics.min(), statistics.max()));
+		assert_eq!(11000.0, statistics.mean());
+		assert_eq!(10000.0, statistics.median());
+		assert_eq!(None, Statistics::of(&Expedition::new()));
+	}
+
+	#[test]
+	fn percentiles() {
+		let statistics = Statistics::of(&example()).unwrap();
+
+		assert_eq!(4000, statistics.percentile(0));
+		assert_eq!(4000, statistics.percentile(20));
+		assert_eq!(6000, statistics.percentile(21));
+		assert_eq!(10000, statistics.percentile(50));
+		assert_eq!(24000, statistics.percentile(100));
+
+		let even = Statistics { totals: vec![1, 2, 3, 10], snack_counts: vec![1; 4] };
+		assert_eq!(2.5, even.median());
+	}
+
+	#[test]
+	fn histogram() {
+		let statistics = Statistics::of(&example()).unwrap();
+
+		let histogram = statistics.histogram(4);
+		assert_eq!(Bucket { from: 4000, to: 9000, elves: 2 }, histogram[0]);
+		assert_eq!(vec![2, 2, 0, 1], histogram.iter().map(|b| b.elves).collect::<Vec<_>>());
+		assert_eq!(24000, histogram[3].to);
+
+		let same = Statistics { totals: vec![7, 7], snack_counts: vec![1; 2] };
+		assert_eq!(vec![Bucket { from: 7, to: 7, elves: 2 }], same.histogram(BUCKETS));
+	}
+
+	#[test]
+	fn report() {
+		let statistics = Statistics::of(&example()).unwrap();
+
+		let text = statistics.to_string();
+		assert!(text.starts_with("elves          5\nsnacks         10 (1 to 3 per elf, 2.0 on average)\n"));
+		assert!(text.contains("\np90            24000\n"));
+		assert!(text.ends_with(&format!("22009-24000 {:<40} 1", "#".repeat(20))));
+
+		let json = statistics.to_json("example.txt");
+		assert!(json.starts_with("{\"input\":\"example.txt\",\"elves\":5,\"snacks\":10,\"total\":55000,\"min\":4000,\"max\":24000,\"mean\":11000,\"median\":10000,"));
+		assert!(json.contains("\"percentiles\":{\"10\":4000,\"25\":6000,\"75\":11000,\"90\":24000,\"99\":24000}"));
+		assert!(json.contains("\"snack_counts\":[3,1,2,3,1]"));
+		assert!(json.ends_with("{\"from\":22009,\"to\":24000,\"elves\":1}]}"));
+	}
+}
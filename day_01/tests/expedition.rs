@@ -0,0 +1,43 @@
+use day_01::{Day01, Elf, Expedition};
+use util::Solution;
+
+#[test]
+fn example() {
+    let expedition = Day01::parse(include_str!("../fixtures/example.txt")).unwrap();
+
+    assert_eq!(Some((3, 24000)), expedition.find_snack_source());
+    assert_eq!(Some(45000), expedition.find_top_snack_sources(3));
+}
+
+#[test]
+fn by_hand() {
+    let mut expedition = Expedition::new();
+    for snacks in [vec![100, 200], vec![5000], vec![1, 2, 3]] {
+        let mut elf = Elf::new();
+        for calories in snacks {
+            elf.add_snack(calories);
+        }
+        expedition.add_elf(elf);
+    }
+
+    assert_eq!(Some((1, 5000)), expedition.find_snack_source());
+    assert_eq!(Some(5300), expedition.find_top_snack_sources(2));
+}
+
+#[test]
+fn parse() {
+    let example = include_str!("../fixtures/example.txt");
+    let expedition = example.trim_end().replace('\n', "\r\n").parse::<Expedition>().unwrap();
+
+    assert_eq!(Day01::parse(example).unwrap(), expedition);
+    assert_eq!(Some((3, 24000)), expedition.find_snack_source());
+}
+
+#[test]
+fn rankings() {
+    let expedition = Day01::parse(include_str!("../fixtures/example.txt")).unwrap();
+
+    assert_eq!(vec![(3, 24000), (2, 11000), (4, 10000)], expedition.top(3));
+    assert_eq!(Some(4), expedition.rank(0));
+    assert_eq!(vec![(2, 11000), (3, 24000)], expedition.carrying_at_least(11000));
+}
@@ -0,0 +1,311 @@
+use std::error::Error;
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// One of a [`Game`]'s shapes, by its place in the circle. It only belongs to the game that handed
+/// it out, and to that game's clones.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Shape {
+    game: usize,
+    index: usize,
+}
+
+impl Shape {
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+/// The result of a game, from one player's point of view.
+#[derive(Debug, PartialEq, Eq)]
+pub enum GameOutcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+/// Numbers each new game, so shapes can tell which game they came from.
+static GAMES: AtomicUsize = AtomicUsize::new(0);
+
+/// A hand game whose shapes stand in a circle, where each shape beats the half of the others just
+/// before it and loses to the half just after. That's only fair with an odd number of shapes.
+///
+/// Rock, paper, scissors is the game of three: paper beats rock, scissors beats paper and rock
+/// beats scissors, coming round again.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Game {
+    id: usize,
+    names: Vec<String>,
+    scores: Vec<u32>,
+    /// Points for a loss, a draw and a win
+    outcome_scores: [u32; 3],
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum GameError {
+    ShapeCount(usize),
+    DuplicateShape(String),
+    /// A shape from some other game
+    UnknownShape(Shape),
+    /// Two hands thrown in different games
+    DifferentGames,
+}
+
+impl fmt::Display for GameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GameError::ShapeCount(count) => write!(f, "a game needs an odd number of shapes, at least 3, but has {}", count),
+            GameError::DuplicateShape(name) => write!(f, "the shape '{}' is in the game more than once", name),
+            GameError::UnknownShape(shape) => write!(f, "shape {} is from a different game", shape.index + 1),
+            GameError::DifferentGames => write!(f, "the hands were thrown in different games"),
+        }
+    }
+}
+
+impl Error for GameError {}
+
+impl Game {
+    /// The shapes in circle order with the points for throwing each, scoring outcomes like the
+    /// puzzle: 0 for a loss, 3 for a draw and 6 for a win.
+    pub fn new(shapes: &[(&str, u32)]) -> Result<Game, GameError> {
+        if shapes.len() < 3 || shapes.len().is_multiple_of(2) {
+            return Err(GameError::ShapeCount(shapes.len()));
+        }
+        for (index, (name, _)) in shapes.iter().enumerate() {
+            if shapes[..index].iter().any(|(other, _)| other == name) {
+                return Err(GameError::DuplicateShape(name.to_string()));
+            }
+        }
+
+        Ok(Game {
+            id: GAMES.fetch_add(1, Ordering::Relaxed),
+            names: shapes.iter().map(|(name, _)| name.to_string()).collect(),
+            scores: shapes.iter().map(|(_, score)| *score).collect(),
+            outcome_scores: [0, 3, 6],
+        })
+    }
+
+    pub fn with_outcome_scores(mut self, loss: u32, draw: u32, win: u32) -> Self {
+        self.outcome_scores = [loss, draw, win];
+        self
+    }
+
+    pub fn rock_paper_scissors() -> Game {
+        Game::new(&[("Rock", 1), ("Paper", 2), ("Scissors", 3)]).unwrap()
+    }
+
+    /// The five shape game where Spock smashes scissors and vaporizes rock, and lizard poisons
+    /// Spock and eats paper, on top of the usual three.
+    pub fn rock_paper_scissors_lizard_spock() -> Game {
+        Game::new(&[("Rock", 1), ("Spock", 2), ("Paper", 3), ("Lizard", 4), ("Scissors", 5)]).unwrap()
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn shapes(&self) -> impl Iterator<Item = Shape> + '_ {
+        (0..self.len()).map(|index| self.shape_at(index))
+    }
+
+    pub fn shape(&self, name: &str) -> Option<Shape> {
+        self.names.iter().position(|n| n == name).map(|index| self.shape_at(index))
+    }
+
+    /// The shape at `index` in the circle, which has to be less than [`Game::len`].
+    pub(crate) fn shape_at(&self, index: usize) -> Shape {
+        Shape { game: self.id, index }
+    }
+
+    /// Where `shape` is in the circle, as long as it's one of this game's.
+    pub(crate) fn check(&self, shape: Shape) -> Result<usize, GameError> {
+        if shape.game == self.id {
+            Ok(shape.index)
+        } else {
+            Err(GameError::UnknownShape(shape))
+        }
+    }
+
+    pub fn name(&self, shape: Shape) -> Result<&str, GameError> {
+        Ok(&self.names[self.check(shape)?])
+    }
+
+    /// Points for throwing `shape`.
+    pub fn shape_score(&self, shape: Shape) -> Result<u32, GameError> {
+        Ok(self.shape_score_at(self.check(shape)?))
+    }
+
+    /// [`Game::shape_score`] for the shape at `index`.
+    pub(crate) fn shape_score_at(&self, index: usize) -> u32 {
+        self.scores[index]
+    }
+
+    /// Points for finishing a game this way.
+    pub fn outcome_score(&self, outcome: &GameOutcome) -> u32 {
+        match outcome {
+            GameOutcome::Loss => self.outcome_scores[0],
+            GameOutcome::Draw => self.outcome_scores[1],
+            GameOutcome::Win => self.outcome_scores[2],
+        }
+    }
+
+    /// The outcome of the game for `a`.
+    pub fn play(&self, a: Shape, b: Shape) -> Result<GameOutcome, GameError> {
+        Ok(self.play_at(self.check(a)?, self.check(b)?))
+    }
+
+    /// [`Game::play`] for the shapes at indexes `a` and `b`.
+    pub(crate) fn play_at(&self, a: usize, b: usize) -> GameOutcome {
+        // how far round the circle a is from b
+        let ahead = (a + self.len() - b) % self.len();
+        if ahead == 0 {
+            GameOutcome::Draw
+        } else if ahead <= self.len() / 2 {
+            GameOutcome::Win
+        } else {
+            GameOutcome::Loss
+        }
+    }
+
+    /// A shape to throw against `opponent` to get `outcome`. With more than three shapes there's
+    /// more than one way to win or lose, and this is the shape next to the opponent's in the circle.
+    pub fn rig(&self, opponent: Shape, outcome: &GameOutcome) -> Result<Shape, GameError> {
+        Ok(self.shape_at(self.rig_at(self.check(opponent)?, outcome)))
+    }
+
+    /// [`Game::rig`] for the shape at index `opponent`, giving the index to throw.
+    pub(crate) fn rig_at(&self, opponent: usize, outcome: &GameOutcome) -> usize {
+        match outcome {
+            GameOutcome::Loss => (opponent + self.len() - 1) % self.len(),
+            GameOutcome::Draw => opponent,
+            GameOutcome::Win => (opponent + 1) % self.len(),
+        }
+    }
+
+    /// Points `a` earns from their shape and the outcome of the game.
+    pub fn score(&self, a: Shape, b: Shape) -> Result<u32, GameError> {
+        Ok(self.score_at(self.check(a)?, self.check(b)?))
+    }
+
+    /// [`Game::score`] for the shapes at indexes `a` and `b`.
+    pub(crate) fn score_at(&self, a: usize, b: usize) -> u32 {
+        self.shape_score_at(a) + self.outcome_score(&self.play_at(a, b))
+    }
+
+    pub fn throw(&self, name: &str) -> Option<Throw<'_>> {
+        self.shape(name).map(|shape| Throw { game: self, shape })
+    }
+}
+
+/// A shape thrown in some game, so it can be scored without saying which game again.
+pub trait Hand {
+    fn game(&self) -> &Game;
+    fn shape(&self) -> Shape;
+}
+
+/// A shape of any [`Game`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Throw<'g> {
+    game: &'g Game,
+    shape: Shape,
+}
+
+impl Hand for Throw<'_> {
+    fn game(&self) -> &Game {
+        self.game
+    }
+
+    fn shape(&self) -> Shape {
+        self.shape
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid() {
+        assert_eq!(Err(GameError::ShapeCount(4)), Game::new(&[("a", 1), ("b", 2), ("c", 3), ("d", 4)]));
+        assert_eq!(Err(GameError::ShapeCount(1)), Game::new(&[("a", 1)]));
+        assert_eq!(Err(GameError::DuplicateShape("a".to_string())), Game::new(&[("a", 1), ("b", 2), ("a", 3)]));
+    }
+
+    #[test]
+    fn lizard_spock() {
+        let game = Game::rock_paper_scissors_lizard_spock();
+        let shape = |name| game.shape(name).unwrap();
+        let beats = [
+            ("Scissors", "Paper"), ("Paper", "Rock"), ("Rock", "Lizard"), ("Lizard", "Spock"), ("Spock", "Scissors"),
+            ("Scissors", "Lizard"), ("Lizard", "Paper"), ("Paper", "Spock"), ("Spock", "Rock"), ("Rock", "Scissors"),
+        ];
+
+        for a in game.shapes() {
+            for b in game.shapes() {
+                let pair = (game.name(a).unwrap(), game.name(b).unwrap());
+                let expected = if a == b {
+                    GameOutcome::Draw
+                } else if beats.contains(&pair) {
+                    GameOutcome::Win
+                } else {
+                    GameOutcome::Loss
+                };
+                assert_eq!(Ok(expected), game.play(a, b), "{:?}", pair);
+            }
+        }
+
+        assert_eq!(Ok(shape("Spock")), game.rig(shape("Rock"), &GameOutcome::Win));
+        assert_eq!(Ok(shape("Scissors")), game.rig(shape("Rock"), &GameOutcome::Loss));
+    }
+
+    #[test]
+    fn rig() {
+        for game in [Game::rock_paper_scissors(), Game::rock_paper_scissors_lizard_spock()] {
+            for opponent in game.shapes() {
+                for outcome in [GameOutcome::Loss, GameOutcome::Draw, GameOutcome::Win] {
+                    let shape = game.rig(opponent, &outcome).unwrap();
+                    assert_eq!(Ok(outcome), game.play(shape, opponent));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn scores() {
+        let game = Game::rock_paper_scissors_lizard_spock().with_outcome_scores(1, 2, 10);
+        let rock = game.throw("Rock").unwrap();
+        let lizard = game.throw("Lizard").unwrap();
+
+        assert_eq!(Ok(1 + 10), game.score(rock.shape(), lizard.shape()));
+        assert_eq!(Ok(4 + 1), game.score(lizard.shape(), rock.shape()));
+        assert_eq!(None, game.throw("Dynamite"));
+    }
+
+    #[test]
+    fn unknown_shape() {
+        let classic = Game::rock_paper_scissors();
+        let lizard = Game::rock_paper_scissors_lizard_spock().shape("Lizard").unwrap();
+        let rock = classic.shape("Rock").unwrap();
+
+        assert_eq!(Err(GameError::UnknownShape(lizard)), classic.shape_score(lizard));
+        assert_eq!(Err(GameError::UnknownShape(lizard)), classic.play(rock, lizard));
+        assert_eq!(Err(GameError::UnknownShape(lizard)), classic.rig(lizard, &GameOutcome::Win));
+        assert_eq!(Err(GameError::UnknownShape(lizard)), classic.score(lizard, rock));
+        assert_eq!("shape 4 is from a different game", GameError::UnknownShape(lizard).to_string());
+    }
+
+    #[test]
+    fn same_size_game() {
+        let classic = Game::rock_paper_scissors();
+        let elements = Game::new(&[("Fire", 10), ("Water", 20), ("Wood", 30)]).unwrap();
+        let water = elements.shape("Water").unwrap();
+
+        assert_eq!(Err(GameError::UnknownShape(water)), classic.play(water, classic.shape("Rock").unwrap()));
+        assert_eq!(Err(GameError::UnknownShape(water)), classic.name(water));
+        assert_eq!(Ok("Water"), elements.clone().name(water));
+    }
+}
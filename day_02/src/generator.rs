@@ -0,0 +1,25 @@
+use util::Rng;
+
+/// `scale` rounds of the strategy guide.
+pub fn generate(rng: &mut Rng, scale: usize) -> String {
+    (0..scale)
+        .map(|_| format!("{} {}\n", rng.choose(&['A', 'B', 'C']), rng.choose(&['X', 'Y', 'Z'])))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use util::{Part, Puzzle, Report, Timer};
+
+    use super::*;
+    use crate::Day02;
+
+    #[test]
+    fn solvable() {
+        let input = generate(&mut Rng::new(1), 100);
+
+        assert_eq!(100, input.lines().count());
+        assert_eq!(input, generate(&mut Rng::new(1), 100));
+        assert!(Puzzle::of::<Day02>().solve(&input, &Part::all(), &Timer::with_report(Report::Quiet)).is_ok());
+    }
+}
@@ -0,0 +1,78 @@
+//! Day 2: Rock Paper Scissors - scoring a tournament from an encrypted strategy guide.
+
+pub mod game;
+pub mod rockpaperscissors;
+pub mod tournament;
+pub mod strategy_guide;
+pub mod generator;
+
+use std::fmt::Display;
+
+pub use game::{Game, GameError, GameOutcome, Hand, Shape, Throw};
+pub use rockpaperscissors::RockPaperScissors;
+pub use strategy_guide::{Round, StrategyGuide, WrongStrategyGuide, CorrectStrategyGuide};
+pub use tournament::Tournament;
+use util::{NoAnswer, ParseError, Rng, Solution, parse_lines, write_lines};
+
+/// Scores every [`Round`] of the guide, reading it the wrong way for part 1 and the intended way for part 2.
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const MANIFEST_DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    type Input = Vec<Round>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input)
+    }
+
+    fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+        Some(generator::generate(rng, scale))
+    }
+
+    fn serialize(rounds: &Vec<Round>) -> Option<String> {
+        Some(write_lines(rounds))
+    }
+
+    fn part_1(rounds: &Vec<Round>) -> Result<impl Display, NoAnswer> {
+        Ok(rounds.iter()
+            .map(WrongStrategyGuide::selection_pair)
+            .map(|(a, b)| RockPaperScissors::score_game(&a, &b))
+            .sum::<u32>())
+    }
+
+    fn part_2(rounds: &Vec<Round>) -> Result<impl Display, NoAnswer> {
+        Ok(rounds.iter()
+            .map(CorrectStrategyGuide::selection_pair)
+            .map(|(a, b)| RockPaperScissors::score_game(&a, &b))
+            .sum::<u32>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::{TestCaseError, prop_assert_eq, proptest};
+    use util::round_trip;
+
+    use super::*;
+
+    #[test]
+    fn fixtures() {
+        util::fixtures::assert_fixtures(util::Puzzle::of::<Day02>());
+    }
+
+    proptest! {
+        #[test]
+        fn parse_anything(input in "[ABCXYZ \\n]*") {
+            round_trip::check::<Day02>(&input).map_err(TestCaseError::fail)?;
+        }
+
+        #[test]
+        fn round_trip(seed: u64, scale in 1..50usize) {
+            let input = Day02::generate(&mut Rng::new(seed), scale).unwrap();
+
+            prop_assert_eq!(Ok(true), round_trip::check::<Day02>(&input));
+        }
+    }
+}
@@ -1,4 +1,14 @@
-#[derive(Debug, PartialEq, Eq)]
+use lazy_static::lazy_static;
+
+use crate::game::{Game, GameError, Hand, Shape};
+pub use crate::game::GameOutcome;
+
+lazy_static! {
+    static ref CLASSIC: Game = Game::rock_paper_scissors();
+}
+
+/// A shape either player can throw.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum RockPaperScissors {
     Rock,
     Paper,
@@ -6,69 +16,51 @@ pub enum RockPaperScissors {
 }
 
 impl RockPaperScissors {
+    /// Every shape, in the same order as [`Game::rock_paper_scissors`]'s circle.
+    const ALL: [RockPaperScissors; 3] = [RockPaperScissors::Rock, RockPaperScissors::Paper, RockPaperScissors::Scissors];
+
+    /// Where this shape is in the classic game's circle.
+    fn index(&self) -> usize {
+        *self as usize
+    }
+
+    /// Points for throwing this shape.
     pub fn score(&self) -> u32 {
-        match self {
-            RockPaperScissors::Rock => 1,
-            RockPaperScissors::Paper => 2,
-            RockPaperScissors::Scissors => 3,
-        }
+        CLASSIC.shape_score_at(self.index())
     }
 
+    /// The outcome of the game for `a`.
     pub fn play(a: &RockPaperScissors, b: &RockPaperScissors) -> GameOutcome {
-        match a {
-            RockPaperScissors::Rock => match b {
-                RockPaperScissors::Rock => GameOutcome::Draw,
-                RockPaperScissors::Paper => GameOutcome::Loss,
-                RockPaperScissors::Scissors => GameOutcome::Win,
-            },
-            RockPaperScissors::Paper => match b {
-                RockPaperScissors::Rock => GameOutcome::Win,
-                RockPaperScissors::Paper => GameOutcome::Draw,
-                RockPaperScissors::Scissors => GameOutcome::Loss,
-            },
-            RockPaperScissors::Scissors => match b {
-                RockPaperScissors::Rock => GameOutcome::Loss,
-                RockPaperScissors::Paper => GameOutcome::Win,
-                RockPaperScissors::Scissors => GameOutcome::Draw,
-            },
-        }
+        CLASSIC.play_at(a.index(), b.index())
     }
 
+    /// The shape to throw against `opponent` to get `outcome`.
     pub fn rig(opponent: &RockPaperScissors, outcome: &GameOutcome) -> RockPaperScissors {
-        match opponent {
-            RockPaperScissors::Rock => match outcome {
-                GameOutcome::Loss => RockPaperScissors::Scissors,
-                GameOutcome::Draw => RockPaperScissors::Rock,
-                GameOutcome::Win => RockPaperScissors::Paper,
-            },
-            RockPaperScissors::Paper => match outcome {
-                GameOutcome::Loss => RockPaperScissors::Rock,
-                GameOutcome::Draw => RockPaperScissors::Paper,
-                GameOutcome::Win => RockPaperScissors::Scissors,
-            },
-            RockPaperScissors::Scissors => match outcome {
-                GameOutcome::Loss => RockPaperScissors::Paper,
-                GameOutcome::Draw => RockPaperScissors::Scissors,
-                GameOutcome::Win => RockPaperScissors::Rock,
-            },
-        }
+        RockPaperScissors::ALL[CLASSIC.rig_at(opponent.index(), outcome)]
+    }
+
+    /// Points `a` earns from their shape and the outcome of the game against `b`.
+    pub fn score_game(a: &RockPaperScissors, b: &RockPaperScissors) -> u32 {
+        CLASSIC.score_at(a.index(), b.index())
     }
 }
 
-#[derive(Debug, PartialEq, Eq)]
-pub enum GameOutcome {
-    Loss,
-    Draw,
-    Win,
+/// The classic shape, as long as `shape` came from the same game as these shapes' [`Hand::game`].
+impl TryFrom<Shape> for RockPaperScissors {
+    type Error = GameError;
+
+    fn try_from(shape: Shape) -> Result<Self, Self::Error> {
+        Ok(RockPaperScissors::ALL[CLASSIC.check(shape)?])
+    }
 }
 
-impl GameOutcome {
-    pub fn score(&self) -> u32 {
-        match self {
-            GameOutcome::Loss => 0,
-            GameOutcome::Draw => 3,
-            GameOutcome::Win => 6,
-        }
+impl Hand for RockPaperScissors {
+    fn game(&self) -> &Game {
+        &CLASSIC
+    }
+
+    fn shape(&self) -> Shape {
+        CLASSIC.shape_at(self.index())
     }
 }
 
@@ -85,9 +77,21 @@ mod tests {
 
     #[test]
     fn outcome_scores() {
-        assert_eq!(0, GameOutcome::Loss.score());
-        assert_eq!(3, GameOutcome::Draw.score());
-        assert_eq!(6, GameOutcome::Win.score());
+        assert_eq!(0, CLASSIC.outcome_score(&GameOutcome::Loss));
+        assert_eq!(3, CLASSIC.outcome_score(&GameOutcome::Draw));
+        assert_eq!(6, CLASSIC.outcome_score(&GameOutcome::Win));
+    }
+
+    #[test]
+    fn shapes() {
+        for shape in [RockPaperScissors::Rock, RockPaperScissors::Paper, RockPaperScissors::Scissors] {
+            assert_eq!(Ok(shape), RockPaperScissors::try_from(shape.shape()));
+        }
+
+        let lizard = Game::rock_paper_scissors_lizard_spock().shape("Lizard").unwrap();
+        assert_eq!(Err(GameError::UnknownShape(lizard)), RockPaperScissors::try_from(lizard));
+        let rock = Game::rock_paper_scissors().shape("Rock").unwrap();
+        assert_eq!(Err(GameError::UnknownShape(rock)), RockPaperScissors::try_from(rock));
     }
 
     #[test]
@@ -105,6 +109,13 @@ mod tests {
         assert_eq!(GameOutcome::Draw, RockPaperScissors::play(&RockPaperScissors::Scissors, &RockPaperScissors::Scissors));
     }
 
+    #[test]
+    fn score_games() {
+        assert_eq!(8, RockPaperScissors::score_game(&RockPaperScissors::Paper, &RockPaperScissors::Rock));
+        assert_eq!(1, RockPaperScissors::score_game(&RockPaperScissors::Rock, &RockPaperScissors::Paper));
+        assert_eq!(6, RockPaperScissors::score_game(&RockPaperScissors::Scissors, &RockPaperScissors::Scissors));
+    }
+
     #[test]
     fn rig_games() {
         assert_eq!(RockPaperScissors::Rock, RockPaperScissors::rig(&RockPaperScissors::Rock, &GameOutcome::Draw));
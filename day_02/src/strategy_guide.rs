@@ -1,5 +1,9 @@
+use std::fmt;
+use std::str::FromStr;
+
 use lazy_static::lazy_static;
 use regex::Regex;
+use util::ParseError;
 
 use crate::rockpaperscissors::{
     RockPaperScissors, RockPaperScissors::Rock, RockPaperScissors::Paper, RockPaperScissors::Scissors,
@@ -8,43 +12,71 @@ use crate::rockpaperscissors::{
 
 
 lazy_static! {
-    static ref PAIR: Regex = Regex::new(r"(?P<first>[ABC])\s(?P<second>[XYZ])").unwrap();
+    static ref PAIR: Regex = Regex::new(r"^(?P<first>[ABC])\s(?P<second>[XYZ])$").unwrap();
+}
+
+/// One line of the encrypted strategy guide, before either guide decides what it means.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Round {
+    first: char,
+    second: char,
+}
+
+impl FromStr for Round {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let capture = PAIR.captures(line.trim())
+            .ok_or_else(|| ParseError::new(line, "a round like 'A Y' - (A, B, C) then (X, Y, Z)"))?;
+        let first = capture["first"].chars().next().unwrap();
+        let second = capture["second"].chars().next().unwrap();
+
+        Ok(Round { first, second })
+    }
+}
+
+impl fmt::Display for Round {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.first, self.second)
+    }
 }
 
+/// A way of reading a [`Round`] as the shapes we and the opponent throw.
 pub trait StrategyGuide {
-    fn selection_pair(line: &str) -> (RockPaperScissors, RockPaperScissors);
+    /// Our shape, then the opponent's.
+    fn selection_pair(round: &Round) -> (RockPaperScissors, RockPaperScissors);
 }
 
+/// Reads both columns as shapes, which is what we assumed at first.
 pub struct WrongStrategyGuide {}
 
 impl StrategyGuide for WrongStrategyGuide {
-    fn selection_pair(line: &str) -> (RockPaperScissors, RockPaperScissors) {
-        let capture = PAIR.captures(line).unwrap();
-        let first = WrongStrategyGuide::decode(&capture["first"]);
-        let second = WrongStrategyGuide::decode(&capture["second"]);
+    fn selection_pair(round: &Round) -> (RockPaperScissors, RockPaperScissors) {
+        let first = WrongStrategyGuide::decode(round.first);
+        let second = WrongStrategyGuide::decode(round.second);
         // reverse order because 'we' are the second column
         (second, first)
     }
 }
 
 impl WrongStrategyGuide {
-    fn decode(value: &str) -> RockPaperScissors {
+    fn decode(value: char) -> RockPaperScissors {
         match value {
-            "A" | "X" => Rock,
-            "B" | "Y" => Paper,
-            "C" | "Z" => Scissors,
-            _ => panic!("Unexpected value '{}' - must be in (A, B, C, X, Y, Z)", value),
+            'A' | 'X' => Rock,
+            'B' | 'Y' => Paper,
+            'C' | 'Z' => Scissors,
+            _ => unreachable!("rounds only hold (A, B, C, X, Y, Z)"),
         }
     }
 }
 
+/// Reads the second column as how the round needs to end.
 pub struct CorrectStrategyGuide {}
 
 impl StrategyGuide for CorrectStrategyGuide {
-    fn selection_pair(line: &str) -> (RockPaperScissors, RockPaperScissors) {
-        let capture = PAIR.captures(line).unwrap();
-        let first = CorrectStrategyGuide::decode_selection(&capture["first"]);
-        let outcome = CorrectStrategyGuide::decode_outcome(&capture["second"]);
+    fn selection_pair(round: &Round) -> (RockPaperScissors, RockPaperScissors) {
+        let first = CorrectStrategyGuide::decode_selection(round.first);
+        let outcome = CorrectStrategyGuide::decode_outcome(round.second);
 
         let second = RockPaperScissors::rig(&first, &outcome);
         
@@ -54,21 +86,21 @@ impl StrategyGuide for CorrectStrategyGuide {
 }
 
 impl CorrectStrategyGuide {
-    fn decode_selection(value: &str) -> RockPaperScissors {
+    fn decode_selection(value: char) -> RockPaperScissors {
         match value {
-            "A" => Rock,
-            "B" => Paper,
-            "C" => Scissors,
-            _ => panic!("Unexpected value '{}' - must be in (A, B, C)", value),
+            'A' => Rock,
+            'B' => Paper,
+            'C' => Scissors,
+            _ => unreachable!("rounds only hold (A, B, C) in the first column"),
         }
     }
 
-    fn decode_outcome(value: &str) -> GameOutcome {
+    fn decode_outcome(value: char) -> GameOutcome {
         match value {
-            "X" => Loss,
-            "Y" => Draw,
-            "Z" => Win,
-            _ => panic!("Unexpected value '{}' - must be in (X, Y, Z)", value),
+            'X' => Loss,
+            'Y' => Draw,
+            'Z' => Win,
+            _ => unreachable!("rounds only hold (X, Y, Z) in the second column"),
         }
     }
 }
@@ -77,17 +109,33 @@ impl CorrectStrategyGuide {
 mod tests {
     use super::*;
 
+    fn round(line: &str) -> Round {
+        line.parse().unwrap()
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(Round { first: 'B', second: 'Z' }, round("B Z"));
+
+        let error = "D Y".parse::<Round>().unwrap_err();
+        assert_eq!("D Y", error.text());
+        assert!("A".parse::<Round>().is_err());
+        assert!("A YY".parse::<Round>().is_err());
+
+        assert_eq!("B Z", round(" B Z").to_string());
+    }
+
     #[test]
     fn wrong() {
-        assert_eq!((Paper, Rock), WrongStrategyGuide::selection_pair("A Y"));
-        assert_eq!((Rock, Paper), WrongStrategyGuide::selection_pair("B X"));
-        assert_eq!((Scissors, Scissors), WrongStrategyGuide::selection_pair("C Z"));
+        assert_eq!((Paper, Rock), WrongStrategyGuide::selection_pair(&round("A Y")));
+        assert_eq!((Rock, Paper), WrongStrategyGuide::selection_pair(&round("B X")));
+        assert_eq!((Scissors, Scissors), WrongStrategyGuide::selection_pair(&round("C Z")));
     }
 
     #[test]
     fn correct() {
-        assert_eq!((Rock, Rock), CorrectStrategyGuide::selection_pair("A Y"));
-        assert_eq!((Rock, Paper), CorrectStrategyGuide::selection_pair("B X"));
-        assert_eq!((Rock, Scissors), CorrectStrategyGuide::selection_pair("C Z"));
+        assert_eq!((Rock, Rock), CorrectStrategyGuide::selection_pair(&round("A Y")));
+        assert_eq!((Rock, Paper), CorrectStrategyGuide::selection_pair(&round("B X")));
+        assert_eq!((Rock, Scissors), CorrectStrategyGuide::selection_pair(&round("C Z")));
     }
 }
\ No newline at end of file
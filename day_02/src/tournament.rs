@@ -1,26 +1,53 @@
-use crate::rockpaperscissors::RockPaperScissors;
+use crate::game::{GameError, Hand};
 
+/// Scoring for a tournament of rock paper scissors games, or of any other [`Game`](crate::Game).
 pub struct Tournament {}
 
 impl Tournament {
-    pub fn score_game(a: &RockPaperScissors, b: &RockPaperScissors) -> u32 {
-        let outcome = RockPaperScissors::play(a, b);
-        a.score() + outcome.score()
+    /// Points `a` earns from their shape and the outcome of the game, as long as both hands were
+    /// thrown in the same game.
+    pub fn score_game<H: Hand>(a: &H, b: &H) -> Result<u32, GameError> {
+        if a.game() != b.game() {
+            return Err(GameError::DifferentGames);
+        }
+        a.game().score(a.shape(), b.shape())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::game::Game;
     use crate::rockpaperscissors::{RockPaperScissors::Rock, RockPaperScissors::Paper, RockPaperScissors::Scissors};
 
     #[test]
     fn small() {
-
         let mut total = 0;
-        total += Tournament::score_game(&Paper, &Rock);
-        total += Tournament::score_game(&Rock, &Paper);
-        total += Tournament::score_game(&Scissors, &Scissors);
+        total += Tournament::score_game(&Paper, &Rock).unwrap();
+        total += Tournament::score_game(&Rock, &Paper).unwrap();
+        total += Tournament::score_game(&Scissors, &Scissors).unwrap();
         assert_eq!(15, total);
     }
+
+    #[test]
+    fn lizard_spock() {
+        let game = Game::rock_paper_scissors_lizard_spock();
+        let throw = |name| game.throw(name).unwrap();
+
+        let mut total = 0;
+        total += Tournament::score_game(&throw("Spock"), &throw("Rock")).unwrap();
+        total += Tournament::score_game(&throw("Lizard"), &throw("Scissors")).unwrap();
+        total += Tournament::score_game(&throw("Paper"), &throw("Paper")).unwrap();
+        assert_eq!(8 + 4 + 6, total);
+    }
+
+    #[test]
+    fn different_games() {
+        let (classic, lizard_spock) = (Game::rock_paper_scissors(), Game::rock_paper_scissors_lizard_spock());
+
+        let rock = classic.throw("Rock").unwrap();
+        let spock = lizard_spock.throw("Spock").unwrap();
+        assert_eq!(Err(GameError::DifferentGames), Tournament::score_game(&rock, &spock));
+        assert_eq!(Err(GameError::DifferentGames), Tournament::score_game(&spock, &rock));
+    }
 }
\ No newline at end of file
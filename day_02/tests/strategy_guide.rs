@@ -0,0 +1,34 @@
+use day_02::{CorrectStrategyGuide, Day02, Game, GameOutcome, Hand, RockPaperScissors, Round, StrategyGuide, Tournament, WrongStrategyGuide};
+use util::Solution;
+
+#[test]
+fn example() {
+    let rounds = Day02::parse(include_str!("../fixtures/example.txt")).unwrap();
+
+    let score = |guide: fn(&Round) -> (RockPaperScissors, RockPaperScissors)| rounds.iter()
+        .map(guide)
+        .map(|(ours, theirs)| Tournament::score_game(&ours, &theirs).unwrap())
+        .sum::<u32>();
+
+    assert_eq!(15, score(WrongStrategyGuide::selection_pair));
+    assert_eq!(12, score(CorrectStrategyGuide::selection_pair));
+}
+
+#[test]
+fn games() {
+    let shape = RockPaperScissors::rig(&RockPaperScissors::Scissors, &GameOutcome::Win);
+
+    assert_eq!(RockPaperScissors::Rock, shape);
+    assert_eq!(GameOutcome::Win, RockPaperScissors::play(&shape, &RockPaperScissors::Scissors));
+    assert_eq!(Ok(7), Tournament::score_game(&shape, &RockPaperScissors::Scissors));
+}
+
+#[test]
+fn any_game() {
+    let game = Game::new(&[("Fire", 10), ("Water", 20), ("Wood", 30)]).unwrap().with_outcome_scores(0, 1, 2);
+    let (fire, water) = (game.throw("Fire").unwrap(), game.throw("Water").unwrap());
+
+    assert_eq!(Ok(GameOutcome::Win), game.play(water.shape(), fire.shape()));
+    assert_eq!(Ok(water.shape()), game.rig(fire.shape(), &GameOutcome::Win));
+    assert_eq!(Ok(22), Tournament::score_game(&water, &fire));
+}
@@ -0,0 +1,53 @@
+use util::Rng;
+
+const ITEMS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// `scale` rucksacks, rounded up to whole groups of 3.
+pub fn generate(rng: &mut Rng, scale: usize) -> String {
+    let mut rucksacks = String::new();
+    for _ in 0..scale.div_ceil(3).max(1) {
+        let mut items = ITEMS.to_vec();
+        rng.shuffle(&mut items);
+        let badge = items.pop().unwrap();
+
+        // each elf packs from their own share of the other items, so only the badge is in all 3
+        for share in items.chunks(items.len() / 3).take(3) {
+            rucksacks += &rucksack(rng, badge, share);
+            rucksacks.push('\n');
+        }
+    }
+    rucksacks
+}
+
+/// A rucksack holding the badge, where only one item from `share` is in both compartments.
+fn rucksack(rng: &mut Rng, badge: u8, share: &[u8]) -> String {
+    let (left, right) = share.split_at(share.len() / 2);
+    let common = *rng.choose(share);
+    let size = rng.below(15) + 2;
+
+    let mut first = vec![common, badge];
+    first.extend((2..size).map(|_| *rng.choose(left)));
+    let mut second = vec![common];
+    second.extend((1..size).map(|_| *rng.choose(right)));
+    rng.shuffle(&mut first);
+    rng.shuffle(&mut second);
+
+    first.into_iter().chain(second).map(char::from).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use util::{Part, Puzzle, Report, Timer};
+
+    use super::*;
+    use crate::Day03;
+
+    #[test]
+    fn solvable() {
+        let input = generate(&mut Rng::new(1), 100);
+
+        assert_eq!(102, input.lines().count());
+        assert_eq!(input, generate(&mut Rng::new(1), 100));
+        assert!(Puzzle::of::<Day03>().solve(&input, &Part::all(), &Timer::with_report(Report::Quiet)).is_ok());
+    }
+}
@@ -0,0 +1,78 @@
+//! Day 3: Rucksack Reorganization - finding the item types that ended up in the wrong place.
+
+pub mod rucksack;
+pub mod generator;
+
+use std::fmt::Display;
+
+pub use rucksack::{ElfGroup, Rucksack};
+use util::{NoAnswer, ParseError, Rng, Solution, parse_lines, write_lines};
+
+/// Sums the priorities of each [`Rucksack`]'s misplaced item, then of each [`ElfGroup`]'s badge.
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const MANIFEST_DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    type Input = Vec<Rucksack>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input)
+    }
+
+    fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+        Some(generator::generate(rng, scale))
+    }
+
+    fn serialize(rucksacks: &Vec<Rucksack>) -> Option<String> {
+        Some(write_lines(rucksacks))
+    }
+
+    fn part_1(rucksacks: &Vec<Rucksack>) -> Result<impl Display, NoAnswer> {
+        Ok(rucksacks.iter()
+            .map(|r| r.common_priority())
+            .sum::<u32>())
+    }
+
+    fn part_2(rucksacks: &Vec<Rucksack>) -> Result<impl Display, NoAnswer> {
+        let mut total_priority = 0;
+        let mut elf_group = ElfGroup::new();
+        for rucksack in rucksacks {
+            elf_group.add(rucksack.items().to_string());
+            if elf_group.len() == 3 {
+                total_priority += elf_group.priority();
+                elf_group.reset();
+            }
+        }
+
+        Ok(total_priority)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::{TestCaseError, prop_assert_eq, proptest};
+    use util::round_trip;
+
+    use super::*;
+
+    #[test]
+    fn fixtures() {
+        util::fixtures::assert_fixtures(util::Puzzle::of::<Day03>());
+    }
+
+    proptest! {
+        #[test]
+        fn parse_anything(input in "[a-zA-Z \\n]*") {
+            round_trip::check::<Day03>(&input).map_err(TestCaseError::fail)?;
+        }
+
+        #[test]
+        fn round_trip(seed: u64, scale in 1..50usize) {
+            let input = Day03::generate(&mut Rng::new(seed), scale).unwrap();
+
+            prop_assert_eq!(Ok(true), round_trip::check::<Day03>(&input));
+        }
+    }
+}
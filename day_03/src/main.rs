@@ -1,45 +1,8 @@
-mod rucksack;
+use std::process::ExitCode;
 
-use std::{fs::File, io::{BufReader, BufRead, Lines}};
+use day_03::Day03;
+use util::Puzzle;
 
-use rucksack::ElfGroup;
-use util::Timer;
-
-use crate::rucksack::Rucksack;
-
-fn main() -> std::io::Result<()> {
-    let _timer = Timer::new();
-    
-    let file = File::open("./day_03/input.txt")?;
-    let lines = BufReader::new(file).lines();
-
-    run_part_2(lines);
-
-    Ok(())
+fn main() -> ExitCode {
+    util::cli::main(Puzzle::of::<Day03>())
 }
-
-#[allow(dead_code)]
-fn run_part_1(lines: Lines<BufReader<File>>) {
-    let total_priority = lines
-        .map(Result::unwrap)
-        .map(|l| Rucksack::from(&l))
-        .map(|r| r.common_priority())
-        .sum::<u32>();
-
-    println!("The sum of the common priorities in all rucksacks is {}", total_priority);
-}
-
-fn run_part_2(lines: Lines<BufReader<File>>) {
-    let mut total_priority = 0;
-    let mut elf_group = ElfGroup::new();
-    for line in lines {
-        let line = line.unwrap();
-        elf_group.add(line);
-        if elf_group.len() == 3 {
-            total_priority += elf_group.priority();
-            elf_group.reset();
-        }
-    }
-
-    println!("The sum of the common badge priorities in all three-elf groups is {}", total_priority);
-}
\ No newline at end of file
@@ -1,24 +1,52 @@
 use std::collections::HashSet;
+use std::fmt;
+use std::str::FromStr;
 
+use util::ParseError;
+
+/// A rucksack's items, split evenly between two compartments that share exactly one item type.
 pub struct Rucksack {
+    items: String,
     common: HashSet<char>,
 }
 
-impl Rucksack {
-    pub fn from(items: &str) -> Self {
+impl FromStr for Rucksack {
+    type Err = ParseError;
+
+    fn from_str(items: &str) -> Result<Self, Self::Err> {
+        if let Some((index, _)) = items.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+            return Err(ParseError::new(items, "items in a-z or A-Z").at_column(index + 1));
+        }
         // each rucksack must be equally splitable across two compartments
-        assert!(items.len() % 2 == 0);
+        if !items.len().is_multiple_of(2) {
+            return Err(ParseError::new(items, "an even number of items"));
+        }
         let midpoint = items.len() / 2;
-        let contents = vec![
+        let contents = [
             &items[0..midpoint],
             &items[midpoint..],
         ];
         let common = find_common_characters_pair(contents[0], contents[1]);
         // there should only be one matching item between compartments
-        assert_eq!(1, common.len());
-        Rucksack { common }
+        if common.len() != 1 {
+            return Err(ParseError::new(items, "exactly one item type in both compartments"));
+        }
+        Ok(Rucksack { items: items.to_string(), common })
     }
+}
+
+impl fmt::Display for Rucksack {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.items)
+    }
+}
 
+impl Rucksack {
+    pub fn items(&self) -> &str {
+        &self.items
+    }
+
+    /// Priority of the item type found in both compartments.
     pub fn common_priority(&self) -> u32 {
         priority(*self.common.iter().next().unwrap())
     }
@@ -36,6 +64,7 @@ fn find_common_characters_pair(left: &str, right: &str) -> HashSet<char> {
     common_characters
 }
 
+/// The rucksacks of a group of elves, whose badge is the one item type they all carry.
 #[derive(Default)]
 pub struct ElfGroup {
     rucksacks: Vec<String>,
@@ -54,6 +83,11 @@ impl ElfGroup {
         self.rucksacks.len()
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.rucksacks.is_empty()
+    }
+
+    /// Priority of the badge, which has to be the only item type in every rucksack.
     pub fn priority(&self) -> u32 {
         let common = find_common_characters_set(&self.rucksacks);
         assert_eq!(1, common.len());
@@ -90,7 +124,8 @@ fn find_common_characters_set(strings: &[String]) -> HashSet<char> {
     common_characters
 }
 
-fn priority(character: char) -> u32 {
+/// 1-26 for `a`-`z` and 27-52 for `A`-`Z`.
+pub fn priority(character: char) -> u32 {
     let code = character as u32;
     match code {
         // A-Z have priority 27-52
@@ -105,18 +140,30 @@ fn priority(character: char) -> u32 {
 mod tests {
     use super::*;
 
+    fn parse(items: &str) -> Rucksack {
+        items.parse().unwrap()
+    }
+
     #[test]
     fn rucksack() {
-        let rucksack = Rucksack::from("vJrwpWtwJgWrhcsFMMfFFhFp");
+        let rucksack = parse("vJrwpWtwJgWrhcsFMMfFFhFp");
         assert_eq!(vec!['p'], rucksack.common.into_iter().collect::<Vec<char>>());
 
-        let rucksack = Rucksack::from("jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL");
+        let rucksack = parse("jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL");
         assert_eq!(vec!['L'], rucksack.common.into_iter().collect::<Vec<char>>());
 
-        let rucksack = Rucksack::from("PmmdzqPrVvPwwTWBwg");
+        let rucksack = parse("PmmdzqPrVvPwwTWBwg");
         assert_eq!(vec!['P'], rucksack.common.into_iter().collect::<Vec<char>>());
     }
 
+    #[test]
+    fn invalid_rucksacks() {
+        assert_eq!(4, "abc1cd".parse::<Rucksack>().err().unwrap().column());
+        assert_eq!("an even number of items", "abcab".parse::<Rucksack>().err().unwrap().expected());
+        assert!("abcd".parse::<Rucksack>().is_err());
+        assert!("abab".parse::<Rucksack>().is_err());
+    }
+
     #[test]
     fn priority() {
         assert_eq!(16, super::priority('p'));
@@ -0,0 +1,26 @@
+use day_03::{Day03, ElfGroup, Rucksack};
+use day_03::rucksack::priority;
+use util::Solution;
+
+#[test]
+fn example() {
+    let rucksacks = Day03::parse(include_str!("../fixtures/example.txt")).unwrap();
+
+    assert_eq!(vec![16, 38, 42, 22, 20, 19], rucksacks.iter().map(Rucksack::common_priority).collect::<Vec<_>>());
+
+    let mut group = ElfGroup::new();
+    for rucksack in &rucksacks[..3] {
+        group.add(rucksack.items().to_string());
+    }
+    assert_eq!(3, group.len());
+    assert_eq!(priority('r'), group.priority());
+
+    group.reset();
+    assert!(group.is_empty());
+}
+
+#[test]
+fn invalid() {
+    assert!("ab1b".parse::<Rucksack>().is_err());
+    assert!(Day03::parse("vJrwpWtwJgWrhcsFMMfFFhFp\nabc\n").is_err());
+}
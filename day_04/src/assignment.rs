@@ -1,7 +1,9 @@
 use std::ops::RangeInclusive;
 
+/// The sections an elf has been assigned to clean.
 pub type Assignment = RangeInclusive<u8>;
 
+/// Whether either assignment covers every section of the other.
 pub fn assignments_redundant(a: Assignment, b: Assignment) -> bool {
     assert!(a.start() <= a.end());
     assert!(b.start() <= b.end());
@@ -11,6 +13,7 @@ pub fn assignments_redundant(a: Assignment, b: Assignment) -> bool {
     (b.start() <= a.start() && b.end() >= a.end())
 }
 
+/// Whether the assignments share any section.
 pub fn assignments_have_waste(a: Assignment, b: Assignment) -> bool {
     assert!(a.start() <= a.end());
     assert!(b.start() <= b.end());
@@ -34,40 +37,40 @@ mod tests {
 
     #[test]
     fn no_overlap() {
-        assert_eq!(false, assignments_redundant(11..=20, 21..=30));
-        assert_eq!(false, assignments_redundant(1..=10, 11..=20));
+        assert!(!assignments_redundant(11..=20, 21..=30));
+        assert!(!assignments_redundant(1..=10, 11..=20));
 
-        assert_eq!(false, assignments_have_waste(11..=20, 21..=30));
-        assert_eq!(false, assignments_have_waste(1..=10, 11..=20));
+        assert!(!assignments_have_waste(11..=20, 21..=30));
+        assert!(!assignments_have_waste(1..=10, 11..=20));
     }
 
     #[test]
     fn some_overlap() {
-        assert_eq!(false, assignments_redundant(11..=20, 16..=25));
-        assert_eq!(false, assignments_redundant(6..=15, 11..=20));
+        assert!(!assignments_redundant(11..=20, 16..=25));
+        assert!(!assignments_redundant(6..=15, 11..=20));
 
-        assert_eq!(false, assignments_redundant(11..=20, 20..=25));
-        assert_eq!(false, assignments_redundant(5..=11, 11..=20));
+        assert!(!assignments_redundant(11..=20, 20..=25));
+        assert!(!assignments_redundant(5..=11, 11..=20));
 
-        assert_eq!(true, assignments_have_waste(11..=20, 16..=25));
-        assert_eq!(true, assignments_have_waste(6..=15, 11..=20));
+        assert!(assignments_have_waste(11..=20, 16..=25));
+        assert!(assignments_have_waste(6..=15, 11..=20));
 
-        assert_eq!(true, assignments_have_waste(11..=20, 20..=25));
-        assert_eq!(true, assignments_have_waste(5..=11, 11..=20));
+        assert!(assignments_have_waste(11..=20, 20..=25));
+        assert!(assignments_have_waste(5..=11, 11..=20));
     }
 
     #[test]
     fn full_overlap() {
-        assert_eq!(true, assignments_redundant(1..=10, 2..=8));
-        assert_eq!(true, assignments_redundant(2..=8, 1..=10));
+        assert!(assignments_redundant(1..=10, 2..=8));
+        assert!(assignments_redundant(2..=8, 1..=10));
 
-        assert_eq!(true, assignments_redundant(1..=10, 10..=10));
-        assert_eq!(true, assignments_redundant(1..=1, 1..=10));
+        assert!(assignments_redundant(1..=10, 10..=10));
+        assert!(assignments_redundant(1..=1, 1..=10));
 
-        assert_eq!(true, assignments_have_waste(1..=10, 2..=8));
-        assert_eq!(true, assignments_have_waste(2..=8, 1..=10));
+        assert!(assignments_have_waste(1..=10, 2..=8));
+        assert!(assignments_have_waste(2..=8, 1..=10));
 
-        assert_eq!(true, assignments_have_waste(1..=10, 10..=10));
-        assert_eq!(true, assignments_have_waste(1..=1, 1..=10));
+        assert!(assignments_have_waste(1..=10, 10..=10));
+        assert!(assignments_have_waste(1..=1, 1..=10));
     }
 }
\ No newline at end of file
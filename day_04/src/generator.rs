@@ -0,0 +1,31 @@
+use util::Rng;
+
+/// `scale` pairs of section assignments within 1-99.
+pub fn generate(rng: &mut Rng, scale: usize) -> String {
+    let mut assignment = || {
+        let start = rng.range(1..=99);
+        let end = rng.range(start..=99);
+        format!("{}-{}", start, end)
+    };
+
+    (0..scale)
+        .map(|_| format!("{},{}\n", assignment(), assignment()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use util::{Part, Puzzle, Report, Timer};
+
+    use super::*;
+    use crate::Day04;
+
+    #[test]
+    fn solvable() {
+        let input = generate(&mut Rng::new(1), 100);
+
+        assert_eq!(100, input.lines().count());
+        assert_eq!(input, generate(&mut Rng::new(1), 100));
+        assert!(Puzzle::of::<Day04>().solve(&input, &Part::all(), &Timer::with_report(Report::Quiet)).is_ok());
+    }
+}
@@ -0,0 +1,75 @@
+//! Day 4: Camp Cleanup - finding pairs of elves whose section assignments overlap.
+
+pub mod assignment;
+pub mod parser;
+pub mod generator;
+
+use std::fmt::Display;
+
+pub use assignment::{Assignment, assignments_redundant, assignments_have_waste};
+pub use parser::{AssignmentPair, parse_assignments};
+use util::{NoAnswer, ParseError, Rng, Solution};
+
+/// Counts the pairs where one [`Assignment`] contains the other, then the pairs that overlap at all.
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const MANIFEST_DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    type Input = Vec<(Assignment, Assignment)>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.lines()
+            .enumerate()
+            .map(|(index, line)| parse_assignments(line).map_err(|e| e.offset_lines(index)))
+            .collect()
+    }
+
+    fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+        Some(generator::generate(rng, scale))
+    }
+
+    fn serialize(pairs: &Self::Input) -> Option<String> {
+        Some(pairs.iter().map(|(a, b)| format!("{}\n", AssignmentPair(a.clone(), b.clone()))).collect())
+    }
+
+    fn part_1(pairs: &Self::Input) -> Result<impl Display, NoAnswer> {
+        Ok(pairs.iter()
+            .filter(|(a, b)| assignments_redundant(a.clone(), b.clone()))
+            .count())
+    }
+
+    fn part_2(pairs: &Self::Input) -> Result<impl Display, NoAnswer> {
+        Ok(pairs.iter()
+            .filter(|(a, b)| assignments_have_waste(a.clone(), b.clone()))
+            .count())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::{TestCaseError, prop_assert_eq, proptest};
+    use util::round_trip;
+
+    use super::*;
+
+    #[test]
+    fn fixtures() {
+        util::fixtures::assert_fixtures(util::Puzzle::of::<Day04>());
+    }
+
+    proptest! {
+        #[test]
+        fn parse_anything(input in "[0-9,\\- \\n]*") {
+            round_trip::check::<Day04>(&input).map_err(TestCaseError::fail)?;
+        }
+
+        #[test]
+        fn round_trip(seed: u64, scale in 1..50usize) {
+            let input = Day04::generate(&mut Rng::new(seed), scale).unwrap();
+
+            prop_assert_eq!(Ok(true), round_trip::check::<Day04>(&input));
+        }
+    }
+}
@@ -1,22 +1,54 @@
+use std::fmt;
 use std::ops::RangeInclusive;
+use std::str::FromStr;
+
 use lazy_static::lazy_static;
 use regex::Regex;
+use util::{ParseError, parse_number};
 
 lazy_static! {
-    static ref PAIR: Regex = Regex::new(r"(?P<first_start>\d+)-(?P<first_end>\d+),(?P<second_start>\d+)-(?P<second_end>\d+)").unwrap();
+    static ref PAIR: Regex = Regex::new(r"^(?P<first_start>\d+)-(?P<first_end>\d+),(?P<second_start>\d+)-(?P<second_end>\d+)$").unwrap();
 }
-pub fn parse_assignments(line: &str) -> (RangeInclusive<u8>, RangeInclusive<u8>) {
-    let capture = PAIR.captures(line)
-        .unwrap_or_else(|| panic!("Line '{}' should match the regex", line));
-    let parse_id = |v: &str| v.parse::<u8>().unwrap();
-
-    let ids = vec!["first_start", "first_end", "second_start", "second_end"]
-        .iter()
-        .map(|n| &capture[*n])
-        .map(parse_id)
-        .collect::<Vec<u8>>();
-    
-    (ids[0]..=ids[1], ids[2]..=ids[3])
+
+/// The section assignments of a pair of elves, e.g. `2-4,6-8`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct AssignmentPair(pub RangeInclusive<u8>, pub RangeInclusive<u8>);
+
+impl FromStr for AssignmentPair {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let capture = PAIR.captures(line.trim())
+            .ok_or_else(|| ParseError::new(line, "a pair of section ranges like '2-4,6-8'"))?;
+
+        let mut ids = [0; 4];
+        for (id, name) in ids.iter_mut().zip(["first_start", "first_end", "second_start", "second_end"]) {
+            let m = capture.name(name).unwrap();
+            *id = parse_number(m.as_str(), m.start() + 1, "a section id in 0-255")?;
+        }
+
+        let [first_start, first_end, second_start, second_end] = ids;
+        if first_start > first_end {
+            return Err(ParseError::new(line, "the first range to start before it ends"));
+        }
+        if second_start > second_end {
+            return Err(ParseError::new(line, "the second range to start before it ends"));
+        }
+
+        Ok(AssignmentPair(first_start..=first_end, second_start..=second_end))
+    }
+}
+
+impl fmt::Display for AssignmentPair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{},{}-{}", self.0.start(), self.0.end(), self.1.start(), self.1.end())
+    }
+}
+
+/// Parses an [`AssignmentPair`] into its two ranges.
+pub fn parse_assignments(line: &str) -> Result<(RangeInclusive<u8>, RangeInclusive<u8>), ParseError> {
+    line.parse::<AssignmentPair>()
+        .map(|AssignmentPair(first, second)| (first, second))
 }
 
 #[cfg(test)]
@@ -25,8 +57,17 @@ mod tests {
 
     #[test]
     fn assignments() {
-        let (first, second) = parse_assignments("2-4,6-8");
+        let (first, second) = parse_assignments("2-4,6-8").unwrap();
         assert_eq!(2..=4, first);
         assert_eq!(6..=8, second);
+
+        assert_eq!("2-4,6-8", AssignmentPair(first, second).to_string());
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!("a pair of section ranges like '2-4,6-8'", parse_assignments("2-4").unwrap_err().expected());
+        assert_eq!(7, parse_assignments("2-4,6-800").unwrap_err().column());
+        assert!(parse_assignments("4-2,6-8").is_err());
     }
 }
\ No newline at end of file
@@ -0,0 +1,18 @@
+use day_04::{AssignmentPair, Day04, assignments_have_waste, assignments_redundant};
+use util::Solution;
+
+#[test]
+fn example() {
+    let pairs = Day04::parse(include_str!("../fixtures/example.txt")).unwrap();
+
+    assert_eq!(6, pairs.len());
+    assert_eq!((2..=4, 6..=8), pairs[0]);
+    assert_eq!(2, pairs.iter().filter(|(a, b)| assignments_redundant(a.clone(), b.clone())).count());
+    assert_eq!(4, pairs.iter().filter(|(a, b)| assignments_have_waste(a.clone(), b.clone())).count());
+}
+
+#[test]
+fn pairs() {
+    assert_eq!(AssignmentPair(6..=6, 4..=6), "6-6,4-6".parse().unwrap());
+    assert!("6-4,4-6".parse::<AssignmentPair>().is_err());
+}
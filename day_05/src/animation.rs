@@ -0,0 +1,76 @@
+use util::viz::{Cell, Colour, Frame, Viewer};
+
+use crate::crane::{Crane, CrateMover9000, CrateMover9001, Move, rearrange};
+use crate::crates::Crates;
+
+/// Rearranges the crates with each crane in turn, a frame per move.
+pub fn animate(crates: &Crates, moves: &[Move], viewer: &mut Viewer) {
+    play::<CrateMover9000>("CrateMover 9000", crates, moves, viewer);
+    play::<CrateMover9001>("CrateMover 9001", crates, moves, viewer);
+}
+
+fn play<C: Crane>(crane: &str, crates: &Crates, moves: &[Move], viewer: &mut Viewer) {
+    viewer.show(&frame(crates, None).with_caption(format!("{}, before moving anything", crane)));
+
+    let mut done = 0;
+    let crates = rearrange::<C>(crates, moves, |crates, m| {
+        done += 1;
+        viewer.show(&frame(crates, Some(m)).with_caption(format!("{}, move {} of {}: {}", crane, done, moves.len(), m)));
+    });
+
+    viewer.show(&frame(&crates, None).with_caption(format!("{}, top crates {}", crane, crates.top_crates())));
+}
+
+/// The stacks drawn like the puzzle input, with the crates `last` moved in yellow and the top crates in green.
+pub fn frame(crates: &Crates, last: Option<&Move>) -> Frame {
+    let tallest = crates.heights().into_iter().max().unwrap_or(0);
+
+    let mut frame = Frame::new();
+    for row in (0..tallest).rev() {
+        let mut cells = vec![];
+        for (index, stack) in crates.stacks().iter().enumerate() {
+            if index > 0 {
+                cells.push(Cell::from(' '));
+            }
+            let Some(name) = stack.get(row) else {
+                cells.extend([Cell::from(' '); 3]);
+                continue;
+            };
+
+            let moved = last.is_some_and(|m| m.stacks()[1] as usize == index + 1 && row + m.count() as usize >= stack.len());
+            let colour = if moved {
+                Colour::Yellow
+            } else if row + 1 == stack.len() {
+                Colour::Green
+            } else {
+                Colour::White
+            };
+            cells.extend([Cell::from('['), Cell::new(*name, colour), Cell::from(']')]);
+        }
+        frame.push_row(cells);
+    }
+
+    let numbers = (1..=crates.stack_count()).map(|n| format!(" {} ", n)).collect::<Vec<_>>();
+    frame.push_text(&numbers.join(" "), Some(Colour::Grey));
+    frame
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frames() {
+        let crates = "    [D]    \n[N] [C]    \n[Z] [M] [P]".parse::<Crates>().unwrap();
+        let m = Move::new(2, 2, 3);
+        let moved = rearrange::<CrateMover9001>(&crates, std::slice::from_ref(&m), |_, _| {});
+
+        let frame = frame(&moved, Some(&m));
+        assert_eq!("        [D]\n[N]     [C]\n[Z] [M] [P]\n 1   2   3 \n", frame.render(false));
+        assert!(frame.render(true).contains("\x1b[33mD"));
+
+        let mut viewer = Viewer::offscreen();
+        animate(&crates, &[m], &mut viewer);
+        assert_eq!(6, viewer.frames_shown());
+    }
+}
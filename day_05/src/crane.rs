@@ -1,8 +1,13 @@
+use std::fmt;
+use std::str::FromStr;
+
 use lazy_static::lazy_static;
 use regex::Regex;
+use util::{ParseError, parse_number};
 
 use crate::crates::Crates;
 
+/// One step of the rearrangement procedure, e.g. `move 1 from 2 to 1`.
 #[derive(Debug, PartialEq, Eq)]
 pub struct Move {
     moves: u8,
@@ -11,24 +16,66 @@ pub struct Move {
 }
 
 lazy_static! {
-    static ref MOVE_COMMAND: Regex = Regex::new(r"move\s(?P<moves>\d+)\sfrom\s(?P<from>\d+)\sto\s(?P<to>\d+)").unwrap();
+    static ref MOVE_COMMAND: Regex = Regex::new(r"^move\s(?P<moves>\d+)\sfrom\s(?P<from>\d+)\sto\s(?P<to>\d+)$").unwrap();
 }
 
-impl Move {
-    pub fn from(line: &str) -> Self {
-        let captures = MOVE_COMMAND.captures(line).unwrap();
-        let moves = captures["moves"].parse::<u8>().unwrap();
-        let from = captures["from"].parse::<u8>().unwrap();
-        let to = captures["to"].parse::<u8>().unwrap();
+impl FromStr for Move {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let captures = MOVE_COMMAND.captures(line.trim())
+            .ok_or_else(|| ParseError::new(line, "a move like 'move 1 from 2 to 1'"))?;
+        let number = |name: &str| {
+            let m = captures.name(name).unwrap();
+            parse_number::<u8>(m.as_str(), m.start() + 1, "a number in 0-255")
+        };
+
+        Ok(Move {
+            moves: number("moves")?,
+            from: number("from")?,
+            to: number("to")?,
+        })
+    }
+}
 
+impl Move {
+    pub fn new(moves: u8, from: u8, to: u8) -> Self {
         Move { moves, from, to }
     }
+
+    /// How many crates are moved.
+    pub fn count(&self) -> u8 {
+        self.moves
+    }
+
+    /// The stacks this move takes from and gives to, numbered from 1.
+    pub fn stacks(&self) -> [u8; 2] {
+        [self.from, self.to]
+    }
 }
 
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "move {} from {} to {}", self.moves, self.from, self.to)
+    }
+}
+
+/// A crane that can carry out a [`Move`].
 pub trait Crane {
     fn move_crates(crates: &mut Crates, m: &Move);
 }
 
+/// Carries out every move with a `C` crane, calling `on_move` with the crates after each one.
+pub fn rearrange<C: Crane>(crates: &Crates, moves: &[Move], mut on_move: impl FnMut(&Crates, &Move)) -> Crates {
+    let mut crates = crates.clone();
+    for m in moves {
+        C::move_crates(&mut crates, m);
+        on_move(&crates, m);
+    }
+    crates
+}
+
+/// Moves crates one at a time, reversing their order.
 pub struct CrateMover9000 {}
 
 impl Crane for CrateMover9000 {
@@ -39,6 +86,7 @@ impl Crane for CrateMover9000 {
     }
 }
 
+/// Moves several crates at once, keeping their order.
 pub struct CrateMover9001 {}
 
 impl Crane for CrateMover9001 {
@@ -51,12 +99,6 @@ impl Crane for CrateMover9001 {
 mod tests {
     use super::*;
 
-    impl Move {
-        pub fn new(moves: u8, from: u8, to: u8) -> Self {
-            Move { moves, from, to }
-        }
-    }
-
     #[test]
     fn crate_mover_9000() {
         let mut crates = Crates::from_stacks(vec![
@@ -91,9 +133,12 @@ mod tests {
 
     #[test]
     fn parse() {
-        assert_eq!(Move::new(1, 2, 1), Move::from("move 1 from 2 to 1"));
-        assert_eq!(Move::new(3, 1, 3), Move::from("move 3 from 1 to 3"));
-        assert_eq!(Move::new(2, 2, 1), Move::from("move 2 from 2 to 1"));
-        assert_eq!(Move::new(1, 1, 2), Move::from("move 1 from 1 to 2"));
+        assert_eq!(Move::new(1, 2, 1), "move 1 from 2 to 1".parse().unwrap());
+        assert_eq!(Move::new(3, 1, 3), "move 3 from 1 to 3".parse().unwrap());
+        assert_eq!(Move::new(2, 2, 1), "move 2 from 2 to 1".parse().unwrap());
+        assert_eq!(Move::new(1, 1, 2), "move 1 from 1 to 2".parse().unwrap());
+
+        assert!("move 1 from 2".parse::<Move>().is_err());
+        assert_eq!(6, "move 300 from 2 to 1".parse::<Move>().unwrap_err().column());
     }
 }
\ No newline at end of file
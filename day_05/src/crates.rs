@@ -1,43 +1,123 @@
+use std::fmt;
+use std::str::FromStr;
+
 use lazy_static::lazy_static;
 use regex::Regex;
+use util::ParseError;
+
+lazy_static! {
+    static ref STACK_NUMBERS: Regex = Regex::new(r"^(\s+(\d+)\s+)+$").unwrap();
+}
 
-type Crate = char;
+/// A crate, named by its letter.
+pub type Crate = char;
 
-#[derive(Default)]
+/// Stacks of crates, numbered from 1, each listed from the bottom up.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct Crates {
     stacks: Vec<Vec<Crate>>,
 }
 
-lazy_static! {
-    static ref HORIZONTAL_CRATES: Regex = Regex::new(r"(\[(?P<crate>[A-Z])\]|(\s{3}))\s?").unwrap();
-}
+impl FromStr for Crates {
+    type Err = ParseError;
+
+    /// Parses the drawing of the stacks, optionally with the line of stack numbers underneath. When
+    /// they're there, the numbers say how many stacks there are, including empty ones on the right.
+    fn from_str(drawing: &str) -> Result<Self, Self::Err> {
+        let mut lines = drawing.lines().collect::<Vec<_>>();
+        let numbers = lines.pop_if(|line| is_stack_numbers(line)).map(|line| (lines.len(), line));
 
-impl Crates {
-    pub fn from(lines: &[String]) -> Self {
         let mut stacks = vec![];
-        for line in lines {
-            let captures = HORIZONTAL_CRATES.captures_iter(line);
+        for (index, line) in lines.into_iter().enumerate() {
+            let cells = line.as_bytes();
 
-            for (column, capture) in captures.enumerate() {
+            // every stack takes up 3 characters with a single space between them
+            for (column, cell) in cells.chunks(4).enumerate() {
                 if stacks.len() == column {
                     stacks.push(vec![]);
                 }
-                if let Some(m) = capture.name("crate") {
-                    let name = m.as_str().chars().next().unwrap();
-                    // stacks start at the bottom but the lines start at the top
-                    // always insert new crates to the bottom of the stack
-                    stacks[column].insert(0, name);
+                let error = || ParseError::new(line, "crates drawn like '[A]' or an empty '   ' gap").at(index + 1, column * 4 + 1);
+                match cell {
+                    [b'[', name, b']'] | [b'[', name, b']', b' '] if name.is_ascii_uppercase() => {
+                        // stacks start at the bottom but the lines start at the top
+                        // always insert new crates to the bottom of the stack
+                        stacks[column].insert(0, *name as char);
+                    },
+                    _ if cell.iter().all(|b| *b == b' ') => {},
+                    _ => return Err(error()),
                 }
             }
         }
 
+        if let Some((index, line)) = numbers {
+            let count = stack_count(line).map_err(|e| e.offset_lines(index))?;
+            if stacks.len() > count {
+                return Err(ParseError::new(line, format!("numbers for all {} stacks", stacks.len())).at(index + 1, 1));
+            }
+            stacks.resize(count, vec![]);
+        }
+
+        Ok(Crates { stacks })
+    }
+}
+
+/// Whether the line is the one numbering the stacks under the drawing.
+pub fn is_stack_numbers(line: &str) -> bool {
+    STACK_NUMBERS.is_match(line)
+}
+
+/// Checks the stacks are numbered in order from 1.
+fn stack_count(line: &str) -> Result<usize, ParseError> {
+    let mut count = 0;
+    for number in line.split_whitespace() {
+        let column = number.as_ptr() as usize - line.as_ptr() as usize + 1;
+        if number.parse() != Ok(count + 1) {
+            return Err(ParseError::new(line, format!("stack number {}", count + 1)).at_column(column));
+        }
+        count += 1;
+    }
+    Ok(count)
+}
+
+/// The drawing of the stacks with their numbers underneath, like the top of the puzzle input.
+impl fmt::Display for Crates {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let tallest = self.heights().into_iter().max().unwrap_or(0);
+        for row in (0..tallest).rev() {
+            let cells = self.stacks.iter()
+                .map(|stack| stack.get(row).map_or("   ".to_string(), |name| format!("[{}]", name)))
+                .collect::<Vec<_>>();
+            writeln!(f, "{}", cells.join(" "))?;
+        }
+
+        let numbers = (1..=self.stacks.len()).map(|n| format!(" {} ", n)).collect::<Vec<_>>();
+        write!(f, "{}", numbers.join(" "))
+    }
+}
+
+impl Crates {
+    pub fn from_stacks(stacks: Vec<Vec<Crate>>) -> Self {
         Crates { stacks }
     }
 
+    pub fn stack_count(&self) -> usize {
+        self.stacks.len()
+    }
+
+    pub fn stacks(&self) -> &[Vec<Crate>] {
+        &self.stacks
+    }
+
+    /// How many crates are in each stack.
+    pub fn heights(&self) -> Vec<usize> {
+        self.stacks.iter().map(Vec::len).collect()
+    }
+
+    /// The crate on top of each stack, with a space for a stack that's empty.
     pub fn top_crates(&self) -> String {
         self.stacks
             .iter()
-            .map(|s| s.last().unwrap())
+            .map(|s| s.last().copied().unwrap_or(' '))
             .collect::<String>()
     }
 
@@ -46,6 +126,7 @@ impl Crates {
         self.stacks[to - 1].push(c);
     }
 
+    /// Moves the top `count` crates from one stack to another, keeping their order.
     pub fn move_crates(&mut self, count: usize, from: usize, to: usize) {
         let crates = self.take_crates(count, from - 1);
         self.give_crates(crates, to - 1);
@@ -69,12 +150,6 @@ impl Crates {
 mod tests {
     use super::*;
 
-    impl Crates {
-        pub fn from_stacks(stacks: Vec<Vec<Crate>>) -> Self {
-            Crates { stacks }
-        }
-    }
-
     #[test]
     fn top_crates() {
         let crates = Crates::from_stacks(vec![
@@ -84,18 +159,56 @@ mod tests {
         ]);
 
         assert_eq!("CMZ", crates.top_crates());
+
+        let crates = Crates::from_stacks(vec![vec![], vec!['A'], vec![]]);
+        assert_eq!(" A ", crates.top_crates());
     }
 
     #[test]
     fn parse() {
-        let lines = vec![
-            "    [D]    ".to_owned(),
-            "[N] [C]    ".to_owned(),
-            "[Z] [M] [P]".to_owned(),
-        ];
+        let drawing = [
+            "    [D]    ",
+            "[N] [C]    ",
+            "[Z] [M] [P]",
+        ].join("\n");
 
-        let crates = Crates::from(&lines);
+        let crates = drawing.parse::<Crates>().unwrap();
 
+        assert_eq!(3, crates.stack_count());
         assert_eq!("NDP", crates.top_crates());
     }
+
+    #[test]
+    fn numbered() {
+        let drawing = [
+            "    [D]",
+            "[N] [C]",
+            "[Z] [M] [P]",
+            " 1   2   3   4 ",
+        ].join("\n");
+
+        let crates = drawing.parse::<Crates>().unwrap();
+
+        assert_eq!(vec![2, 3, 1, 0], crates.heights());
+        let redrawn = [
+            "    [D]        ",
+            "[N] [C]        ",
+            "[Z] [M] [P]    ",
+            " 1   2   3   4 ",
+        ].join("\n");
+        assert_eq!(redrawn, crates.to_string());
+
+        let error = "[A] [B]\n 1 ".parse::<Crates>().unwrap_err();
+        assert_eq!((2, 1), (error.line(), error.column()));
+        let error = "[A] [B]\n 1   3 ".parse::<Crates>().unwrap_err();
+        assert_eq!((2, 6), (error.line(), error.column()));
+    }
+
+    #[test]
+    fn parse_invalid() {
+        let error = "    [D]    \n[N] [c]    ".parse::<Crates>().unwrap_err();
+
+        assert_eq!(2, error.line());
+        assert_eq!(5, error.column());
+    }
 }
\ No newline at end of file
@@ -0,0 +1,58 @@
+use util::Rng;
+
+/// 2 to 9 stacks of crates and `scale` moves between them. Moves never empty a stack, so
+/// there's always a crate on top of each one at the end.
+pub fn generate(rng: &mut Rng, scale: usize) -> String {
+    let stack_count = rng.below(8) + 2;
+    let mut heights = (0..stack_count).map(|_| rng.below(7) + 2).collect::<Vec<_>>();
+    let tallest = *heights.iter().max().unwrap();
+
+    let mut input = String::new();
+    let names = heights.iter()
+        .map(|height| (0..*height).map(|_| (b'A' + rng.below(26) as u8) as char).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    for row in (0..tallest).rev() {
+        let cells = names.iter()
+            .map(|stack| stack.get(row).map_or("   ".to_string(), |name| format!("[{}]", name)))
+            .collect::<Vec<_>>();
+        input += &cells.join(" ");
+        input.push('\n');
+    }
+    let numbers = (1..=stack_count).map(|n| format!(" {} ", n)).collect::<Vec<_>>();
+    input += &numbers.join(" ");
+    input += "\n\n";
+
+    for _ in 0..scale {
+        let from = loop {
+            let from = rng.below(stack_count);
+            if heights[from] > 1 {
+                break from;
+            }
+        };
+        let to = (from + rng.below(stack_count - 1) + 1) % stack_count;
+        let moves = rng.below(heights[from] - 1) + 1;
+        heights[from] -= moves;
+        heights[to] += moves;
+
+        input += &format!("move {} from {} to {}\n", moves, from + 1, to + 1);
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use util::{Part, Puzzle, Report, Timer};
+
+    use super::*;
+    use crate::Day05;
+
+    #[test]
+    fn solvable() {
+        for seed in 0..10 {
+            let input = generate(&mut Rng::new(seed), 100);
+
+            assert_eq!(input, generate(&mut Rng::new(seed), 100));
+            assert!(Puzzle::of::<Day05>().solve(&input, &Part::all(), &Timer::with_report(Report::Quiet)).is_ok());
+        }
+    }
+}
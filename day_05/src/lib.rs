@@ -0,0 +1,150 @@
+//! Day 5: Supply Stacks - rearranging stacks of crates with a giant crane.
+
+pub mod crates;
+pub mod crane;
+pub mod generator;
+pub mod animation;
+
+use std::fmt::Display;
+
+pub use crane::{Move, Crane, CrateMover9000, CrateMover9001, rearrange};
+pub use crates::Crates;
+use util::viz::Viewer;
+use util::{NoAnswer, ParseError, Rng, Solution, write_lines};
+
+/// Parses the drawing of the [`Crates`] and the [`Move`]s under it, then reports the top crates after
+/// moving them one at a time and then several at once.
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const MANIFEST_DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    type Input = (Crates, Vec<Move>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_procedure(input)
+    }
+
+    fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+        Some(generator::generate(rng, scale))
+    }
+
+    fn serialize((crates, moves): &Self::Input) -> Option<String> {
+        Some(format!("{}\n\n{}", crates, write_lines(moves)))
+    }
+
+    fn animate((crates, moves): &Self::Input, viewer: &mut Viewer) -> bool {
+        animation::animate(crates, moves, viewer);
+        true
+    }
+
+    fn part_1((crates, moves): &Self::Input) -> Result<impl Display, NoAnswer> {
+        Ok(rearrange::<CrateMover9000>(crates, moves, |_, _| {}).top_crates())
+    }
+
+    fn part_2((crates, moves): &Self::Input) -> Result<impl Display, NoAnswer> {
+        Ok(rearrange::<CrateMover9001>(crates, moves, |_, _| {}).top_crates())
+    }
+}
+
+enum InputReaderState {
+    CrateStacks,
+    Moves,
+}
+
+fn parse_procedure(input: &str) -> Result<(Crates, Vec<Move>), ParseError> {
+    let mut state = InputReaderState::CrateStacks;
+
+    let mut crate_stack_lines = vec![];
+    let mut moves = vec![];
+
+    for (index, line) in input.lines().enumerate() {
+        if line.is_empty() {
+            continue;
+        }
+
+        match state {
+            InputReaderState::CrateStacks => {
+                if crates::is_stack_numbers(line) {
+                    state = InputReaderState::Moves;
+                }
+                crate_stack_lines.push(line);
+            },
+            InputReaderState::Moves => {
+                let m = line.parse::<Move>().map_err(|e| e.offset_lines(index))?;
+                moves.push((index, line, m));
+            },
+        }
+    }
+
+    if let InputReaderState::CrateStacks = state {
+        let last = input.lines().count();
+        return Err(ParseError::new("", "a line of stack numbers under the crates").at(last + 1, 1));
+    }
+
+    let crates = crate_stack_lines.join("\n").parse::<Crates>()?;
+
+    // follow how many crates are in each stack, so no move takes more than there are
+    let mut heights = crates.heights();
+    for (index, line, m) in moves.iter() {
+        if m.stacks().iter().any(|s| *s == 0 || *s as usize > crates.stack_count()) {
+            let expected = format!("stacks numbered from 1 to {}", crates.stack_count());
+            return Err(ParseError::new(line, expected).at(index + 1, 1));
+        }
+
+        let [from, to] = m.stacks().map(|s| s as usize - 1);
+        let count = m.count() as usize;
+        if count > heights[from] {
+            let expected = format!("a move of at most {} crates from stack {}", heights[from], from + 1);
+            return Err(ParseError::new(line, expected).at(index + 1, 1));
+        }
+        heights[from] -= count;
+        heights[to] += count;
+    }
+
+    Ok((crates, moves.into_iter().map(|(_, _, m)| m).collect()))
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::{TestCaseError, prop_assert_eq, proptest};
+    use util::{Part, round_trip};
+
+    use super::*;
+
+    #[test]
+    fn fixtures() {
+        util::fixtures::assert_fixtures(util::Puzzle::of::<Day05>());
+    }
+
+    #[test]
+    fn emptied_stack() {
+        let input = Day05::parse("[A]    \n 1   2 \n\nmove 1 from 1 to 2").unwrap();
+
+        assert_eq!(Ok(" A".to_string()), Day05::solve(&input, Part::One));
+    }
+
+    #[test]
+    fn impossible_moves() {
+        let error = Day05::parse("[A]\n 1   2 \n\nmove 1 from 1 to 2\nmove 2 from 2 to 1\n").unwrap_err();
+
+        assert_eq!(5, error.line());
+        assert_eq!("a move of at most 1 crates from stack 2", error.expected());
+        assert!(Day05::parse("[A]\n 1   2 \n\nmove 1 from 1 to 3\n").is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn parse_anything(input in "(move|from|to|\\[[A-Z]\\]|[0-9]+| |\\n)*") {
+            round_trip::check::<Day05>(&input).map_err(TestCaseError::fail)?;
+        }
+
+        #[test]
+        fn round_trip(seed: u64, scale in 1..50usize) {
+            let input = Day05::generate(&mut Rng::new(seed), scale).unwrap();
+
+            prop_assert_eq!(Ok(true), round_trip::check::<Day05>(&input));
+        }
+    }
+}
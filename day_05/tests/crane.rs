@@ -0,0 +1,26 @@
+use day_05::{Crane, CrateMover9000, CrateMover9001, Crates, Day05, Move};
+use util::Solution;
+
+#[test]
+fn example() {
+    let (crates, moves) = Day05::parse(include_str!("../fixtures/example.txt")).unwrap();
+
+    assert_eq!(3, crates.stack_count());
+    assert_eq!("NDP", crates.top_crates());
+    assert_eq!(Move::new(1, 2, 1), moves[0]);
+    assert_eq!(4, moves.len());
+}
+
+#[test]
+fn cranes() {
+    let stacks = Crates::from_stacks(vec![vec!['A', 'B', 'C'], vec![]]);
+    let m = "move 2 from 1 to 2".parse::<Move>().unwrap();
+
+    let mut crates = stacks.clone();
+    CrateMover9000::move_crates(&mut crates, &m);
+    assert_eq!("AB", crates.top_crates());
+
+    let mut crates = stacks.clone();
+    CrateMover9001::move_crates(&mut crates, &m);
+    assert_eq!("AC", crates.top_crates());
+}
@@ -1,5 +1,7 @@
 use std::collections::{VecDeque, HashSet};
+use std::fmt;
 
+/// The characters received by the communication device.
 pub struct DataStream {
 	data: String,
 }
@@ -9,10 +11,12 @@ impl DataStream {
 		DataStream { data: d.to_string() }
 	}
 
+	/// Characters read by the end of the first 4 distinct characters in a row.
 	pub fn find_packet_marker(&self) -> Option<usize> {
 		self.find_unique_set_marker(4)
 	}
 
+	/// Characters read by the end of the first 14 distinct characters in a row.
 	pub fn find_message_marker(&self) -> Option<usize> {
 		self.find_unique_set_marker(14)
 	}
@@ -41,6 +45,12 @@ impl DataStream {
 	}
 }
 
+impl fmt::Display for DataStream {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(&self.data)
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -0,0 +1,36 @@
+use util::Rng;
+
+/// A stream of `scale` characters (at least 14) with a single run of 14 distinct characters
+/// somewhere in it, so both markers are always found.
+pub fn generate(rng: &mut Rng, scale: usize) -> String {
+	let length = scale.max(14);
+	let start = rng.below(length - 13);
+
+	// 3 letters can't hold a marker, so the stream is noise until the run of distinct ones
+	let mut stream = (0..start).map(|_| *rng.choose(&['a', 'b', 'c'])).collect::<String>();
+	let mut letters = ('a'..='z').collect::<Vec<_>>();
+	rng.shuffle(&mut letters);
+	stream.extend(&letters[..14]);
+	stream.extend((start + 14..length).map(|_| (b'a' + rng.below(26) as u8) as char));
+
+	stream + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+	use util::{Part, Puzzle, Report, Timer};
+
+	use super::*;
+	use crate::Day06;
+
+	#[test]
+	fn solvable() {
+		for seed in 0..10 {
+			let input = generate(&mut Rng::new(seed), 1000);
+
+			assert_eq!(1001, input.len());
+			assert_eq!(input, generate(&mut Rng::new(seed), 1000));
+			assert!(Puzzle::of::<Day06>().solve(&input, &Part::all(), &Timer::with_report(Report::Quiet)).is_ok());
+		}
+	}
+}
@@ -0,0 +1,73 @@
+//! Day 6: Tuning Trouble - finding the start-of-packet and start-of-message markers in a data stream.
+
+pub mod data_stream;
+pub mod generator;
+
+use std::fmt::Display;
+
+pub use data_stream::DataStream;
+use util::{NoAnswer, ParseError, Rng, Solution};
+
+/// Finds the markers in the [`DataStream`] on the first line of the input.
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const MANIFEST_DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    type Input = DataStream;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(DataStream::new(input.trim()))
+    }
+
+    fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+        Some(generator::generate(rng, scale))
+    }
+
+    fn serialize(stream: &DataStream) -> Option<String> {
+        Some(format!("{}\n", stream))
+    }
+
+    fn part_1(stream: &DataStream) -> Result<impl Display, NoAnswer> {
+        stream.find_packet_marker().ok_or_else(|| NoAnswer::new("the stream has no start-of-packet marker"))
+    }
+
+    fn part_2(stream: &DataStream) -> Result<impl Display, NoAnswer> {
+        stream.find_message_marker().ok_or_else(|| NoAnswer::new("the stream has no start-of-message marker"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::{TestCaseError, prop_assert_eq, proptest};
+    use util::{Part, round_trip};
+
+    use super::*;
+
+    #[test]
+    fn fixtures() {
+        util::fixtures::assert_fixtures(util::Puzzle::of::<Day06>());
+    }
+
+    #[test]
+    fn no_answer() {
+        let input = Day06::parse("aaaa").unwrap();
+
+        assert_eq!(Err(NoAnswer::new("the stream has no start-of-packet marker")), Day06::solve(&input, Part::One));
+    }
+
+    proptest! {
+        #[test]
+        fn parse_anything(input in "\\PC*") {
+            round_trip::check::<Day06>(&input).map_err(TestCaseError::fail)?;
+        }
+
+        #[test]
+        fn round_trip(seed: u64, scale in 1..50usize) {
+            let input = Day06::generate(&mut Rng::new(seed), scale).unwrap();
+
+            prop_assert_eq!(Ok(true), round_trip::check::<Day06>(&input));
+        }
+    }
+}
@@ -1,26 +1,8 @@
-use std::fs;
+use std::process::ExitCode;
 
-use data_stream::DataStream;
-use util::Timer;
+use day_06::Day06;
+use util::Puzzle;
 
-mod data_stream;
-
-fn main() -> std::io::Result<()> {
-    let _timer = Timer::new();
-    
-    let data = fs::read_to_string("./day_06/input.txt")?;
-    let stream = DataStream::new(data.trim());
-
-    println!("{}", run_part_2(&stream));
-
-    Ok(())
+fn main() -> ExitCode {
+    util::cli::main(Puzzle::of::<Day06>())
 }
-
-#[allow(dead_code)]
-fn run_part_1(stream: &DataStream) -> usize {
-    stream.find_packet_marker().unwrap()
-}
-
-fn run_part_2(stream: &DataStream) -> usize {
-    stream.find_message_marker().unwrap()
-}
\ No newline at end of file
@@ -0,0 +1,18 @@
+use day_06::{DataStream, Day06};
+use util::Solution;
+
+#[test]
+fn example() {
+    let stream = Day06::parse("mjqjpqmgbljsphdztnvjfqwrcgsmlb\n").unwrap();
+
+    assert_eq!(Some(7), stream.find_packet_marker());
+    assert_eq!(Some(19), stream.find_message_marker());
+}
+
+#[test]
+fn no_marker() {
+    let stream = DataStream::new("abcabcabcabc");
+
+    assert_eq!(None, stream.find_packet_marker());
+    assert_eq!(None, stream.find_message_marker());
+}
@@ -0,0 +1,32 @@
+use util::Rng;
+
+/// A square forest `scale` trees wide.
+pub fn generate(rng: &mut Rng, scale: usize) -> String {
+    let side = scale.max(1);
+
+    (0..side)
+        .map(|_| {
+            let mut row = (0..side).map(|_| (b'0' + rng.below(10) as u8) as char).collect::<String>();
+            row.push('\n');
+            row
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use util::{Part, Puzzle, Report, Timer};
+
+    use super::*;
+    use crate::Day08;
+
+    #[test]
+    fn solvable() {
+        let input = generate(&mut Rng::new(1), 30);
+
+        assert_eq!(30, input.lines().count());
+        assert!(input.lines().all(|l| l.len() == 30));
+        assert_eq!(input, generate(&mut Rng::new(1), 30));
+        assert!(Puzzle::of::<Day08>().solve(&input, &Part::all(), &Timer::with_report(Report::Quiet)).is_ok());
+    }
+}
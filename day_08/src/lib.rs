@@ -0,0 +1,80 @@
+//! Day 8: Treetop Tree House - finding visible trees and the best spot for a tree house.
+
+pub mod tree_grid;
+pub mod generator;
+
+use std::fmt::Display;
+
+pub use tree_grid::{ScenicInfo, TreeGrid};
+use util::image::Image;
+use util::{NoAnswer, ParseError, Rng, Solution};
+
+/// Counts the trees visible from outside the [`TreeGrid`], then scores the most scenic one.
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const MANIFEST_DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    type Input = TreeGrid;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+        Some(generator::generate(rng, scale))
+    }
+
+    fn serialize(tree_grid: &TreeGrid) -> Option<String> {
+        Some(format!("{}\n", tree_grid))
+    }
+
+    fn image(tree_grid: &TreeGrid) -> Option<Image> {
+        Some(tree_grid.image())
+    }
+
+    fn part_1(tree_grid: &TreeGrid) -> Result<impl Display, NoAnswer> {
+        Ok(tree_grid.visible_trees_count())
+    }
+
+    fn part_2(tree_grid: &TreeGrid) -> Result<impl Display, NoAnswer> {
+        let tree = tree_grid.find_most_scenic_tree().ok_or_else(|| NoAnswer::new("there are no trees"))?;
+
+        Ok(tree.score())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::{TestCaseError, prop_assert_eq, proptest};
+    use util::{Part, round_trip};
+
+    use super::*;
+
+    #[test]
+    fn fixtures() {
+        util::fixtures::assert_fixtures(util::Puzzle::of::<Day08>());
+    }
+
+    #[test]
+    fn no_answer() {
+        let input = Day08::parse("").unwrap();
+
+        assert_eq!(Err(NoAnswer::new("there are no trees")), Day08::solve(&input, Part::Two));
+    }
+
+    proptest! {
+        #[test]
+        fn parse_anything(input in "[0-9 \\n]*") {
+            round_trip::check::<Day08>(&input).map_err(TestCaseError::fail)?;
+        }
+
+        #[test]
+        fn round_trip(seed: u64, scale in 1..50usize) {
+            let input = Day08::generate(&mut Rng::new(seed), scale).unwrap();
+
+            prop_assert_eq!(Ok(true), round_trip::check::<Day08>(&input));
+        }
+    }
+}
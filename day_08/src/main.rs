@@ -1,31 +1,8 @@
-mod tree_grid;
+use std::process::ExitCode;
 
-use std::fs;
+use day_08::Day08;
+use util::Puzzle;
 
-use tree_grid::TreeGrid;
-use util::Timer;
-
-fn main() -> std::io::Result<()> {
-    let _timer = Timer::new();
-    
-    let data = fs::read_to_string("./day_08/input.txt")?;
-
-    let tree_grid = TreeGrid::from_grid(&data);
-
-    run_part_2(&tree_grid);
-
-    Ok(())
+fn main() -> ExitCode {
+    util::cli::main(Puzzle::of::<Day08>())
 }
-
-#[allow(dead_code)]
-fn run_part_1(tree_grid: &TreeGrid) {
-    let visible_tree_count = tree_grid.visible_trees_count();
-
-    println!("There are {} visible trees", visible_tree_count);
-}
-
-fn run_part_2(tree_grid: &TreeGrid) {
-    let most_scenic = tree_grid.find_most_scenic_tree();
-
-    println!("The most scenic tree has a score of {}", most_scenic.score());
-}
\ No newline at end of file
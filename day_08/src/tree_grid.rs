@@ -1,74 +1,41 @@
-pub struct TreeGrid {
-    trees: Vec<u8>,
-    rows: i32,
-    columns: i32,
-}
-
-impl TreeGrid {
-    pub fn from_grid(text: &str) -> Self {
-        let lines = text
-            .split('\n')
-            .collect::<Vec<&str>>();
-
-        TreeGrid::from_lines(&lines)
-    }
-
-    pub fn from_lines(lines: &[&str]) -> Self {
-        let mut trees = vec![];
+use std::fmt;
+use std::str::FromStr;
 
-        let mut rows = 0;
-        let mut columns = -1;
-        for line in lines.iter() {
-            let char_count = line.chars().count() as i32;
-            if char_count == 0 {
-                continue;
-            }
-            if columns == -1 {
-                columns = char_count;
-            } else {
-                assert_eq!(columns, char_count);
-            }
-            rows += 1;
-            for c in line.chars() {
-                assert!(c.is_numeric(), "only numeric characters should be included - {}", c);
-                trees.push(c.to_digit(10).unwrap() as u8);
-            }
-        }
+use util::image::{Image, Palette};
+use util::{Direction, Grid, ParseError, Point};
 
-        Self { trees, rows, columns }
-    }
+/// The height, 0-9, of every tree in the forest.
+pub struct TreeGrid {
+    trees: Grid<u8>,
+}
 
-    fn grid_ref(&self, index: usize) -> (i32, i32) {
-        let row = (index as i32 / self.columns) as i32;
-        let column = (index as i32 % self.columns) as i32;
+impl FromStr for TreeGrid {
+    type Err = ParseError;
 
-        (row, column)
-    }
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let trees = Grid::parse(text, |_, c| match c.to_digit(10) {
+            Some(height) => Ok(height as u8),
+            None => Err("tree heights in 0-9".to_string()),
+        })?;
 
-    fn index(&self, row: i32, column: i32) -> usize {
-        row as usize * self.columns as usize + column as usize
+        Ok(Self { trees })
     }
+}
 
-    fn next(&self, index: usize, direction: &SearchDirection) -> Option<usize> {
-        let (row, column) = self.grid_ref(index);
-        let (row, column) = match direction {
-            SearchDirection::Up => (row.checked_sub(1).unwrap(), column),
-            SearchDirection::Down => (row.checked_add(1).unwrap(), column),
-            SearchDirection::Left => (row, column.checked_sub(1).unwrap()),
-            SearchDirection::Right => (row, column.checked_add(1).unwrap()),
-        };
-        if row < 0 || row >= self.rows as i32 || column < 0 || column >= self.columns as i32 {
-            None
-        } else {
-            Some(self.index(row, column))
-        }
+/// One row of heights per line, like the puzzle input.
+impl fmt::Display for TreeGrid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.trees.render(|_, height| (b'0' + height) as char))
     }
+}
 
+impl TreeGrid {
+    /// Trees that aren't hidden behind taller or equally tall trees from at least one edge.
     pub fn visible_trees_count(&self) -> u64 {
         let mut visible_count = 0;
 
-        for index in 0..self.trees.len() {
-            if search_up(self, index).1 || search_down(self, index).1 || search_left(self, index).1 || search_right(self, index).1 {
+        for tree in self.trees.points() {
+            if search_up(self, tree).1 || search_down(self, tree).1 || search_left(self, tree).1 || search_right(self, tree).1 {
                 visible_count += 1
             }
         }
@@ -76,14 +43,15 @@ impl TreeGrid {
         visible_count
     }
 
-    pub fn find_most_scenic_tree(&self) -> ScenicInfo {
+    /// The tree with the highest scenic score, the first one found on a tie, or None if the grid is empty.
+    pub fn find_most_scenic_tree(&self) -> Option<ScenicInfo> {
         let mut most_scenic_tree: Option<ScenicInfo> = None;
 
-        for index in 0..self.trees.len() {
-            let up = search_up(self, index).0;
-            let down = search_down(self, index).0;
-            let left = search_left(self, index).0;
-            let right = search_right(self, index).0;
+        for tree in self.trees.points() {
+            let up = search_up(self, tree).0;
+            let down = search_down(self, tree).0;
+            let left = search_left(self, tree).0;
+            let right = search_right(self, tree).0;
 
             let score = up * down * left * right;
 
@@ -93,62 +61,67 @@ impl TreeGrid {
                 true
             };
             if replace {
-                most_scenic_tree.replace(ScenicInfo { index, score });
+                most_scenic_tree.replace(ScenicInfo { tree, score });
             }
         }
 
-        most_scenic_tree.unwrap()
+        most_scenic_tree
+    }
+
+    /// The forest from above, shaded from pale for the shortest trees to dark for the tallest, with
+    /// the most scenic tree in red.
+    pub fn image(&self) -> Image {
+        let mut image = Image::from_grid(&self.trees, |_, height| Palette::FOREST.scale(*height as i64, 0, 9));
+        if let Some(most_scenic) = self.find_most_scenic_tree() {
+            image.set(most_scenic.tree(), [220, 30, 30]);
+        }
+        image
     }
 }
 
+/// A tree and its scenic score: the product of how far it can see in each direction.
 #[derive(Debug, PartialEq, Eq)]
 pub struct ScenicInfo {
-    index: usize,
+    tree: Point,
     score: u64,
 }
 
 impl ScenicInfo {
+    pub fn tree(&self) -> Point {
+        self.tree
+    }
+
     pub fn score(&self) -> u64 {
         self.score
     }
 }
 
-pub enum SearchDirection {
-    Up,
-    Down,
-    Left,
-    Right
+fn search_up(tree_grid: &TreeGrid, tree: Point) -> (u64, bool) {
+    search(tree_grid, tree, Direction::Up)
 }
 
-fn search_up(tree_grid: &TreeGrid, tree: usize) -> (u64, bool) {
-    search(tree_grid, tree, SearchDirection::Up)
+fn search_down(tree_grid: &TreeGrid, tree: Point) -> (u64, bool) {
+    search(tree_grid, tree, Direction::Down)
 }
 
-fn search_down(tree_grid: &TreeGrid, tree: usize) -> (u64, bool) {
-    search(tree_grid, tree, SearchDirection::Down)
+fn search_left(tree_grid: &TreeGrid, tree: Point) -> (u64, bool) {
+    search(tree_grid, tree, Direction::Left)
 }
 
-fn search_left(tree_grid: &TreeGrid, tree: usize) -> (u64, bool) {
-    search(tree_grid, tree, SearchDirection::Left)
+fn search_right(tree_grid: &TreeGrid, tree: Point) -> (u64, bool) {
+    search(tree_grid, tree, Direction::Right)
 }
 
-fn search_right(tree_grid: &TreeGrid, tree: usize) -> (u64, bool) {
-    search(tree_grid, tree, SearchDirection::Right)
-}
-
-fn search(tree_grid: &TreeGrid, origin: usize, direction: SearchDirection) -> (u64, bool) {
-    let size = *tree_grid.trees.get(origin).unwrap();
+fn search(tree_grid: &TreeGrid, origin: Point, direction: Direction) -> (u64, bool) {
+    let size = tree_grid.trees[origin];
 
     let mut visible_tree_count = 0;
-    
-    let mut current = origin;
-    while let Some(next) = tree_grid.next(current, &direction) {
+
+    for (_, next_size) in tree_grid.trees.ray(origin, direction) {
         visible_tree_count += 1;
-        let next_size = *tree_grid.trees.get(next).unwrap_or_else(|| panic!("There should be a tree with index {}", next));
-        if next_size >= size {
+        if *next_size >= size {
             return (visible_tree_count, false);
         }
-        current = next;
     }
 
     (visible_tree_count, true)
@@ -158,51 +131,50 @@ fn search(tree_grid: &TreeGrid, origin: usize, direction: SearchDirection) -> (u
 mod tests {
     use super::*;
 
+    fn example() -> TreeGrid {
+        include_str!("../fixtures/example.txt").parse().unwrap()
+    }
+
     #[test]
     fn from() {
-        let tree_grid = TreeGrid::from_grid(
-r#"
-30373
-25512
-65332
-33549
-35390
-"#
-        );
+        let tree_grid = example();
 
         assert_eq!(25, tree_grid.trees.len());
-        assert_eq!(3, tree_grid.trees[0]);
-        assert_eq!(3, tree_grid.trees[12]);
-        assert_eq!(0, tree_grid.trees[24]);
+        assert_eq!(3, tree_grid.trees[Point::new(0, 0)]);
+        assert_eq!(3, tree_grid.trees[Point::new(2, 2)]);
+        assert_eq!(0, tree_grid.trees[Point::new(4, 4)]);
     }
 
     #[test]
     fn visibility() {
-        let tree_grid = TreeGrid::from_grid(
-r#"
-30373
-25512
-65332
-33549
-35390
-"#
-        );
+        let tree_grid = example();
 
         assert_eq!(21, tree_grid.visible_trees_count());
     }
 
     #[test]
     fn most_scenic() {
-        let tree_grid = TreeGrid::from_grid(
-r#"
-30373
-25512
-65332
-33549
-35390
-"#
-        );
-
-        assert_eq!(ScenicInfo { index: 17, score: 8 }, tree_grid.find_most_scenic_tree());
+        let tree_grid = example();
+
+        assert_eq!(Some(ScenicInfo { tree: Point::new(2, 3), score: 8 }), tree_grid.find_most_scenic_tree());
+    }
+
+    #[test]
+    fn image() {
+        let image = example().image();
+
+        assert_eq!((5, 5), (image.width(), image.height()));
+        assert_eq!(Some(Palette::FOREST.at(0.0)), image.get(Point::new(4, 4)));
+        assert_eq!(Some(Palette::FOREST.at(1.0)), image.get(Point::new(4, 3)));
+        assert_eq!(Some([220, 30, 30]), image.get(Point::new(2, 3)));
+    }
+
+    #[test]
+    fn invalid() {
+        let error = "303\n25x\n".parse::<TreeGrid>().err().unwrap();
+        assert_eq!((2, 3), (error.line(), error.column()));
+
+        let error = "303\n2551\n".parse::<TreeGrid>().err().unwrap();
+        assert_eq!("a row of 3 cells", error.expected());
     }
 }
\ No newline at end of file
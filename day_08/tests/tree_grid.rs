@@ -0,0 +1,17 @@
+use day_08::{Day08, TreeGrid};
+use util::{Point, Solution};
+
+#[test]
+fn example() {
+    let trees = Day08::parse(include_str!("../fixtures/example.txt")).unwrap();
+    let scenic = trees.find_most_scenic_tree().unwrap();
+
+    assert_eq!(21, trees.visible_trees_count());
+    assert_eq!(Point::new(2, 3), scenic.tree());
+    assert_eq!(8, scenic.score());
+}
+
+#[test]
+fn invalid() {
+    assert!("123\n4x6".parse::<TreeGrid>().is_err());
+}
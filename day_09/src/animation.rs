@@ -0,0 +1,83 @@
+use util::Point;
+use util::viz::{Cell, Colour, Frame, Viewer};
+
+use crate::rope::{Move, Rope};
+
+const WIDTH: usize = 60;
+const HEIGHT: usize = 24;
+/// How close the head gets to the edge of the view before it moves along
+const MARGIN: i64 = 4;
+
+/// Pulls a rope of 2 knots and then one of 10 through the moves, a frame per step.
+pub fn animate(moves: &[Move], viewer: &mut Viewer) {
+	for knots in [2, 10] {
+		let mut rope = Rope::new(knots);
+		let mut view = View::new();
+		for (index, mv) in moves.iter().enumerate() {
+			rope.move_head_with(mv, |rope| {
+				view.follow(rope.knots()[0]);
+				let caption = format!("{} knots, move {} of {}: {}, tail visited {}", knots, index + 1, moves.len(), mv, rope.tail_trace().len());
+				viewer.show(&view.frame(rope).with_caption(caption));
+			});
+		}
+	}
+}
+
+/// The part of the plane on screen, which only moves when the head gets close to its edge.
+struct View {
+	top_left: Point,
+}
+
+impl View {
+	fn new() -> Self {
+		View { top_left: Point::new(-(WIDTH as i64) / 2, -(HEIGHT as i64) / 2) }
+	}
+
+	fn follow(&mut self, head: Point) {
+		let offset = head - self.top_left;
+		let (width, height) = (WIDTH as i64, HEIGHT as i64);
+		if offset.x < MARGIN || offset.x >= width - MARGIN || offset.y < MARGIN || offset.y >= height - MARGIN {
+			self.top_left = head - Point::new(width / 2, height / 2);
+		}
+	}
+
+	/// The knots over the trail the tail has left, drawn like the puzzle: `H` for the head, then the
+	/// number of each knot, or `T` when there's only the tail.
+	fn frame(&self, rope: &Rope) -> Frame {
+		Frame::window(self.top_left, WIDTH, HEIGHT, |point| {
+			match rope.knots().iter().position(|knot| *knot == point) {
+				Some(0) => Cell::new('H', Colour::Red),
+				Some(_) if rope.knots().len() == 2 => Cell::new('T', Colour::Yellow),
+				Some(index) => Cell::new(char::from_digit(index as u32, 10).unwrap_or('+'), Colour::Yellow),
+				None if point == Point::ORIGIN => Cell::new('s', Colour::Cyan),
+				None if rope.tail_trace().contains(&point) => Cell::new('#', Colour::Grey),
+				None => Cell::from('.'),
+			}
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn frames() {
+		let moves = ["R 4", "U 2"].map(|m| m.parse::<Move>().unwrap());
+
+		let mut viewer = Viewer::offscreen();
+		animate(&moves, &mut viewer);
+		assert_eq!(12, viewer.frames_shown());
+
+		let mut rope = Rope::new(2);
+		for mv in &moves {
+			rope.move_head(mv);
+		}
+		let frame = View::new().frame(&rope).render(false);
+		let rows = frame.lines().collect::<Vec<_>>();
+		assert_eq!(HEIGHT, rows.len());
+		assert_eq!("s###", &rows[HEIGHT / 2][WIDTH / 2..WIDTH / 2 + 4]);
+		assert_eq!("H", &rows[HEIGHT / 2 - 2][WIDTH / 2 + 4..WIDTH / 2 + 5]);
+		assert_eq!("T", &rows[HEIGHT / 2 - 1][WIDTH / 2 + 4..WIDTH / 2 + 5]);
+	}
+}
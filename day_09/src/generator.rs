@@ -0,0 +1,25 @@
+use util::Rng;
+
+/// `scale` moves of the head of the rope, each 1-20 steps.
+pub fn generate(rng: &mut Rng, scale: usize) -> String {
+	(0..scale)
+		.map(|_| format!("{} {}\n", rng.choose(&['U', 'D', 'L', 'R']), rng.range(1..=20)))
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use util::{Part, Puzzle, Report, Timer};
+
+	use super::*;
+	use crate::Day09;
+
+	#[test]
+	fn solvable() {
+		let input = generate(&mut Rng::new(1), 200);
+
+		assert_eq!(200, input.lines().count());
+		assert_eq!(input, generate(&mut Rng::new(1), 200));
+		assert!(Puzzle::of::<Day09>().solve(&input, &Part::all(), &Timer::with_report(Report::Quiet)).is_ok());
+	}
+}
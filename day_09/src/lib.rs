@@ -0,0 +1,113 @@
+//! Day 9: Rope Bridge - following the tail of a rope around a grid.
+
+pub mod rope;
+pub mod generator;
+pub mod animation;
+
+use std::fmt::Display;
+use std::iter;
+
+pub use rope::{Rope, Move, Moveable};
+use util::image::{BLACK, Image, WHITE};
+use util::viz::Viewer;
+use util::{NoAnswer, ParseError, Point, Rng, Solution, parse_lines, write_lines};
+
+/// Counts the positions visited by the tail of a [`Rope`] with 2 knots, then with 10.
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const MANIFEST_DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    type Input = Vec<Move>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input)
+    }
+
+    fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+        Some(generator::generate(rng, scale))
+    }
+
+    fn serialize(moves: &Vec<Move>) -> Option<String> {
+        Some(write_lines(moves))
+    }
+
+    fn animate(moves: &Vec<Move>, viewer: &mut Viewer) -> bool {
+        animation::animate(moves, viewer);
+        true
+    }
+
+    fn image(moves: &Vec<Move>) -> Option<Image> {
+        Some(trace_image(moves))
+    }
+
+    fn part_1(moves: &Vec<Move>) -> Result<impl Display, NoAnswer> {
+        Ok(tail_trace_count(moves, Rope::new(2)))
+    }
+
+    fn part_2(moves: &Vec<Move>) -> Result<impl Display, NoAnswer> {
+        Ok(tail_trace_count(moves, Rope::new(10)))
+    }
+}
+
+fn tail_trace_count(moves: &[Move], rope: Rope) -> usize {
+    pull(moves, rope).tail_trace_count()
+}
+
+fn pull(moves: &[Move], mut rope: Rope) -> Rope {
+    for mv in moves {
+        rope.move_head(mv);
+    }
+
+    rope
+}
+
+/// Where the tails went: grey for the rope of 2 knots, white for the rope of 10 over it, and the
+/// starting point in red.
+fn trace_image(moves: &[Move]) -> Image {
+    let short = pull(moves, Rope::new(2));
+    let long = pull(moves, Rope::new(10));
+
+    let points = short.tail_trace().iter().map(|p| (*p, [110, 110, 110]))
+        .chain(long.tail_trace().iter().map(|p| (*p, WHITE)))
+        .chain(iter::once((Point::ORIGIN, [220, 30, 30])));
+    Image::from_points(points, BLACK)
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::{TestCaseError, prop_assert_eq, proptest};
+    use util::round_trip;
+
+    use super::*;
+
+    #[test]
+    fn fixtures() {
+        util::fixtures::assert_fixtures(util::Puzzle::of::<Day09>());
+    }
+
+    #[test]
+    fn image() {
+        let moves = Day09::parse(include_str!("../fixtures/larger_example.txt")).unwrap();
+        let image = trace_image(&moves);
+        let pixels = |colour| image.pixels().iter().filter(|p| **p == colour).count();
+
+        assert_eq!(36 - 1, pixels(WHITE));
+        assert_eq!(1, pixels([220, 30, 30]));
+    }
+
+    proptest! {
+        #[test]
+        fn parse_anything(input in "[UDLR0-9 \\n]*") {
+            round_trip::check::<Day09>(&input).map_err(TestCaseError::fail)?;
+        }
+
+        #[test]
+        fn round_trip(seed: u64, scale in 1..50usize) {
+            let input = Day09::generate(&mut Rng::new(seed), scale).unwrap();
+
+            prop_assert_eq!(Ok(true), round_trip::check::<Day09>(&input));
+        }
+    }
+}
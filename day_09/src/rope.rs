@@ -1,21 +1,17 @@
 use std::collections::HashSet;
+use std::fmt;
+use std::str::FromStr;
 
-pub type Position = (i32, i32);
+use util::{Direction, ParseError, Point, parse_number};
 
+/// A rope made of knots, each following the one in front of it, that remembers where its tail has been.
 #[derive(Debug)]
 pub struct Rope {
-	knots: Vec<Position>,
-	tail_trace: HashSet<Position>,
-}
-
-#[derive(Debug, PartialEq, Eq)]
-pub enum Direction {
-	Up,
-	Down,
-	Left,
-	Right,
+	knots: Vec<Point>,
+	tail_trace: HashSet<Point>,
 }
 
+/// Steps for the head of the rope in one direction, e.g. `R 4`.
 #[derive(Debug, PartialEq, Eq)]
 pub struct Move {
 	direction: Direction,
@@ -23,128 +19,113 @@ pub struct Move {
 }
 
 impl Move {
-	pub fn from(line: &str) -> Self {
-		let mut pair = line.split(' ');
-		let dir = pair.next().unwrap();
-		
-		let direction = match dir {
-			"U" => Direction::Up,
-			"D"	=> Direction::Down,
-			"R" => Direction::Right,
-			"L" => Direction::Left,
-			_ => panic!("")
-		};
-		let steps = pair.next().unwrap().parse::<u8>().unwrap();
-		Self { direction, steps }
+	pub fn new(direction: Direction, steps: u8) -> Self {
+		Move { direction, steps }
 	}
 }
 
-pub trait Moveable {
-	type Item;
+impl FromStr for Move {
+	type Err = ParseError;
 
-	fn mv(&mut self, direction: &Direction);
+	fn from_str(line: &str) -> Result<Self, Self::Err> {
+		let Some((dir, steps)) = line.trim().split_once(' ') else {
+			return Err(ParseError::new(line, "a move like 'R 4'"));
+		};
+
+		let mut letters = dir.chars();
+		let direction = match (letters.next().map(Direction::try_from), letters.next()) {
+			(Some(Ok(direction)), None) => direction,
+			_ => return Err(ParseError::new(line, "a direction in (U, D, L, R)")),
+		};
+		let steps = parse_number(steps, dir.len() + 2, "a step count in 0-255")?;
+		Ok(Self { direction, steps })
+	}
 }
 
-impl Moveable for Position {
-    type Item = Self;
+impl fmt::Display for Move {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{} {}", self.direction.letter(), self.steps)
+	}
+}
 
-    fn mv(&mut self, direction: &Direction) {
-		match direction {
-			Direction::Up => self.1 += 1,
-			Direction::Down => self.1 -= 1,
-			Direction::Left => self.0 -= 1,
-			Direction::Right => self.0 += 1,
-		}
-    }
+/// Something that moves a single step at a time.
+pub trait Moveable {
+	fn mv(&mut self, direction: &Direction);
 }
 
 impl Rope {
+	/// A rope with every knot at the origin.
 	pub fn new(knot_count: usize) -> Self {
-		let knots = vec![(0, 0); knot_count];
+		let knots = vec![Point::ORIGIN; knot_count];
 		let mut tail_trace = HashSet::new();
-		tail_trace.insert((0, 0));
+		tail_trace.insert(Point::ORIGIN);
 		Self {
 			knots,
 			tail_trace,
 		}
 	}
 
-	pub fn head_mut(&mut self) -> &mut Position {
+	pub fn head_mut(&mut self) -> &mut Point {
 		self.knots.first_mut().unwrap()
 	}
 
-	pub fn tail(&self) -> &Position {
+	pub fn tail(&self) -> &Point {
 		self.knots.last().unwrap()
 	}
 
+	/// Every knot, head first.
+	pub fn knots(&self) -> &[Point] {
+		&self.knots
+	}
+
+	/// Moves the head one step at a time, pulling the rest of the knots along.
 	pub fn move_head(&mut self, mv: &Move) {
+		self.move_head_with(mv, |_| {});
+	}
+
+	/// Like [`Rope::move_head`], calling `on_step` with the rope after every step.
+	pub fn move_head_with(&mut self, mv: &Move, mut on_step: impl FnMut(&Rope)) {
 		for _ in 0..mv.steps {
 			self.mv(&mv.direction);
 			self.tail_trace.insert(*self.tail());
+			on_step(self);
 		}
 	}
 
+	pub fn tail_trace(&self) -> &HashSet<Point> {
+		&self.tail_trace
+	}
+
+	/// Distinct positions the tail has visited, including the origin.
 	pub fn tail_trace_count(&self) -> usize {
 		self.tail_trace.len()
 	}
 }
 
 impl Moveable for Rope {
-    type Item = Self;
-
-    fn mv(&mut self, direction: &Direction) {
-		self.head_mut().mv(direction);
+	fn mv(&mut self, direction: &Direction) {
+		*self.head_mut() += direction.offset();
 
 		for i in 0..self.knots.len() - 1 {
-			let current = self.knots.get(i).unwrap();
-			let next = self.knots.get(i + 1).unwrap();
-			if let Some(delta) = position_delta(current, next) {
-				for pull in pull(&delta) {
-					self.knots.get_mut(i + 1).unwrap().mv(&pull);
-				}
+			if let Some(delta) = position_delta(&self.knots[i], &self.knots[i + 1]) {
+				self.knots[i + 1] += pull(&delta);
 			}
-
 		}
-    }
+	}
 }
 
-fn position_delta(a: &Position, b: &Position) -> Option<Position> {
-	let horizontal_delta = a.0 - b.0;
-	let vertical_delta = a.1 - b.1;
-
-	if horizontal_delta.abs() <= 1 && vertical_delta.abs() <= 1 {
+fn position_delta(a: &Point, b: &Point) -> Option<Point> {
+	if a.chebyshev(b) <= 1 {
 		None
 	} else {
-		Some((horizontal_delta, vertical_delta))
+		Some(*a - *b)
 	}
 }
 
-fn pull(delta: &Position) -> Vec<Direction> {
-	let mut moves = vec![];
-
-	let (horizontal_move, vertical_move) =
-	if delta.0.abs() > 0 && delta.1.abs() > 0 && (delta.0.abs() > 1 || delta.1.abs() > 1) {
-		(true, true)
-	} else {
-		(delta.0.abs() > 1, delta.1.abs() > 1)
-	};
-
-	if horizontal_move {
-		if delta.0 > 0 {
-			moves.push(Direction::Right);
-		} else {
-			moves.push(Direction::Left);
-		}
-	}
-	if vertical_move {
-		if delta.1 > 0 {
-			moves.push(Direction::Up);
-		} else {
-			moves.push(Direction::Down);
-		}
-	}
-
-	moves
+/// The step a knot takes towards the one in front once they're no longer touching: one square along
+/// each axis they differ on, so diagonally unless they're in line.
+fn pull(delta: &Point) -> Point {
+	delta.signum()
 }
 
 #[cfg(test)]
@@ -153,27 +134,25 @@ mod tests {
 
 	#[test]
 	fn position_deltas() {
-		assert_eq!(None, position_delta(&(1, 1), &(1, 1)));
-		assert_eq!(Some((5, 2)), position_delta(&(5, 2), &(0, 0)));
-		assert_eq!(Some((-1, 6)), position_delta(&(2, 7), &(3, 1)));
+		assert_eq!(None, position_delta(&Point::new(1, 1), &Point::new(1, 1)));
+		assert_eq!(None, position_delta(&Point::new(1, 0), &Point::ORIGIN));
+		assert_eq!(None, position_delta(&Point::new(0, -1), &Point::ORIGIN));
+		assert_eq!(None, position_delta(&Point::new(-1, 1), &Point::ORIGIN));
+		assert_eq!(Some(Point::new(5, 2)), position_delta(&Point::new(5, 2), &Point::ORIGIN));
+		assert_eq!(Some(Point::new(-1, 6)), position_delta(&Point::new(2, 7), &Point::new(3, 1)));
 	}
 
 	#[test]
 	fn pulls() {
-		assert_eq!(Vec::<Direction>::new(), pull(&(0,0)));
-		assert_eq!(Vec::<Direction>::new(), pull(&(1,0)));
-		assert_eq!(Vec::<Direction>::new(), pull(&(0,-1)));
-		assert_eq!(Vec::<Direction>::new(), pull(&(-1,1)));
-
-		assert_eq!(vec![Direction::Right], pull(&(5,0)));
-		assert_eq!(vec![Direction::Left], pull(&(-2,0)));
-		assert_eq!(vec![Direction::Up], pull(&(0,3)));
-		assert_eq!(vec![Direction::Down], pull(&(0,-2)));
-
-		assert_eq!(vec![Direction::Right, Direction::Up], pull(&(5,2)));
-		assert_eq!(vec![Direction::Right, Direction::Down], pull(&(5,-3)));
-		assert_eq!(vec![Direction::Left, Direction::Down], pull(&(-3,-2)));
-		assert_eq!(vec![Direction::Left, Direction::Up], pull(&(-3,5)));
+		assert_eq!(Direction::Right.offset(), pull(&Point::new(5, 0)));
+		assert_eq!(Direction::Left.offset(), pull(&Point::new(-2, 0)));
+		assert_eq!(Direction::Down.offset(), pull(&Point::new(0, 3)));
+		assert_eq!(Direction::Up.offset(), pull(&Point::new(0, -2)));
+
+		assert_eq!(Point::new(1, 1), pull(&Point::new(5, 2)));
+		assert_eq!(Point::new(1, -1), pull(&Point::new(5, -3)));
+		assert_eq!(Point::new(-1, -1), pull(&Point::new(-3, -2)));
+		assert_eq!(Point::new(-1, 1), pull(&Point::new(-3, 5)));
 	}
 
 	#[test]
@@ -189,19 +168,19 @@ mod tests {
 		rope.move_head(&Move { direction: Direction::Right, steps: 2 });
 
 		let mut expected_trace = HashSet::new();
-		expected_trace.insert((0, 0));
-		expected_trace.insert((1, 0));
-		expected_trace.insert((2, 0));
-		expected_trace.insert((3, 0));
-		expected_trace.insert((4, 1));
-		expected_trace.insert((1, 2));
-		expected_trace.insert((2, 2));
-		expected_trace.insert((3, 2));
-		expected_trace.insert((4, 2));
-		expected_trace.insert((3, 3));
-		expected_trace.insert((4, 3));
-		expected_trace.insert((2, 4));
-		expected_trace.insert((3, 4));
+		expected_trace.insert(Point::new(0, 0));
+		expected_trace.insert(Point::new(1, 0));
+		expected_trace.insert(Point::new(2, 0));
+		expected_trace.insert(Point::new(3, 0));
+		expected_trace.insert(Point::new(4, -1));
+		expected_trace.insert(Point::new(1, -2));
+		expected_trace.insert(Point::new(2, -2));
+		expected_trace.insert(Point::new(3, -2));
+		expected_trace.insert(Point::new(4, -2));
+		expected_trace.insert(Point::new(3, -3));
+		expected_trace.insert(Point::new(4, -3));
+		expected_trace.insert(Point::new(2, -4));
+		expected_trace.insert(Point::new(3, -4));
 
 		assert_eq!(expected_trace, rope.tail_trace);
 	}
@@ -219,58 +198,62 @@ mod tests {
 		rope.move_head(&Move { direction: Direction::Up, steps: 20 });
 
 		let mut expected_trace = HashSet::new();
-		expected_trace.insert((0, 0));
-		expected_trace.insert((1, 1));
-		expected_trace.insert((2, 2));
-		expected_trace.insert((1, 3));
-		expected_trace.insert((2, 4));
-		expected_trace.insert((3, 5));
-		expected_trace.insert((4, 5));
-		expected_trace.insert((5, 5));
-		expected_trace.insert((6, 4));
-		expected_trace.insert((7, 3));
-		expected_trace.insert((8, 2));
-		expected_trace.insert((9, 1));
-		expected_trace.insert((10, 0));
-		expected_trace.insert((9, -1));
-		expected_trace.insert((8, -2));
-		expected_trace.insert((7, -3));
-		expected_trace.insert((6, -4));
-		expected_trace.insert((5, -5));
-		expected_trace.insert((4, -5));
-		expected_trace.insert((3, -5));
-		expected_trace.insert((2, -5));
-		expected_trace.insert((1, -5));
-		expected_trace.insert((0, -5));
-		expected_trace.insert((-1, -5));
-		expected_trace.insert((-2, -5));
-		expected_trace.insert((-3, -4));
-		expected_trace.insert((-4, -3));
-		expected_trace.insert((-5, -2));
-		expected_trace.insert((-6, -1));
-		expected_trace.insert((-7, 0));
-		expected_trace.insert((-8, 1));
-		expected_trace.insert((-9, 2));
-		expected_trace.insert((-10, 3));
-		expected_trace.insert((-11, 4));
-		expected_trace.insert((-11, 5));
-		expected_trace.insert((-11, 6));
+		expected_trace.insert(Point::new(0, 0));
+		expected_trace.insert(Point::new(1, -1));
+		expected_trace.insert(Point::new(2, -2));
+		expected_trace.insert(Point::new(1, -3));
+		expected_trace.insert(Point::new(2, -4));
+		expected_trace.insert(Point::new(3, -5));
+		expected_trace.insert(Point::new(4, -5));
+		expected_trace.insert(Point::new(5, -5));
+		expected_trace.insert(Point::new(6, -4));
+		expected_trace.insert(Point::new(7, -3));
+		expected_trace.insert(Point::new(8, -2));
+		expected_trace.insert(Point::new(9, -1));
+		expected_trace.insert(Point::new(10, 0));
+		expected_trace.insert(Point::new(9, 1));
+		expected_trace.insert(Point::new(8, 2));
+		expected_trace.insert(Point::new(7, 3));
+		expected_trace.insert(Point::new(6, 4));
+		expected_trace.insert(Point::new(5, 5));
+		expected_trace.insert(Point::new(4, 5));
+		expected_trace.insert(Point::new(3, 5));
+		expected_trace.insert(Point::new(2, 5));
+		expected_trace.insert(Point::new(1, 5));
+		expected_trace.insert(Point::new(0, 5));
+		expected_trace.insert(Point::new(-1, 5));
+		expected_trace.insert(Point::new(-2, 5));
+		expected_trace.insert(Point::new(-3, 4));
+		expected_trace.insert(Point::new(-4, 3));
+		expected_trace.insert(Point::new(-5, 2));
+		expected_trace.insert(Point::new(-6, 1));
+		expected_trace.insert(Point::new(-7, 0));
+		expected_trace.insert(Point::new(-8, -1));
+		expected_trace.insert(Point::new(-9, -2));
+		expected_trace.insert(Point::new(-10, -3));
+		expected_trace.insert(Point::new(-11, -4));
+		expected_trace.insert(Point::new(-11, -5));
+		expected_trace.insert(Point::new(-11, -6));
 
 		assert_eq!(expected_trace, rope.tail_trace);
 	}
 
 	#[test]
 	fn move_from() {
-		let up_four = Move::from("U 4");
+		let up_four = "U 4".parse::<Move>().unwrap();
 		assert_eq!(Move { direction: Direction::Up, steps: 4}, up_four);
 
-		let down_d = Move::from("D 2");
+		let down_d = "D 2".parse::<Move>().unwrap();
 		assert_eq!(Move { direction: Direction::Down, steps: 2}, down_d);
 
-		let right_six = Move::from("R 6");
+		let right_six = "R 6".parse::<Move>().unwrap();
 		assert_eq!(Move { direction: Direction::Right, steps: 6}, right_six);
 
-		let left_one = Move::from("L 1");
+		let left_one = "L 1".parse::<Move>().unwrap();
 		assert_eq!(Move { direction: Direction::Left, steps: 1}, left_one);
+
+		assert_eq!("a direction in (U, D, L, R)", "X 1".parse::<Move>().unwrap_err().expected());
+		assert_eq!(3, "U -1".parse::<Move>().unwrap_err().column());
+		assert!("U".parse::<Move>().is_err());
 	}
 }
\ No newline at end of file
@@ -0,0 +1,24 @@
+use day_09::{Day09, Move, Rope};
+use util::{Direction, Solution};
+
+#[test]
+fn example() {
+    let moves = Day09::parse(include_str!("../fixtures/example.txt")).unwrap();
+
+    assert_eq!(Move::new(Direction::Right, 4), moves[0]);
+
+    let mut rope = Rope::new(2);
+    for mv in &moves {
+        rope.move_head(mv);
+    }
+    assert_eq!(13, rope.tail_trace_count());
+}
+
+#[test]
+fn straight_line() {
+    let mut rope = Rope::new(3);
+    rope.move_head(&"U 5".parse().unwrap());
+
+    assert_eq!(util::Point::new(0, -3), *rope.tail());
+    assert_eq!(4, rope.tail_trace_count());
+}
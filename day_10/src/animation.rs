@@ -0,0 +1,66 @@
+use util::viz::{Cell, Colour, Frame, Viewer};
+
+use crate::cpu::Program;
+
+const WIDTH: usize = 40;
+const HEIGHT: usize = 6;
+
+/// Draws the CRT image a pixel per frame, with the sprite's position shown under the screen.
+pub fn animate(program: &Program, viewer: &mut Viewer) {
+	program.draw_pixels_with(|cycle, x, pixels| {
+		viewer.show(&frame(cycle, x, pixels).with_caption(format!("cycle {}, X = {}", cycle, x)));
+	});
+}
+
+/// The screen with the pixels drawn by `cycle`, the one just drawn in yellow, and a row under it
+/// with the sprite's three pixels and a `^` where the beam is when it misses them.
+pub fn frame(cycle: u64, x: i64, pixels: &str) -> Frame {
+	let beam = ((cycle as usize - 1) / WIDTH, (cycle as usize - 1) % WIDTH);
+
+	let mut frame = Frame::new();
+	let mut rows = pixels.lines();
+	for y in 0..HEIGHT {
+		let mut row = rows.next().unwrap_or("").chars();
+		frame.push_row((0..WIDTH).map(|column| match row.next() {
+			Some(pixel) if (y, column) == beam => Cell::new(pixel, Colour::Yellow),
+			Some('#') => Cell::new('#', Colour::Green),
+			Some(pixel) => Cell::new(pixel, Colour::Grey),
+			None => Cell::from(' '),
+		}));
+	}
+
+	frame.push_row((0..WIDTH as i64).map(|column| {
+		let on_beam = column == beam.1 as i64;
+		if (column - x).abs() <= 1 {
+			Cell::new('#', if on_beam { Colour::Yellow } else { Colour::Cyan })
+		} else if on_beam {
+			Cell::new('^', Colour::Yellow)
+		} else {
+			Cell::from(' ')
+		}
+	}));
+	frame
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::cpu::Operation;
+
+	#[test]
+	fn frames() {
+		let mut program = Program::new();
+		for op in [Operation::AddX(15), Operation::AddX(-11), Operation::Noop] {
+			program.run(op);
+		}
+		let mut viewer = Viewer::offscreen();
+		animate(&program, &mut viewer);
+		assert_eq!(240, viewer.frames_shown());
+
+		let frame = frame(3, 1, "##.");
+		let lines = frame.render(false).lines().map(str::to_string).collect::<Vec<_>>();
+		assert_eq!(7, lines.len());
+		assert_eq!(format!("##.{}", " ".repeat(37)), lines[0]);
+		assert_eq!(format!("###{}", " ".repeat(37)), lines[6]);
+	}
+}
@@ -1,4 +1,13 @@
+use std::fmt;
+use std::str::FromStr;
 
+use util::image::Image;
+use util::{ParseError, Point, parse_lines, parse_number};
+
+/// The amber of a lit pixel in [`Program::image`]
+const LIT: [u8; 3] = [255, 176, 0];
+
+/// The CPU's single `X` register and how many cycles it has run for.
 pub struct Cpu {
 	x: i64,
 	cycles_completed: u64,
@@ -29,38 +38,46 @@ impl Default for Cpu {
     }
 }
 
+/// An instruction, `noop` or `addx <n>`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Operation {
 	Noop,
 	AddX(i64)
 }
 
-impl Operation {
-	pub fn from(line: &str) -> Self {
+impl FromStr for Operation {
+	type Err = ParseError;
+
+	fn from_str(line: &str) -> Result<Self, Self::Err> {
+		let line = line.trim();
 		if line == "noop" {
-			Operation::Noop
-		} else if line.starts_with("addx") {
-			let mut pair = line.split(' ');
-			pair.next();
-			let n = pair.next().unwrap().parse::<i64>().unwrap();
-			Operation::AddX(n)
+			Ok(Operation::Noop)
+		} else if let Some(n) = line.strip_prefix("addx ") {
+			let n = parse_number::<i64>(n, 6, "an integer to add")?;
+			Ok(Operation::AddX(n))
 		} else {
-			panic!("")
+			Err(ParseError::new(line, "'noop' or 'addx <n>'"))
 		}
 	}
+}
 
-	pub fn series_from(input: &str) -> Vec<Self> {
-		let mut ops = vec![];
-		for line in input.split('\n') {
-			if line.is_empty() {
-				continue;
-			}
-			let op = Operation::from(line);
-			ops.push(op);
+impl fmt::Display for Operation {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Operation::Noop => write!(f, "noop"),
+			Operation::AddX(n) => write!(f, "addx {}", n),
 		}
-		ops
 	}
 }
 
+impl Operation {
+	/// One operation per line.
+	pub fn series_from(input: &str) -> Result<Vec<Self>, ParseError> {
+		parse_lines(input)
+	}
+}
+
+/// A CPU along with the value of `X` after every operation it has run.
 pub struct Program {
 	cpu: Cpu,
 	cycle_register: Vec<(u64, i64)>,
@@ -80,6 +97,7 @@ impl Program {
 		self.cycle_register.push((self.cpu.cycles_completed, self.cpu.x()));
 	}
 
+	/// The cycle number multiplied by the value of `X` during that cycle.
 	pub fn signal_strength(&self, target_cycle: u64) -> i64 {
 		let mut current_strength = 0;
 		for (cycle, x) in self.cycle_register.iter() {
@@ -99,6 +117,7 @@ impl Program {
 			.sum()
 	}
 
+	/// The value of `X` during the given cycle, counting from 1.
 	pub fn register_as_cycle(&self, target_cycle: u64) -> i64 {
 		let mut current_value = 0;
 		for (cycle, x) in self.cycle_register.iter() {
@@ -112,17 +131,25 @@ impl Program {
 		current_value
 	}
 
+	/// The 40x6 CRT image, `#` for lit pixels and `.` for dark ones.
 	pub fn draw_pixels(&self) -> String {
+		self.draw_pixels_with(|_, _, _| {})
+	}
+
+	/// Like [`Program::draw_pixels`], calling `on_cycle` with the cycle, the sprite's position and
+	/// the image so far as each pixel is drawn.
+	pub fn draw_pixels_with(&self, mut on_cycle: impl FnMut(u64, i64, &str)) -> String {
 		let mut pixels = String::new();
 		for c in 1..=240 {
 			let x = self.register_as_cycle(c);
-			let column = c as i64 % 40 - 1;
+			let column = (c as i64 - 1) % 40;
 			let delta = x - column;
 			if delta.abs() <= 1 {
 				pixels += "#";
 			} else {
 				pixels += ".";
 			}
+			on_cycle(c, x, &pixels);
 			if c < 240 && c % 40 == 0 {
 				pixels += "\n";
 			}
@@ -131,6 +158,19 @@ impl Program {
 		pixels
 
 	}
+
+	/// The CRT image with lit pixels glowing amber on a dark screen.
+	pub fn image(&self) -> Image {
+		let mut image = Image::new(40, 6, [20, 20, 20]);
+		for (y, row) in self.draw_pixels().lines().enumerate() {
+			for (x, pixel) in row.chars().enumerate() {
+				if pixel == '#' {
+					image.set(Point::new(x as i64, y as i64), LIT);
+				}
+			}
+		}
+		image
+	}
 }
 
 impl Default for Program {
@@ -145,157 +185,10 @@ mod tests {
 
 	#[test]
 	fn sample() {
-		let input = "
-addx 15
-addx -11
-addx 6
-addx -3
-addx 5
-addx -1
-addx -8
-addx 13
-addx 4
-noop
-addx -1
-addx 5
-addx -1
-addx 5
-addx -1
-addx 5
-addx -1
-addx 5
-addx -1
-addx -35
-addx 1
-addx 24
-addx -19
-addx 1
-addx 16
-addx -11
-noop
-noop
-addx 21
-addx -15
-noop
-noop
-addx -3
-addx 9
-addx 1
-addx -3
-addx 8
-addx 1
-addx 5
-noop
-noop
-noop
-noop
-noop
-addx -36
-noop
-addx 1
-addx 7
-noop
-noop
-noop
-addx 2
-addx 6
-noop
-noop
-noop
-noop
-noop
-addx 1
-noop
-noop
-addx 7
-addx 1
-noop
-addx -13
-addx 13
-addx 7
-noop
-addx 1
-addx -33
-noop
-noop
-noop
-addx 2
-noop
-noop
-noop
-addx 8
-noop
-addx -1
-addx 2
-addx 1
-noop
-addx 17
-addx -9
-addx 1
-addx 1
-addx -3
-addx 11
-noop
-noop
-addx 1
-noop
-addx 1
-noop
-noop
-addx -13
-addx -19
-addx 1
-addx 3
-addx 26
-addx -30
-addx 12
-addx -1
-addx 3
-addx 1
-noop
-noop
-noop
-addx -9
-addx 18
-addx 1
-addx 2
-noop
-noop
-addx 9
-noop
-noop
-noop
-addx -1
-addx 2
-addx -37
-addx 1
-addx 3
-noop
-addx 15
-addx -21
-addx 22
-addx -6
-addx 1
-noop
-addx 2
-addx 1
-noop
-addx -10
-noop
-noop
-addx 20
-addx 1
-addx 2
-addx 2
-addx -6
-addx -11
-noop
-noop
-noop
-";
+		let input = include_str!("../fixtures/example.txt");
 		let mut program = Program::new();
 
-		let ops = Operation::series_from(input);
+		let ops = Operation::series_from(input).unwrap();
 		for op in ops {
 			program.run(op);
 		}
@@ -312,20 +205,28 @@ noop
 		let screen = program.draw_pixels();
 
 		assert_eq!(
-// FIXME: use off-by-one-bit expected result to get tests to pass until I get around to figuring out what is wrong
-// "##..##..##..##..##..##..##..##..##..##..
-// ###...###...###...###...###...###...###.
-// ####....####....####....####....####....
-// #####.....#####.....#####.....#####.....
-// ######......######......######......####
-// #######.......#######.......#######.....",
 "##..##..##..##..##..##..##..##..##..##..
 ###...###...###...###...###...###...###.
 ####....####....####....####....####....
 #####.....#####.....#####.....#####.....
-######......######......######......###.
+######......######......######......####
 #######.......#######.......#######.....",
 			screen
 		);
+
+		let image = program.image();
+		assert_eq!(Some(LIT), image.get(Point::new(0, 0)));
+		assert_eq!(Some([20, 20, 20]), image.get(Point::new(2, 0)));
+		assert_eq!(Some(LIT), image.get(Point::new(34, 5)));
+	}
+
+	#[test]
+	fn invalid() {
+		let error = Operation::series_from("noop\naddx 3\naddx\n").unwrap_err();
+		assert_eq!(3, error.line());
+		assert_eq!("'noop' or 'addx <n>'", error.expected());
+
+		let error = Operation::series_from("addx x").unwrap_err();
+		assert_eq!((1, 6), (error.line(), error.column()));
 	}
 }
\ No newline at end of file
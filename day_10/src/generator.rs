@@ -0,0 +1,29 @@
+use util::Rng;
+
+/// `scale` instructions, at least enough to keep the CPU busy for the 240 cycles the CRT draws.
+pub fn generate(rng: &mut Rng, scale: usize) -> String {
+	(0..scale.max(240))
+		.map(|_| if rng.chance(0.3) {
+			"noop\n".to_string()
+		} else {
+			format!("addx {}\n", rng.range(-20..=20))
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use util::{Part, Puzzle, Report, Timer};
+
+	use super::*;
+	use crate::Day10;
+
+	#[test]
+	fn solvable() {
+		let input = generate(&mut Rng::new(1), 100);
+
+		assert_eq!(240, input.lines().count());
+		assert_eq!(input, generate(&mut Rng::new(1), 100));
+		assert!(Puzzle::of::<Day10>().solve(&input, &Part::all(), &Timer::with_report(Report::Quiet)).is_ok());
+	}
+}
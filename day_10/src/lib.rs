@@ -0,0 +1,92 @@
+//! Day 10: Cathode-Ray Tube - running a tiny CPU program and reading the image it draws.
+
+pub mod cpu;
+pub mod generator;
+pub mod animation;
+
+use std::fmt::Display;
+
+pub use cpu::{Cpu, Program, Operation};
+use util::image::Image;
+use util::viz::Viewer;
+use util::{NoAnswer, ParseError, Rng, Solution, write_lines};
+
+/// Runs the [`Operation`]s as a [`Program`], summing signal strengths and then drawing the CRT image.
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const MANIFEST_DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    type Input = Vec<Operation>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Operation::series_from(input)
+    }
+
+    fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+        Some(generator::generate(rng, scale))
+    }
+
+    fn serialize(ops: &Vec<Operation>) -> Option<String> {
+        Some(write_lines(ops))
+    }
+
+    fn animate(ops: &Vec<Operation>, viewer: &mut Viewer) -> bool {
+        animation::animate(&run_program(ops), viewer);
+        true
+    }
+
+    fn image(ops: &Vec<Operation>) -> Option<Image> {
+        Some(run_program(ops).image())
+    }
+
+    fn part_1(ops: &Vec<Operation>) -> Result<impl Display, NoAnswer> {
+        let program = run_program(ops);
+
+        Ok(program.signal_strength_sum(&[20, 60, 100, 140, 180, 220]))
+    }
+
+    fn part_2(ops: &Vec<Operation>) -> Result<impl Display, NoAnswer> {
+        let program = run_program(ops);
+
+        Ok(program.draw_pixels())
+    }
+}
+
+fn run_program(ops: &[Operation]) -> Program {
+    let mut program = Program::new();
+
+    for op in ops {
+        program.run(*op);
+    }
+
+    program
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::{TestCaseError, prop_assert_eq, proptest};
+    use util::round_trip;
+
+    use super::*;
+
+    #[test]
+    fn fixtures() {
+        util::fixtures::assert_fixtures(util::Puzzle::of::<Day10>());
+    }
+
+    proptest! {
+        #[test]
+        fn parse_anything(input in "(noop|addx|-|[0-9]+| |\\n)*") {
+            round_trip::check::<Day10>(&input).map_err(TestCaseError::fail)?;
+        }
+
+        #[test]
+        fn round_trip(seed: u64, scale in 1..50usize) {
+            let input = Day10::generate(&mut Rng::new(seed), scale).unwrap();
+
+            prop_assert_eq!(Ok(true), round_trip::check::<Day10>(&input));
+        }
+    }
+}
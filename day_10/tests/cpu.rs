@@ -0,0 +1,22 @@
+use day_10::{Day10, Operation, Program};
+use util::Solution;
+
+#[test]
+fn example() {
+    let ops = Day10::parse(include_str!("../fixtures/example.txt")).unwrap();
+
+    let mut program = Program::new();
+    for op in ops {
+        program.run(op);
+    }
+
+    assert_eq!(420, program.signal_strength(20));
+    assert_eq!(13140, program.signal_strength_sum(&[20, 60, 100, 140, 180, 220]));
+    assert!(program.draw_pixels().starts_with("##..##..##..##..##..##..##..##..##..##..\n"));
+}
+
+#[test]
+fn operations() {
+    assert_eq!(vec![Operation::Noop, Operation::AddX(-5)], Operation::series_from("noop\naddx -5\n").unwrap());
+    assert!("addx".parse::<Operation>().is_err());
+}
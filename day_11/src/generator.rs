@@ -0,0 +1,67 @@
+use util::Rng;
+
+const PRIMES: [u64; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+
+/// 2-8 monkeys sharing `scale` items between them, shaped like the real puzzle: every monkey
+/// tests a different prime, one multiplies the worry level and the rest add to it.
+///
+/// Nobody squares it. Part 1 only divides by 3, so an item squared a few times overflows, and the
+/// real inputs are careful never to let that happen. Multiplying by at most 19 once per round
+/// stays in range for all 20 rounds.
+pub fn generate(rng: &mut Rng, scale: usize) -> String {
+    let count = rng.below(7) + 2;
+    let mut divisors = PRIMES.to_vec();
+    rng.shuffle(&mut divisors);
+
+    let mut items = vec![vec![]; count];
+    for _ in 0..scale.max(1) {
+        items[rng.below(count)].push(rng.range(50..=99).to_string());
+    }
+
+    let monkeys = (0..count)
+        .map(|index| {
+            let operation = match index {
+                0 => format!("old * {}", rng.range(2..=19)),
+                1 => "old + old".to_string(),
+                _ => format!("old + {}", rng.range(1..=8)),
+            };
+            let if_true = (index + rng.below(count - 1) + 1) % count;
+            let if_false = loop {
+                let target = rng.below(count);
+                if target != index && target != if_true {
+                    break target;
+                }
+                if count == 2 {
+                    break if_true;
+                }
+            };
+            // the grammar needs at least one starting item
+            let starting = if items[index].is_empty() { "0".to_string() } else { items[index].join(", ") };
+
+            format!(
+                "Monkey {}:\n  Starting items: {}\n  Operation: new = {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}\n",
+                index, starting, operation, divisors[index], if_true, if_false,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    monkeys.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use util::{Part, Puzzle, Report, Timer};
+
+    use super::*;
+    use crate::Day11;
+
+    #[test]
+    fn solvable() {
+        for seed in 0..5 {
+            let input = generate(&mut Rng::new(seed), 20);
+
+            assert_eq!(input, generate(&mut Rng::new(seed), 20));
+            assert!(Puzzle::of::<Day11>().solve(&input, &Part::all(), &Timer::with_report(Report::Quiet)).is_ok());
+        }
+    }
+}
@@ -1,12 +1,14 @@
 use crate::monkey::{Monkey, InspectionRelief};
 
+/// The product of every monkey's divisor, which worry levels can be reduced by without changing any test.
 pub fn common_factors(monkeys: &[Monkey]) -> u64 {
     monkeys.iter()
         .map(|m| m.divisor())
         .product()
 }
 
-pub fn keep_away(monkeys: &mut Vec<Monkey>, rounds: u32, relief: &InspectionRelief) {
+/// Plays the given number of rounds, each monkey inspecting and throwing all its items in turn.
+pub fn keep_away(monkeys: &mut [Monkey], rounds: u32, relief: &InspectionRelief) {
 
     for _ in 0..rounds {
         for index in 0..monkeys.len() {
@@ -28,6 +30,7 @@ pub fn keep_away(monkeys: &mut Vec<Monkey>, rounds: u32, relief: &InspectionReli
     }
 }
 
+/// The inspection counts of the two most active monkeys multiplied together.
 pub fn monkey_business_level(monkeys: &[Monkey]) -> u64 {
     let mut item_inspections = monkeys.iter()
         .map(|m| m.inspection_count())
@@ -46,35 +49,9 @@ mod tests {
 
     #[test]
     fn sample_two_thirds() {
-        let input = "Monkey 0:
-        Starting items: 79, 98
-        Operation: new = old * 19
-        Test: divisible by 23
-            If true: throw to monkey 2
-            If false: throw to monkey 3
-    
-    Monkey 1:
-        Starting items: 54, 65, 75, 74
-        Operation: new = old + 6
-        Test: divisible by 19
-            If true: throw to monkey 2
-            If false: throw to monkey 0
-    
-    Monkey 2:
-        Starting items: 79, 60, 97
-        Operation: new = old * old
-        Test: divisible by 13
-            If true: throw to monkey 1
-            If false: throw to monkey 3
-    
-    Monkey 3:
-        Starting items: 74
-        Operation: new = old + 3
-        Test: divisible by 17
-            If true: throw to monkey 0
-            If false: throw to monkey 1";
+        let input = include_str!("../fixtures/example.txt");
         
-        let mut monkeys = parse_monkeys(input);
+        let mut monkeys = parse_monkeys(input).unwrap();
 
         let relief = InspectionRelief::TwoThirds;
 
@@ -117,35 +94,9 @@ mod tests {
 
     #[test]
     fn sample_none() {
-        let input = "Monkey 0:
-        Starting items: 79, 98
-        Operation: new = old * 19
-        Test: divisible by 23
-            If true: throw to monkey 2
-            If false: throw to monkey 3
-    
-    Monkey 1:
-        Starting items: 54, 65, 75, 74
-        Operation: new = old + 6
-        Test: divisible by 19
-            If true: throw to monkey 2
-            If false: throw to monkey 0
-    
-    Monkey 2:
-        Starting items: 79, 60, 97
-        Operation: new = old * old
-        Test: divisible by 13
-            If true: throw to monkey 1
-            If false: throw to monkey 3
-    
-    Monkey 3:
-        Starting items: 74
-        Operation: new = old + 3
-        Test: divisible by 17
-            If true: throw to monkey 0
-            If false: throw to monkey 1";
+        let input = include_str!("../fixtures/example.txt");
         
-        let mut monkeys = parse_monkeys(input);
+        let mut monkeys = parse_monkeys(input).unwrap();
         
         let common_factors = common_factors(&monkeys);
 
@@ -0,0 +1,75 @@
+//! Day 11: Monkey in the Middle - tracking the items monkeys throw to each other.
+
+pub mod monkey;
+pub mod keep_away;
+pub mod generator;
+
+use std::fmt::Display;
+
+pub use keep_away::{keep_away, monkey_business_level, common_factors};
+pub use monkey::{parse_monkeys, InspectionRelief, Monkey};
+use util::{NoAnswer, ParseError, Rng, Solution};
+
+/// Plays [`keep_away()`] for 20 rounds with relief, then 10000 rounds without, and reports the monkey business.
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const MANIFEST_DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    type Input = Vec<Monkey>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_monkeys(input)
+    }
+
+    fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+        Some(generator::generate(rng, scale))
+    }
+
+    fn serialize(monkeys: &Vec<Monkey>) -> Option<String> {
+        Some(monkeys.iter().map(|m| format!("{}\n", m)).collect::<Vec<_>>().join("\n"))
+    }
+
+    fn part_1(monkeys: &Vec<Monkey>) -> Result<impl Display, NoAnswer> {
+        let mut monkeys = monkeys.clone();
+        keep_away(&mut monkeys, 20, &InspectionRelief::TwoThirds);
+
+        Ok(monkey_business_level(&monkeys))
+    }
+
+    fn part_2(monkeys: &Vec<Monkey>) -> Result<impl Display, NoAnswer> {
+        let mut monkeys = monkeys.clone();
+        let common_factors = common_factors(&monkeys);
+        keep_away(&mut monkeys, 10000, &InspectionRelief::None(common_factors));
+
+        Ok(monkey_business_level(&monkeys))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::{TestCaseError, prop_assert_eq, proptest};
+    use util::round_trip;
+
+    use super::*;
+
+    #[test]
+    fn fixtures() {
+        util::fixtures::assert_fixtures(util::Puzzle::of::<Day11>());
+    }
+
+    proptest! {
+        #[test]
+        fn parse_anything(input in "(Monkey|Starting items:|Operation: new = old|[*+]|old|Test: divisible by|If (true|false): throw to monkey|[0-9]+|,|:| |\\n)*") {
+            round_trip::check::<Day11>(&input).map_err(TestCaseError::fail)?;
+        }
+
+        #[test]
+        fn round_trip(seed: u64, scale in 1..50usize) {
+            let input = Day11::generate(&mut Rng::new(seed), scale).unwrap();
+
+            prop_assert_eq!(Ok(true), round_trip::check::<Day11>(&input));
+        }
+    }
+}
@@ -1,21 +1,26 @@
-use pest::Parser;
+use std::fmt;
+use std::str::FromStr;
+
+use pest::{Parser, iterators::Pair};
 use pest_derive::Parser;
+use util::ParseError;
 
 #[derive(Parser)]
 #[grammar = "monkeys.pest"]
 pub struct MonkeyParser;
 
+#[derive(Clone, Debug, PartialEq, Eq)]
 enum Param {
     Old,
     Value(u64),
 }
 
 impl Param {
-    fn from(input: &str) -> Self {
-        if input == "old" {
-            Param::Old
+    fn from(parsed_value: Pair<Rule>) -> Result<Self, ParseError> {
+        if parsed_value.as_str() == "old" {
+            Ok(Param::Old)
         } else {
-            Param::Value(input.parse::<u64>().unwrap())
+            parse_integer(parsed_value, "'old' or a number").map(Param::Value)
         }
     }
 
@@ -27,6 +32,7 @@ impl Param {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
 enum Operator {
     Add,
     Multiply
@@ -37,16 +43,30 @@ impl Operator {
         match input {
             "+" => Operator::Add,
             "*" => Operator::Multiply,
-            _ => panic!("Invalid operator '{}'", input)
+            _ => unreachable!("the grammar only allows '+' or '*', found '{}'", input)
         }
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
 struct Operation {
     op: Operator,
     param: Param,
 }
 
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let op = match self.op {
+            Operator::Add => '+',
+            Operator::Multiply => '*',
+        };
+        match self.param {
+            Param::Old => write!(f, "old {} old", op),
+            Param::Value(value) => write!(f, "old {} {}", op, value),
+        }
+    }
+}
+
 impl Operation {
     fn perform(&self, old: u64) -> u64 {
         match self.op {
@@ -56,7 +76,10 @@ impl Operation {
     }
 }
 
+/// A monkey's items, how it changes their worry levels and who it throws them to.
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Monkey {
+    id: u8,
     items: Vec<u64>,
     operation: Operation,
     test_divisor: u64,
@@ -65,12 +88,16 @@ pub struct Monkey {
     items_inspected_count: u64,
 }
 
+/// What happens to an item's worry level once a monkey has inspected it.
 pub enum InspectionRelief {
+    /// No relief, but keep the worry level below this common multiple of the monkeys' divisors
     None(u64),
+    /// Divided by 3
     TwoThirds
 }
 
 impl Monkey {
+    /// Inspects the first item, returning the monkey it's thrown to and its new worry level.
     pub fn inspect(&mut self, relief: &InspectionRelief) -> Option<(u8, u64)> {
         if self.items.is_empty() {
             None
@@ -91,7 +118,7 @@ impl Monkey {
                 },
             }
 
-            let target = if item % self.test_divisor == 0 {
+            let target = if item.is_multiple_of(self.test_divisor) {
                 self.test_pass_throw_target
             } else {
                 self.test_fail_throw_target
@@ -101,6 +128,14 @@ impl Monkey {
         }
     }
 
+    pub fn id(&self) -> u8 {
+        self.id
+    }
+
+    pub fn items(&self) -> &Vec<u64> {
+        &self.items
+    }
+
     pub fn catch(&mut self, item: u64) {
         self.items.push(item);
     }
@@ -109,143 +144,232 @@ impl Monkey {
         self.items_inspected_count
     }
 
+    /// The number a worry level has to be divisible by to pass the monkey's test.
     pub fn divisor(&self) -> u64 {
         self.test_divisor
     }
 }
 
-pub fn parse_monkeys(input: &str) -> Vec<Monkey> {
-    let parsed_monkeys = MonkeyParser::parse(Rule::monkeys, input)
-    .expect("failed to parse monkeys")
-    .next().unwrap();
+/// The monkey's notes as the puzzle writes them, starting with `Monkey <id>:`.
+impl fmt::Display for Monkey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let items = self.items.iter().map(|item| item.to_string()).collect::<Vec<_>>();
+
+        writeln!(f, "Monkey {}:", self.id)?;
+        writeln!(f, "  Starting items: {}", items.join(", "))?;
+        writeln!(f, "  Operation: new = {}", self.operation)?;
+        writeln!(f, "  Test: divisible by {}", self.test_divisor)?;
+        writeln!(f, "    If true: throw to monkey {}", self.test_pass_throw_target)?;
+        write!(f, "    If false: throw to monkey {}", self.test_fail_throw_target)
+    }
+}
+
+impl FromStr for Monkey {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let parsed_monkey = MonkeyParser::parse(Rule::single_monkey, input)?
+            .next().unwrap()
+            .into_inner().next().unwrap();
+
+        parse_monkey(parsed_monkey)
+    }
+}
+
+/// Monkeys in the order they're numbered.
+pub fn parse_monkeys(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    let parsed_monkeys = MonkeyParser::parse(Rule::input, input)?
+        .next().unwrap()
+        .into_inner().next().unwrap();
 
     let mut monkeys = vec![];
+    let mut throws = vec![];
 
     for parsed_monkey in parsed_monkeys.into_inner() {
         match parsed_monkey.as_rule() {
             Rule::monkey => {
-                let mut id: Option<u8> = None;
-                let mut starting_items: Option<Vec<u64>> = None;
-                let mut operation: Option<Operation> = None;
-                let mut test_divisor: Option<u64> = None;
-                let mut test_pass_throw_target: Option<u8> = None;
-                let mut test_fail_throw_target: Option<u8> = None;
-
-                for parsed_monkey_inner in parsed_monkey.into_inner() {
-                    match parsed_monkey_inner.as_rule() {
-                        Rule::declaration => {
-                            let parsed_id = parsed_monkey_inner.into_inner().next().unwrap().as_str()
-                                .parse::<u8>().unwrap();
-
-                            id.replace(parsed_id);
-                        },
-                        Rule::starting_items => {
-                            let mut items = vec![];
-
-                            for parsed_item in parsed_monkey_inner.into_inner() {
-                                let item = parsed_item.as_str()
-                                    .parse::<u64>().unwrap();
-
-                                items.push(item);
-                            }
-
-                            starting_items.replace(items);
-                        },
-                        Rule::operation => {
-                            let mut parsed_op_components = parsed_monkey_inner.into_inner();
-                            let op = Operator::from(parsed_op_components.next().unwrap().as_str());
-                            let param = Param::from(parsed_op_components.next().unwrap().as_str());
-
-                            operation.replace(Operation { op, param });
-                        },
-                        Rule::test => {
-                            let divisor = parsed_monkey_inner.into_inner().next().unwrap().as_str()
-                                .parse::<u64>().unwrap();
-
-                            test_divisor.replace(divisor);
-                        },
-                        Rule::if_true => {
-                            let target = parsed_monkey_inner.into_inner().next().unwrap().as_str()
-                                .parse::<u8>().unwrap();
-
-                            test_pass_throw_target.replace(target);
-                        },
-                        Rule::if_false => {
-                            let target = parsed_monkey_inner.into_inner().next().unwrap().as_str()
-                            .parse::<u8>().unwrap();
-
-                            test_fail_throw_target.replace(target);
-                        },
-                        _ => panic!("unexpected rule '{:?}': {}", parsed_monkey_inner.as_rule(), parsed_monkey_inner.as_str())
-                    }
+                let (line, column) = parsed_monkey.as_span().start_pos().line_col();
+                let declaration = parsed_monkey.as_str().lines().next().unwrap_or_default().to_string();
+                let monkey = parse_monkey(parsed_monkey)?;
+
+                if monkey.id as usize != monkeys.len() {
+                    let expected = format!("monkey {} to be declared next", monkeys.len());
+                    return Err(ParseError::new(&declaration, expected).at(line, column));
                 }
 
-                assert!(id.is_some());
-                assert!(starting_items.is_some());
-                assert!(operation.is_some());
-                assert!(test_divisor.is_some());
-                assert!(test_pass_throw_target.is_some());
-                assert!(test_fail_throw_target.is_some());
-
-                let monkey = Monkey {
-                    items: starting_items.unwrap(),
-                    operation: operation.unwrap(),
-                    test_divisor: test_divisor.unwrap(),
-                    test_pass_throw_target: test_pass_throw_target.unwrap(),
-                    test_fail_throw_target: test_fail_throw_target.unwrap(),
-                    items_inspected_count: 0,
-                };
+                throws.push((line, column, declaration));
                 monkeys.push(monkey);
             },
-            _ => panic!("unexpected rule")
+            _ => unreachable!("unexpected rule")
+        }
+    }
+
+    for (monkey, (line, column, declaration)) in monkeys.iter().zip(throws) {
+        for target in [monkey.test_pass_throw_target, monkey.test_fail_throw_target] {
+            if target as usize >= monkeys.len() {
+                let expected = format!("throws to monkeys 0 to {}", monkeys.len() - 1);
+                return Err(ParseError::new(&declaration, expected).at(line, column));
+            }
         }
     }
 
-    monkeys
+    Ok(monkeys)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+fn parse_monkey(parsed_monkey: Pair<Rule>) -> Result<Monkey, ParseError> {
+    let mut id: Option<u8> = None;
+    let mut starting_items: Option<Vec<u64>> = None;
+    let mut operation: Option<Operation> = None;
+    let mut test_divisor: Option<u64> = None;
+    let mut test_pass_throw_target: Option<u8> = None;
+    let mut test_fail_throw_target: Option<u8> = None;
 
-    impl Monkey {
-        pub fn items(&self) -> &Vec<u64> {
-            &self.items
+    for parsed_monkey_inner in parsed_monkey.into_inner() {
+        match parsed_monkey_inner.as_rule() {
+            Rule::declaration => {
+                let parsed_id = parse_integer(parsed_monkey_inner.into_inner().next().unwrap(), "a monkey id in 0-255")?;
+
+                id.replace(parsed_id);
+            },
+            Rule::starting_items => {
+                let mut items = vec![];
+
+                for parsed_item in parsed_monkey_inner.into_inner() {
+                    let item = parse_integer(parsed_item, "a worry level")?;
+
+                    items.push(item);
+                }
+
+                starting_items.replace(items);
+            },
+            Rule::operation => {
+                let mut parsed_op_components = parsed_monkey_inner.into_inner();
+                let op = Operator::from(parsed_op_components.next().unwrap().as_str());
+                let param = Param::from(parsed_op_components.next().unwrap())?;
+
+                operation.replace(Operation { op, param });
+            },
+            Rule::test => {
+                let parsed_divisor = parsed_monkey_inner.into_inner().next().unwrap();
+                let divisor = parse_integer(parsed_divisor.clone(), "a divisor")?;
+                if divisor == 0 {
+                    let (line, column) = parsed_divisor.as_span().start_pos().line_col();
+                    return Err(ParseError::new(parsed_divisor.as_str(), "a divisor above 0").at(line, column));
+                }
+
+                test_divisor.replace(divisor);
+            },
+            Rule::if_true => {
+                let target = parse_integer(parsed_monkey_inner.into_inner().next().unwrap(), "a monkey id in 0-255")?;
+
+                test_pass_throw_target.replace(target);
+            },
+            Rule::if_false => {
+                let target = parse_integer(parsed_monkey_inner.into_inner().next().unwrap(), "a monkey id in 0-255")?;
+
+                test_fail_throw_target.replace(target);
+            },
+            _ => unreachable!("unexpected rule '{:?}': {}", parsed_monkey_inner.as_rule(), parsed_monkey_inner.as_str())
         }
     }
 
+    // the grammar guarantees every part of a monkey is present
+    let monkey = Monkey {
+        id: id.unwrap(),
+        items: starting_items.unwrap(),
+        operation: operation.unwrap(),
+        test_divisor: test_divisor.unwrap(),
+        test_pass_throw_target: test_pass_throw_target.unwrap(),
+        test_fail_throw_target: test_fail_throw_target.unwrap(),
+        items_inspected_count: 0,
+    };
+
+    Ok(monkey)
+}
+
+fn parse_integer<T: FromStr>(parsed_integer: Pair<Rule>, expected: &str) -> Result<T, ParseError> {
+    let (line, column) = parsed_integer.as_span().start_pos().line_col();
+
+    parsed_integer.as_str().parse::<T>()
+        .map_err(|_| ParseError::new(parsed_integer.as_str(), expected).at(line, column))
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    use super::*;
+
     #[test]
     fn sample() {
-        let input = "Monkey 0:
-        Starting items: 79, 98
-        Operation: new = old * 19
-        Test: divisible by 23
-            If true: throw to monkey 2
-            If false: throw to monkey 3
-    
-    Monkey 1:
-        Starting items: 54, 65, 75, 74
-        Operation: new = old + 6
-        Test: divisible by 19
-            If true: throw to monkey 2
-            If false: throw to monkey 0
-    
-    Monkey 2:
-        Starting items: 79, 60, 97
-        Operation: new = old * old
-        Test: divisible by 13
-            If true: throw to monkey 1
-            If false: throw to monkey 3
-    
-    Monkey 3:
-        Starting items: 74
-        Operation: new = old + 3
-        Test: divisible by 17
-            If true: throw to monkey 0
-            If false: throw to monkey 1";
+        let input = include_str!("../fixtures/example.txt");
         
-        let monkeys = parse_monkeys(input);
+        let monkeys = parse_monkeys(input).unwrap();
 
         assert_eq!(4, monkeys.len());
     }
+
+    #[test]
+    fn single() {
+        let monkey = "Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1".parse::<Monkey>().unwrap();
+
+        assert_eq!(&vec![74], monkey.items());
+        assert_eq!(17, monkey.divisor());
+    }
+
+    #[test]
+    fn invalid() {
+        let error = "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old ^ 19
+  Test: divisible by 23
+    If true: throw to monkey 0
+    If false: throw to monkey 0".parse::<Monkey>().unwrap_err();
+        assert_eq!((3, 24), (error.line(), error.column()));
+        assert_eq!("  Operation: new = old ^ 19", error.text());
+
+        let error = parse_monkeys("Monkey 0:
+  Starting items: 79
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 0").unwrap_err();
+        assert_eq!("throws to monkeys 0 to 0", error.expected());
+
+        let error = parse_monkeys("Monkey 0:
+  Starting items: 79
+  Operation: new = old * 99999999999999999999
+  Test: divisible by 23
+    If true: throw to monkey 0
+    If false: throw to monkey 0").unwrap_err();
+        assert_eq!((3, 26), (error.line(), error.column()));
+    }
+
+    proptest! {
+        #[test]
+        fn round_trip(
+            id: u8,
+            items in vec(any::<u64>(), 1..10),
+            op in "[*+]",
+            value in proptest::option::of(any::<u64>()),
+            divisor in 1..=u64::MAX,
+            if_true: u8,
+            if_false: u8,
+        ) {
+            let items = items.iter().map(|item| item.to_string()).collect::<Vec<_>>();
+            let value = value.map_or("old".to_string(), |v| v.to_string());
+            let notes = format!(
+                "Monkey {}:\n  Starting items: {}\n  Operation: new = old {} {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}",
+                id, items.join(", "), op, value, divisor, if_true, if_false,
+            );
+
+            prop_assert_eq!(&notes, &notes.parse::<Monkey>().unwrap().to_string());
+        }
+    }
 }
\ No newline at end of file
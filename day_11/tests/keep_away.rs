@@ -0,0 +1,17 @@
+use day_11::{Day11, InspectionRelief, common_factors, keep_away, monkey_business_level};
+use util::Solution;
+
+#[test]
+fn example() {
+    let mut monkeys = Day11::parse(include_str!("../fixtures/example.txt")).unwrap();
+
+    assert_eq!(vec![79, 98], *monkeys[0].items());
+    assert_eq!(96577, common_factors(&monkeys));
+
+    keep_away(&mut monkeys, 1, &InspectionRelief::TwoThirds);
+    assert_eq!(vec![20, 23, 27, 26], *monkeys[0].items());
+    assert_eq!(vec![2, 4, 3, 5], monkeys.iter().map(|m| m.inspection_count()).collect::<Vec<_>>());
+
+    keep_away(&mut monkeys, 19, &InspectionRelief::TwoThirds);
+    assert_eq!(10605, monkey_business_level(&monkeys));
+}
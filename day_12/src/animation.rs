@@ -0,0 +1,70 @@
+use util::{Direction, Grid};
+use util::viz::{Cell, Colour, Frame, Viewer};
+
+use crate::map::{Location, Map};
+use crate::orienteering::find_easiest_route_with;
+
+/// About how many frames the search takes to spread across the whole map.
+const FRAMES: usize = 200;
+
+/// Watches the search spread out from `S` until it reaches `E`, then draws the route it found.
+pub fn animate(map: &Map, viewer: &mut Viewer) {
+    let every = (map.width() * map.height() / FRAMES).max(1);
+    let mut explored = Grid::new(map.width(), map.height(), false);
+    let mut expanded = 0;
+
+    let route = find_easiest_route_with(map, &map.start(), &map.end(), |location| {
+        explored[*location] = true;
+        expanded += 1;
+        if expanded % every == 0 {
+            viewer.show(&frame(map, &explored, &[]).with_caption(format!("explored {} locations", expanded)));
+        }
+    });
+
+    let caption = match &route {
+        Some(route) => format!("explored {} locations, reached E in {} steps", expanded, route.len()),
+        None => format!("explored {} locations, E can't be reached", expanded),
+    };
+    viewer.show(&frame(map, &explored, route.as_deref().unwrap_or(&[])).with_caption(caption));
+}
+
+/// The heightmap with explored locations in green, unexplored ones in grey and the steps of `route`
+/// drawn as red arrows.
+pub fn frame(map: &Map, explored: &Grid<bool>, route: &[Location]) -> Frame {
+    Frame::from_grid(explored, |location, explored| {
+        if let Some(index) = route.iter().position(|step| *step == location) {
+            let next = route.get(index + 1).copied().unwrap_or(map.end());
+            let arrow = Direction::try_from(next - location).map_or('?', |d| d.arrow());
+            Cell::new(arrow, Colour::Red)
+        } else if location == map.start() {
+            Cell::new('S', Colour::Cyan)
+        } else if location == map.end() {
+            Cell::new('E', Colour::Cyan)
+        } else if *explored {
+            Cell::new(map.elevation(&location) as char, Colour::Green)
+        } else {
+            Cell::new(map.elevation(&location) as char, Colour::Grey)
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frames() {
+        let map = include_str!("../fixtures/example.txt").parse::<Map>().unwrap();
+        let mut viewer = Viewer::offscreen();
+
+        animate(&map, &mut viewer);
+        // a frame per location on a map this small, and at least one for each step of the route
+        assert!((32..=41).contains(&viewer.frames_shown()));
+
+        let explored = Grid::new(map.width(), map.height(), false);
+        let route = crate::find_easiest_route(&map, &map.start(), &map.end()).unwrap();
+        let text = frame(&map, &explored, &route).render(false);
+        assert_eq!(31, text.chars().filter(|c| "<>^v".contains(*c)).count());
+        assert_eq!(1, text.matches('E').count());
+    }
+}
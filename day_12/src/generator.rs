@@ -0,0 +1,51 @@
+use util::{Grid, Point, Rng};
+
+/// A heightmap `scale` locations wide (at least 26, to climb from `a` to `z`) and half as tall.
+///
+/// Everything off the route is random, but a winding route from `S` on the left to `E` on the right
+/// climbs one step at a time, so the end can always be reached.
+pub fn generate(rng: &mut Rng, scale: usize) -> String {
+    let width = scale.max(26);
+    let height = (width / 2).max(5);
+    let mut map = Grid::from_cells(width, (0..width * height).map(|_| b'a' + rng.below(26) as u8).collect());
+
+    // right one column at a time, wandering up or down inside each column first
+    let mut route = vec![];
+    let mut y = rng.below(height) as i64;
+    for x in 0..width as i64 {
+        route.push(Point::new(x, y));
+        let target = rng.below(height) as i64;
+        while y != target && rng.chance(0.7) {
+            y += (target - y).signum();
+            route.push(Point::new(x, y));
+        }
+    }
+
+    let last = route.len() - 1;
+    for (step, location) in route.iter().enumerate() {
+        map[*location] = b'a' + (25 * step / last) as u8;
+    }
+    map[route[0]] = b'S';
+    map[route[last]] = b'E';
+
+    map.render(|_, height| *height as char) + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use util::{Part, Puzzle, Report, Timer};
+
+    use super::*;
+    use crate::Day12;
+
+    #[test]
+    fn solvable() {
+        for seed in 0..5 {
+            let input = generate(&mut Rng::new(seed), 40);
+
+            assert_eq!(20, input.lines().count());
+            assert_eq!(input, generate(&mut Rng::new(seed), 40));
+            assert!(Puzzle::of::<Day12>().solve(&input, &Part::all(), &Timer::with_report(Report::Quiet)).is_ok());
+        }
+    }
+}
@@ -0,0 +1,95 @@
+//! Day 12: Hill Climbing Algorithm - finding the fewest steps up to the best signal.
+
+pub mod map;
+pub mod orienteering;
+pub mod generator;
+pub mod animation;
+
+use std::fmt::Display;
+
+pub use map::{Location, Map};
+pub use orienteering::{find_easiest_route, find_easiest_route_from_easiest_start, find_easiest_route_with, draw_route, print_route};
+use util::image::Image;
+use util::viz::Viewer;
+use util::{NoAnswer, ParseError, Rng, Solution};
+
+/// Counts the steps of the easiest route across the [`Map`] from `S`, then from any lowest location.
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const MANIFEST_DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+        Some(generator::generate(rng, scale))
+    }
+
+    fn serialize(map: &Map) -> Option<String> {
+        Some(format!("{}\n", map))
+    }
+
+    fn animate(map: &Map, viewer: &mut Viewer) -> bool {
+        animation::animate(map, viewer);
+        true
+    }
+
+    fn image(map: &Map) -> Option<Image> {
+        let route = find_easiest_route(map, &map.start(), &map.end()).unwrap_or_default();
+        Some(draw_route(map, &route))
+    }
+
+    fn part_1(map: &Map) -> Result<impl Display, NoAnswer> {
+        let route = find_easiest_route(map, &map.start(), &map.end())
+            .ok_or_else(|| NoAnswer::new("the best signal can't be reached from the start"))?;
+
+        Ok(route.len())
+    }
+
+    fn part_2(map: &Map) -> Result<impl Display, NoAnswer> {
+        let route = find_easiest_route_from_easiest_start(map, &map.end())
+            .ok_or_else(|| NoAnswer::new("the best signal can't be reached from any lowest square"))?;
+
+        Ok(route.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::{TestCaseError, prop_assert_eq, proptest};
+    use util::{Part, round_trip};
+
+    use super::*;
+
+    #[test]
+    fn fixtures() {
+        util::fixtures::assert_fixtures(util::Puzzle::of::<Day12>());
+    }
+
+    #[test]
+    fn no_answer() {
+        let input = Day12::parse("SzE").unwrap();
+
+        assert_eq!(Err(NoAnswer::new("the best signal can't be reached from the start")), Day12::solve(&input, Part::One));
+        assert_eq!(Err(NoAnswer::new("the best signal can't be reached from any lowest square")), Day12::solve(&input, Part::Two));
+    }
+
+    proptest! {
+        #[test]
+        fn parse_anything(input in "[a-zSE \\n]*") {
+            round_trip::check::<Day12>(&input).map_err(TestCaseError::fail)?;
+        }
+
+        #[test]
+        fn round_trip(seed: u64, scale in 1..50usize) {
+            let input = Day12::generate(&mut Rng::new(seed), scale).unwrap();
+
+            prop_assert_eq!(Ok(true), round_trip::check::<Day12>(&input));
+        }
+    }
+}
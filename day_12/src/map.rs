@@ -1,153 +1,99 @@
-pub struct Map {
-    grid: Vec<u8>,
-    width: usize,
-    height: usize,
-}
-
-pub type Location = (i64, i64);
+use std::fmt;
+use std::str::FromStr;
 
-trait GridLocation {
-    type Item;
+use util::{Grid, ParseError, Point};
 
-    fn up(&self) -> Self::Item;
-    fn right(&self) -> Self::Item;
-    fn down(&self) -> Self::Item;
-    fn left(&self) -> Self::Item;
+/// A heightmap of `a`-`z` with the start `S` at height `a` and the end `E` at height `z`.
+pub struct Map {
+    grid: Grid<u8>,
+    start: Location,
+    end: Location,
 }
 
-impl GridLocation for Location {
-    type Item = Self;
-
-    fn up(&self) -> Self {
-        (self.0, self.1 - 1)
-    }
-
-    fn right(&self) -> Self {
-        (self.0 + 1, self.1)
-    }
-
-    fn down(&self) -> Self {
-        (self.0, self.1 + 1)
-    }
+pub type Location = Point;
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut start: Option<Location> = None;
+        let mut end: Option<Location> = None;
+
+        let grid = Grid::parse(input, |location, c| match c {
+            'S' => match start.replace(location) {
+                None => Ok(b'a'),
+                Some(_) => Err("a single 'S' start location".to_string()),
+            },
+            'E' => match end.replace(location) {
+                None => Ok(b'z'),
+                Some(_) => Err("a single 'E' end location".to_string()),
+            },
+            'a'..='z' => Ok(c as u8),
+            _ => Err("an elevation in a-z, 'S' or 'E'".to_string()),
+        })?;
+
+        let last_line = input.split('\n').count();
+        let (Some(start), Some(end)) = (start, end) else {
+            return Err(ParseError::new("", "a map with an 'S' start and an 'E' end").at(last_line, 1));
+        };
 
-    fn left(&self) -> Self {
-        (self.0 - 1, self.1)
+        Ok(Self { grid, start, end })
     }
 }
 
-impl Map {
-    pub fn from(input: &str) -> (Self, Location, Location) {
-        let lines = input.split('\n');
-        let mut width: Option<usize> = None;
-        let mut height = 0;
-        let mut start: Option<usize> = None;
-        let mut end: Option<usize> = None;
-        let mut grid = vec![];
-
-        for line in lines {
-            let line = line.trim();
-            if line.is_empty() {
-                continue;
-            }
-            height += 1;
-            if width.is_none() {
-                width.replace(line.len());
+/// The heightmap as the puzzle draws it, with `S` and `E` in place of their heights.
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = self.grid.render(|location, height| {
+            if location == self.start {
+                'S'
+            } else if location == self.end {
+                'E'
             } else {
-                assert_eq!(width.unwrap(), line.len());
-            }
-            for c in line.chars() {
-                let elevation = match c {
-                    'S' => {
-                        if start.is_none() {
-                            start.replace(grid.len());
-                        } else {
-                            panic!("Found a second 'S' grid reference");
-                        }
-                        b'a'
-                    },
-                    'E' => {
-                        if end.is_none() {
-                            end.replace(grid.len());
-                        } else {
-                            panic!("Fround a second 'E' grid reference");
-                        }
-                        b'z'
-                    },
-                    c => c as u8,
-                };
-                grid.push(elevation);
+                *height as char
             }
-        }
-
-        let width = width.unwrap();
-        let start = start.unwrap();
-        let end = end.unwrap();
+        });
+        f.write_str(&text)
+    }
+}
 
-        let map = Self { grid, width, height, };
-        let start = map.location(start);
-        let end = map.location(end);
+impl Map {
+    pub fn start(&self) -> Location {
+        self.start
+    }
 
-        (map, start, end)
+    pub fn end(&self) -> Location {
+        self.end
     }
 
     pub fn width(&self) -> usize {
-        self.width
+        self.grid.width()
     }
 
     pub fn height(&self) -> usize {
-        self.height
+        self.grid.height()
     }
 
-    pub fn location(&self, index: usize) -> Location {
-        let x = index % self.width;
-        let y = index / self.width;
-
-        (x as i64, y as i64)
+    /// The height at `location`, `a` to `z` as bytes.
+    pub fn elevation(&self, location: &Location) -> u8 {
+        self.grid[*location]
     }
 
+    /// The neighbouring locations that are at most one higher, so can be stepped to.
     pub fn neighbors(&self, location: &Location) -> Vec<Location> {
-        let up = location.up();
-        let right = location.right();
-        let down = location.down();
-        let left = location.left();
-
-        let current = self.index(location);
+        let current = self.grid[*location];
 
-        let valid = |l: &Location| {
-            if (l.0 < 0 || l.1 < 0) || (l.0 >= self.width as i64 || l.1 >= self.height as i64) {
-                false
-            } else {
-                let next = self.index(l);
-
-                // neighbor can be at most 1 elevation higher in order to travel
-                let current = self.grid[current];
-                let next = self.grid[next];
-
-                current + 1 >= next
-            }
-        };
-
-        let mut neighbors = vec![];
-
-        for l in [up, right, down, left] {
-            if valid(&l) {
-                neighbors.push(l);
-            }
-        }
-
-        neighbors
-    }
-
-    fn index(&self, location: &Location) -> usize {
-        assert!(location.0 > -1);
-        assert!(location.1 > -1);
-        location.0 as usize + location.1 as usize * self.width
+        // neighbor can be at most 1 elevation higher in order to travel
+        self.grid.neighbours_4(*location)
+            .filter(|next| current + 1 >= self.grid[*next])
+            .collect()
     }
 
+    /// Every location at the lowest height, `a`.
     pub fn all_start_locations(&self) -> Vec<Location> {
-        self.grid.iter().enumerate()
+        self.grid.iter()
             .filter(|(_, c)| **c as char == 'a')
-            .map(|(i, _)| self.location(i))
+            .map(|(location, _)| location)
             .collect()
     }
 }
@@ -156,35 +102,36 @@ impl Map {
 mod tests {
     use super::*;
 
+    fn example() -> Map {
+        include_str!("../fixtures/example.txt").parse().unwrap()
+    }
+
     #[test]
     fn from() {
-        let input = "
-        Sabqponm
-        abcryxxl
-        accszExk
-        acctuvwj
-        abdefghi
-        ";
-
-        let (map, start, end) = Map::from(input);
-
-        assert_eq!(8, map.width);
-        assert_eq!(5, map.height);
-        assert_eq!((0,0), start);
-        assert_eq!((5,2), end);
+        let map = example();
+
+        assert_eq!(8, map.width());
+        assert_eq!(5, map.height());
+        assert_eq!(Point::new(0, 0), map.start());
+        assert_eq!(Point::new(5, 2), map.end());
+        assert_eq!(include_str!("../fixtures/example.txt").trim_end(), map.to_string());
+    }
+
+    #[test]
+    fn invalid() {
+        let error = "Sab\nac!\nxyE".parse::<Map>().err().unwrap();
+        assert_eq!((2, 3), (error.line(), error.column()));
+
+        let error = "Sab\nacE\nxyE".parse::<Map>().err().unwrap();
+        assert_eq!("a single 'E' end location", error.expected());
+
+        assert!("Sab\nabc".parse::<Map>().is_err());
+        assert!("Sab\nacEx".parse::<Map>().is_err());
     }
 
     #[test]
     fn all_starts() {
-        let input = "
-        Sabqponm
-        abcryxxl
-        accszExk
-        acctuvwj
-        abdefghi
-        ";
-
-        let (map, _, _) = Map::from(input);
+        let map = example();
 
         let all_starts = map.all_start_locations();
 
@@ -1,203 +1,109 @@
-use std::collections::BinaryHeap;
-use std::collections::HashMap;
+use util::image::{Image, Palette};
+use util::{Direction, Grid};
+use util::search;
 
 use crate::map::Map;
 use crate::map::Location;
 
-struct PriorityLocation {
-    location: Location,
-    priority: u64,
+/// The locations stepped from on the way to `end`, so its length is the number of steps.
+pub fn find_easiest_route(map: &Map, start: &Location, end: &Location) -> Option<Vec<Location>> {
+    find_easiest_route_with(map, start, end, |_| {})
 }
 
-impl PriorityLocation {
-    fn new(location: Location, priority: u64) -> Self {
-        PriorityLocation { location, priority }
-    }
-}
+/// Like [`find_easiest_route`], calling `on_expand` with each location as the search steps from it.
+pub fn find_easiest_route_with(map: &Map, start: &Location, end: &Location, mut on_expand: impl FnMut(&Location)) -> Option<Vec<Location>> {
+    let search = search::bfs([*start], |l| {
+        on_expand(l);
+        map.neighbors(l)
+    }, |l| l == end);
 
-impl PartialEq for PriorityLocation {
-    fn eq(&self, other: &Self) -> bool {
-        self.priority == other.priority
-    }
+    let mut route = search.goal_path()?;
+    route.pop();
+    Some(route)
 }
 
-impl Eq for PriorityLocation {}
+/// Searches from every lowest location at once, so the route starts from whichever is closest.
+pub fn find_easiest_route_from_easiest_start(map: &Map, end: &Location) -> Option<Vec<Location>> {
+    let search = search::bfs(map.all_start_locations(), |l| map.neighbors(l), |l| l == end);
 
-impl PartialOrd for PriorityLocation {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
-impl Ord for PriorityLocation {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        other.priority.cmp(&self.priority)
-    }
+    let mut route = search.goal_path()?;
+    route.pop();
+    Some(route)
 }
 
-pub fn find_easiest_route(map: &Map, start: &Location, end: &Location) -> Option<Vec<Location>> {
-    let start = *start;
-    let end = *end;
-    let mut frontier = BinaryHeap::new();
-    frontier.push(PriorityLocation::new(start, 0));
-
-    let mut came_from: HashMap<Location, Location> = HashMap::new();
-    let mut movement_so_far: HashMap<Location, u64> = HashMap::new();
-    movement_so_far.insert(start, 0);
-
-    while !frontier.is_empty() {
-        let current = frontier.pop().unwrap();
-
-        if current.location == end {
-            break;
-        }
-
-        for next in map.neighbors(&current.location) {
-            let new_travel = movement_so_far[&current.location] + 1;
-            if !movement_so_far.contains_key(&next) || new_travel < movement_so_far[&next] {
-                movement_so_far.insert(next, new_travel);
-                frontier.push(PriorityLocation::new(next, new_travel));
-                came_from.insert(next, current.location);
-            }
-        }
+/// The map with each step of the route drawn as an arrow, like the puzzle's own drawing.
+pub fn print_route(map: &Map, route: &[Location]) -> String {
+    let mut printout = Grid::new(map.width(), map.height(), '.');
+
+    let mut steps = route.iter().peekable();
+    while let Some(c) = steps.next() {
+        printout[*c] = match steps.peek() {
+            None => 'E',
+            Some(n) => match Direction::try_from(**n - *c) {
+                Ok(direction) => direction.arrow(),
+                Err(step) => panic!("a step moves exactly 1 location - found {}", step),
+            },
+        };
     }
 
-    if !came_from.contains_key(&end) {
-        None
-    } else {
-        let mut path = vec![];
-        let mut current = end;
-        while current != start {
-            if let Some(prev) = came_from.get(&current) {
-                path.insert(0, *prev);
-                current = *prev;
-            } else {
-                panic!("Cannot trace trail back to ({}, {})", current.0, current.1);
-            }
-        }
-    
-        Some(path)
-    }
+    printout.to_string()
 }
 
-pub fn find_easiest_route_from_easiest_start(map: &Map, end: &Location) -> Vec<Location> {
-    let all_starts = map.all_start_locations();
-
-    let mut easiest_route: Option<Vec<Location>> = None;
-
-    for start in all_starts {
-        let route = find_easiest_route(map, &start, end);
-        if let Some(route) = route {
-            let replace = if let Some(easy) = easiest_route.as_ref() {
-                route.len() < easy.len()
-            } else {
-                true
-            };
-            if replace {
-                easiest_route.replace(route);
-            }
-        }
+/// The map shaded by elevation, from green valleys to white peaks, with the route in red and `S`
+/// and `E` in blue.
+pub fn draw_route(map: &Map, route: &[Location]) -> Image {
+    let mut image = Image::from_fn(map.width(), map.height(), |location| {
+        Palette::ELEVATION.scale(map.elevation(&location) as i64, b'a' as i64, b'z' as i64)
+    });
+    for step in route {
+        image.set(*step, [220, 30, 30]);
     }
-
-    easiest_route.unwrap()
-}
-
-pub fn print_route(map: &Map, route: &[Location]) -> String {
-    let mut printout = vec![];
-    for _ in 0..map.height() {
-        printout.extend(vec!['.'; map.width()]);
-        printout.push('\n');
-    }
-
-    let mut steps = route.iter();
-
-    let mut current = steps.next();
-    let mut next = steps.next();
-
-    let step = |c: &Location, n: Option<&Location>| {
-        if n.is_none() {
-            return 'E';
-        }
-        let n = n.unwrap();
-        let horizontal = n.0 - c.0;
-        let vertical = n.1 - c.1;
-        assert!(horizontal != 0 || vertical != 0);
-        assert!(!(horizontal != 0 && vertical != 0));
-        if horizontal != 0 {
-            match horizontal {
-                1 => '>',
-                -1 => '<',
-                _ => panic!("horizontal step can be at most 1 - found {}", horizontal),
-            }
-        } else if vertical != 0 {
-            match vertical {
-                1 => 'v',
-                -1 => '^',
-                _ => panic!("vertical step can be at most 1 - found {}", vertical),
-            }
-        } else {
-            unimplemented!()
-        }
-    };
-
-    while !(current.is_none() && next.is_none()) {
-        assert!(current.is_some());
-        let c = current.unwrap();
-        let n = next;
-        let s = step(c, n);
-
-        let index = c.0 as usize + c.1 as usize * (map.width() + 1);
-        printout[index] = s;
-
-        current = next;
-        next = steps.next();
-    }
-
-    String::from_iter(printout)
-
+    image.set(map.start(), [40, 90, 230]);
+    image.set(map.end(), [40, 90, 230]);
+    image
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn example() -> Map {
+        include_str!("../fixtures/example.txt").parse().unwrap()
+    }
+
     #[test]
     fn sample() {
-        let input = "
-        Sabqponm
-        abcryxxl
-        accszExk
-        acctuvwj
-        abdefghi
-        ";
-        
-        let (map, start, end) = Map::from(input);
-
-        let easy = find_easiest_route(&map, &start, &end).unwrap();
+        let map = example();
+
+        let easy = find_easiest_route(&map, &map.start(), &map.end()).unwrap();
 
         assert_eq!(31, easy.len());
 
         let printout = print_route(&map, &easy);
-        println!("{}", printout);
+        assert_eq!(">>vv<<<<\n..vvv<<^\n..vvE.^^\n..v>>>^^\n..>>>>>^", printout);
+
+        let image = draw_route(&map, &easy);
+        assert_eq!(30, image.pixels().iter().filter(|p| **p == [220, 30, 30]).count());
+        assert_eq!(Some(Palette::ELEVATION.at(0.0)), image.get(Location::new(0, 4)));
     }
 
     #[test]
     fn any_easy_start() {
-        let input = "
-        Sabqponm
-        abcryxxl
-        accszExk
-        acctuvwj
-        abdefghi
-        ";
-        
-        let (map, _, end) = Map::from(input);
-
-        let easy = find_easiest_route_from_easiest_start(&map, &end);
+        let map = example();
+
+        let easy = find_easiest_route_from_easiest_start(&map, &map.end()).unwrap();
 
         assert_eq!(29, easy.len());
 
         let printout = print_route(&map, &easy);
-        println!("{}", printout);
+        assert_eq!("...v<<<<\n...vv<<^\n...vE.^^\n.>v>>>^^\n>^>>>>>^", printout);
+    }
+
+    #[test]
+    fn unreachable() {
+        let map = "SzE".parse::<Map>().unwrap();
+
+        assert_eq!(None, find_easiest_route(&map, &map.start(), &map.end()));
+        assert_eq!(None, find_easiest_route_from_easiest_start(&map, &map.end()));
     }
 }
\ No newline at end of file
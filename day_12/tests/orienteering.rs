@@ -0,0 +1,21 @@
+use day_12::{Day12, Location, find_easiest_route, find_easiest_route_from_easiest_start, print_route};
+use util::Solution;
+
+#[test]
+fn example() {
+    let map = Day12::parse(include_str!("../fixtures/example.txt")).unwrap();
+
+    assert_eq!(Location::new(0, 0), map.start());
+    assert_eq!(Location::new(5, 2), map.end());
+    assert_eq!(6, map.all_start_locations().len());
+
+    let route = find_easiest_route(&map, &map.start(), &map.end()).unwrap();
+    assert_eq!(31, route.len());
+    assert_eq!(29, find_easiest_route_from_easiest_start(&map, &map.end()).unwrap().len());
+
+    let mut full_route = route.clone();
+    full_route.push(map.end());
+    let printout = print_route(&map, &full_route);
+    assert_eq!(31, printout.chars().filter(|c| "^>v<".contains(*c)).count());
+    assert_eq!(Some(2 * 9 + 5), printout.find('E'));
+}
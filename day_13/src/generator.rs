@@ -0,0 +1,69 @@
+use util::Rng;
+
+use crate::packet::{MAX_DEPTH, Packet};
+
+/// `scale` pairs of packets, nested up to `scale` lists deep (or as deep as packets can go).
+///
+/// Pairs that compare equal, and packets equal to a divider, are thrown away and generated again
+/// since the puzzle never has them.
+pub fn generate(rng: &mut Rng, scale: usize) -> String {
+    let dividers = ["[[2]]", "[[6]]"].map(|d| d.parse::<Packet>().unwrap());
+    let max_depth = scale.clamp(1, MAX_DEPTH);
+
+    let mut pairs = vec![];
+    while pairs.len() < scale.max(1) {
+        let left = packet(rng, max_depth);
+        let right = packet(rng, max_depth);
+
+        let parsed = [&left, &right].map(|p| p.parse::<Packet>().unwrap());
+        if parsed[0] == parsed[1] || parsed.iter().any(|p| dividers.contains(p)) {
+            continue;
+        }
+        pairs.push(format!("{}\n{}\n", left, right));
+    }
+    pairs.join("\n")
+}
+
+fn packet(rng: &mut Rng, max_depth: usize) -> String {
+    // a budget keeps deep packets from also being impossibly wide
+    let mut budget = 40;
+    let depth = rng.below(max_depth) + 1;
+    list(rng, depth, &mut budget)
+}
+
+fn list(rng: &mut Rng, depth: usize, budget: &mut usize) -> String {
+    let mut items = vec![];
+    let length = rng.below(5);
+    for i in 0..length {
+        if *budget == 0 {
+            break;
+        }
+        *budget -= 1;
+        // the first item keeps going down so the packet actually gets as deep as intended
+        if depth > 1 && (i == 0 || rng.chance(0.3)) {
+            items.push(list(rng, depth - 1, budget));
+        } else {
+            items.push(rng.below(11).to_string());
+        }
+    }
+    format!("[{}]", items.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use util::{Part, Puzzle, Report, Timer};
+
+    use super::*;
+    use crate::Day13;
+
+    #[test]
+    fn solvable() {
+        for seed in 0..5 {
+            let input = generate(&mut Rng::new(seed), 30);
+
+            assert_eq!(90 - 1, input.lines().count());
+            assert_eq!(input, generate(&mut Rng::new(seed), 30));
+            assert!(Puzzle::of::<Day13>().solve(&input, &Part::all(), &Timer::with_report(Report::Quiet)).is_ok());
+        }
+    }
+}
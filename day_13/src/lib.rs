@@ -0,0 +1,96 @@
+//! Day 13: Distress Signal - putting the packets of a distress signal in the right order.
+
+pub mod packet;
+pub mod signal;
+pub mod generator;
+
+use std::fmt::Display;
+
+pub use packet::{List, Packet, PacketData};
+pub use signal::validate_packets;
+use util::{NoAnswer, ParseError, Rng, Solution, parse_lines, write_lines};
+
+/// Sums the indexes of the pairs of [`Packet`]s in the right order, then sorts every packet along with
+/// the divider packets `[[2]]` and `[[6]]`.
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const MANIFEST_DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    type Input = Vec<Packet>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let packets = parse_lines::<Packet>(input)?;
+
+        if !packets.len().is_multiple_of(2) {
+            let last_line = input.lines().count();
+            return Err(ParseError::new("", "every packet to have a pair").at(last_line + 1, 1));
+        }
+
+        Ok(packets)
+    }
+
+    fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+        Some(generator::generate(rng, scale))
+    }
+
+    fn serialize(packets: &Vec<Packet>) -> Option<String> {
+        Some(packets.chunks(2).map(write_lines).collect::<Vec<_>>().join("\n"))
+    }
+
+    fn part_1(packets: &Vec<Packet>) -> Result<impl Display, NoAnswer> {
+        // input is pairs of lines with a whitespace line in between
+        Ok(packets.chunks(2)
+            .enumerate()
+            .filter(|(_, pair)| validate_packets(&pair[0], &pair[1]))
+            .map(|(i, _)| i + 1)
+            .sum::<usize>())
+    }
+
+    fn part_2(packets: &Vec<Packet>) -> Result<impl Display, NoAnswer> {
+        let first_divider = "[[2]]".parse::<Packet>().unwrap();
+        let second_divider = "[[6]]".parse::<Packet>().unwrap();
+
+        // where each divider would land once sorted, without sorting: a packet equal to a divider,
+        // like [2], could land either side of it, so only count the ones strictly before
+        let first_divider_index = 1 + packets.iter().filter(|p| **p < first_divider).count();
+        let second_divider_index = 2 + packets.iter().filter(|p| **p < second_divider).count();
+
+        Ok(first_divider_index * second_divider_index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::{TestCaseError, prop_assert_eq, proptest};
+    use util::{Part, round_trip};
+
+    use super::*;
+
+    #[test]
+    fn fixtures() {
+        util::fixtures::assert_fixtures(util::Puzzle::of::<Day13>());
+    }
+
+    #[test]
+    fn packets_equal_to_dividers() {
+        let packets = Day13::parse("[2]\n[3]\n").unwrap();
+
+        assert_eq!(Ok("4".to_string()), Day13::solve(&packets, Part::Two));
+    }
+
+    proptest! {
+        #[test]
+        fn parse_anything(input in "[\\[\\],0-9 \\n]*") {
+            round_trip::check::<Day13>(&input).map_err(TestCaseError::fail)?;
+        }
+
+        #[test]
+        fn round_trip(seed: u64, scale in 1..50usize) {
+            let input = Day13::generate(&mut Rng::new(seed), scale).unwrap();
+
+            prop_assert_eq!(Ok(true), round_trip::check::<Day13>(&input));
+        }
+    }
+}
@@ -1,5 +1,9 @@
+use std::fmt::{self, Write as _};
+use std::str::FromStr;
+
 use pest::{Parser, iterators::Pair};
 use pest_derive::Parser;
+use util::ParseError;
 
 use crate::signal::{validate_list};
 
@@ -9,23 +13,35 @@ pub struct PacketParser;
 
 pub type List = Vec<PacketData>;
 
-#[derive(Clone)]
+/// How deeply a packet's lists can nest. Parsing and comparing packets recurse into every list, so
+/// anything much deeper than this would overflow the stack; the puzzle's packets are only a few deep.
+pub const MAX_DEPTH: usize = 256;
+
+/// A packet like `[1,[2,[3]]]`, ordered by the puzzle's rules for comparing packets.
+#[derive(Clone, Debug)]
 pub struct Packet {
     data: List,
 }
 
-impl Packet {
-    pub fn from(line: &str) -> Self {
-        let parsed_packet = PacketParser::parse(Rule::packet, line)
-            .unwrap_or_else(|_| panic !("filed to parse packet '{}'", line))
+impl FromStr for Packet {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        check_depth(line.trim())?;
+        let parsed_packet = PacketParser::parse(Rule::line, line.trim())?
+            .next()
+            .unwrap()
+            .into_inner()
             .next()
             .unwrap();
 
-        let data = parse_packet_list(parsed_packet);
+        let data = parse_packet_list(parsed_packet)?;
 
-        Packet { data }
+        Ok(Packet { data })
     }
+}
 
+impl Packet {
     pub fn data(&self) -> &List {
         &self.data
     }
@@ -41,26 +57,68 @@ impl PartialEq for Packet {
 
 impl PartialOrd for Packet {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(match validate_list(self.data(), other.data()) {
-            Some(valid) => if valid { std::cmp::Ordering::Less } else { std::cmp::Ordering::Greater },
-            None => std::cmp::Ordering::Equal,
-        })
+        Some(self.cmp(other))
     }
 }
 
 impl Ord for Packet {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.partial_cmp(other).unwrap()
+        match validate_list(self.data(), other.data()) {
+            Some(valid) => if valid { std::cmp::Ordering::Less } else { std::cmp::Ordering::Greater },
+            None => std::cmp::Ordering::Equal,
+        }
     }
 }
 
-#[derive(Clone)]
+/// A value in a packet's list.
+#[derive(Clone, Debug)]
 pub enum PacketData {
     Integer(u8),
     List(List),
 }
 
-fn parse_packet_list(parsed_list: Pair<Rule>) -> List {
+impl fmt::Display for Packet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_list(f, &self.data)
+    }
+}
+
+impl fmt::Display for PacketData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PacketData::Integer(integer) => write!(f, "{}", integer),
+            PacketData::List(list) => write_list(f, list),
+        }
+    }
+}
+
+fn write_list(f: &mut fmt::Formatter<'_>, list: &[PacketData]) -> fmt::Result {
+    f.write_char('[')?;
+    for (i, data) in list.iter().enumerate() {
+        if i > 0 {
+            f.write_char(',')?;
+        }
+        write!(f, "{}", data)?;
+    }
+    f.write_char(']')
+}
+
+fn check_depth(line: &str) -> Result<(), ParseError> {
+    let mut depth = 0;
+    for (index, c) in line.chars().enumerate() {
+        match c {
+            '[' => depth += 1,
+            ']' => depth -= 1,
+            _ => {},
+        }
+        if depth > MAX_DEPTH as i64 {
+            return Err(ParseError::new(line, format!("lists nested at most {} deep", MAX_DEPTH)).at_column(index + 1));
+        }
+    }
+    Ok(())
+}
+
+fn parse_packet_list(parsed_list: Pair<Rule>) -> Result<List, ParseError> {
     assert_eq!(Rule::packet, parsed_list.as_rule());
 
     let mut list = vec![];
@@ -68,66 +126,37 @@ fn parse_packet_list(parsed_list: Pair<Rule>) -> List {
     for parsed_data in parsed_list.into_inner() {
         let data = match parsed_data.as_rule() {
             Rule::integer => {
-                PacketData::Integer(parse_packet_integer(parsed_data))
+                PacketData::Integer(parse_packet_integer(parsed_data)?)
             },
             Rule::packet => {
-                PacketData::List(parse_packet_list(parsed_data))
-            }
+                PacketData::List(parse_packet_list(parsed_data)?)
+            },
+            rule => unreachable!("packets only contain integers and packets, found {:?}", rule),
         };
         list.push(data);
     }
 
-    list
+    Ok(list)
 }
 
-fn parse_packet_integer(parsed_integer: Pair<Rule>) -> u8 {
+fn parse_packet_integer(parsed_integer: Pair<Rule>) -> Result<u8, ParseError> {
     assert_eq!(Rule::integer, parsed_integer.as_rule());
 
-    parsed_integer.as_str().parse::<u8>().unwrap()
+    let (line, column) = parsed_integer.as_span().start_pos().line_col();
+    parsed_integer.as_str().parse::<u8>()
+        .map_err(|_| ParseError::new(parsed_integer.as_str(), "an integer in 0-255").at(line, column))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    impl Packet {
-        pub fn to_string(&self) -> String {
-            let mut output = String::new();
-    
-            PacketData::write_list(&mut output, &self.data);
-    
-            output
-        }
-    }
-
-    impl PacketData {
-
-        fn write(&self, output: &mut String) {
-            match self {
-                PacketData::Integer(integer) => PacketData::write_integer(output, integer),
-                PacketData::List(list) => PacketData::write_list(output, list),
-            }
-        }
-    
-        fn write_integer(output: &mut String, integer: &u8) {
-            output.push_str(&format!("{}", integer))
-        }
-    
-        fn write_list(output: &mut String, list: &[PacketData]) {
-            output.push('[');
-            for (i, data) in list.iter().enumerate() {
-                if i > 0 {
-                    output.push(',');
-                }
-                data.write(output);
-            }
-            output.push(']');
-        }
+    fn packet(line: &str) -> Packet {
+        line.parse().unwrap()
     }
 
     fn validate_parsing(line: &str) {
-        let packet = Packet::from(line);
-        assert_eq!(line, &packet.to_string());
+        assert_eq!(line, &packet(line).to_string());
     }
 
     #[test]
@@ -158,27 +187,50 @@ mod tests {
 
     }
 
+    #[test]
+    fn deep() {
+        let nested = |depth: usize| "[".repeat(depth) + &"]".repeat(depth);
+        validate_parsing(&nested(MAX_DEPTH));
+
+        let error = nested(100_000).parse::<Packet>().unwrap_err();
+        assert_eq!(MAX_DEPTH + 1, error.column());
+    }
+
+    #[test]
+    fn parse_invalid() {
+        let error = "[1,[2,3]".parse::<Packet>().unwrap_err();
+        assert_eq!(1, error.line());
+        assert_eq!("[1,[2,3]", error.text());
+
+        let error = "[1,256]".parse::<Packet>().unwrap_err();
+        assert_eq!((1, 4), (error.line(), error.column()));
+        assert_eq!("256", error.text());
+
+        assert!("[1]]".parse::<Packet>().is_err());
+        assert!("".parse::<Packet>().is_err());
+    }
+
     #[test]
     fn sort() {
         let mut packets = vec![
-            Packet::from("[1,1,3,1,1]"),
-            Packet::from("[1,1,5,1,1]"),
-            Packet::from("[[1],[2,3,4]]"),
-            Packet::from("[[1],4]"),
-            Packet::from("[9]"),
-            Packet::from("[[8,7,6]]"),
-            Packet::from("[[4,4],4,4]"),
-            Packet::from("[[4,4],4,4,4]"),
-            Packet::from("[7,7,7,7]"),
-            Packet::from("[7,7,7]"),
-            Packet::from("[]"),
-            Packet::from("[3]"),
-            Packet::from("[[[]]]"),
-            Packet::from("[[]]"),
-            Packet::from("[1,[2,[3,[4,[5,6,7]]]],8,9]"),
-            Packet::from("[1,[2,[3,[4,[5,6,0]]]],8,9]"),
-            Packet::from("[[2]]"),
-            Packet::from("[[6]]"),
+            packet("[1,1,3,1,1]"),
+            packet("[1,1,5,1,1]"),
+            packet("[[1],[2,3,4]]"),
+            packet("[[1],4]"),
+            packet("[9]"),
+            packet("[[8,7,6]]"),
+            packet("[[4,4],4,4]"),
+            packet("[[4,4],4,4,4]"),
+            packet("[7,7,7,7]"),
+            packet("[7,7,7]"),
+            packet("[]"),
+            packet("[3]"),
+            packet("[[[]]]"),
+            packet("[[]]"),
+            packet("[1,[2,[3,[4,[5,6,7]]]],8,9]"),
+            packet("[1,[2,[3,[4,[5,6,0]]]],8,9]"),
+            packet("[[2]]"),
+            packet("[[6]]"),
         ];
 
         packets.sort();
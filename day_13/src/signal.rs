@@ -2,10 +2,14 @@ use std::cmp::Ordering;
 
 use crate::packet::{List, Packet, PacketData::{Integer as IntData, List as ListData}};
 
+/// Whether the pair of packets is in the right order.
+///
+/// Panics if the packets are equal, which the puzzle never has.
 pub fn validate_packets(a: &Packet, b: &Packet) -> bool {
     validate_list(a.data(), b.data()).unwrap()
 }
 
+/// `Some(true)` when in the right order, `Some(false)` when not, and `None` when it's undecided.
 pub fn validate_integer(a: &u8, b: &u8) -> Option<bool> {
     match a.cmp(b) {
         Ordering::Less => Some(true),
@@ -14,6 +18,7 @@ pub fn validate_integer(a: &u8, b: &u8) -> Option<bool> {
     }
 }
 
+/// Compares the lists item by item, with a list that runs out first being in the right order.
 pub fn validate_list(a: &List, b: &List) -> Option<bool> {
     let mut a_it = a.iter();
     let mut b_it = b.iter();
@@ -68,38 +73,42 @@ pub fn validate_list(a: &List, b: &List) -> Option<bool> {
 mod tests {
     use super::*;
 
+    fn packet(line: &str) -> Packet {
+        line.parse().unwrap()
+    }
+
     #[test]
     fn sample() {
-        let a = Packet::from("[1,1,3,1,1]");
-        let b = Packet::from("[1,1,5,1,1]");
-        assert_eq!(true, validate_packets(&a, &b));
+        let a = packet("[1,1,3,1,1]");
+        let b = packet("[1,1,5,1,1]");
+        assert!(validate_packets(&a, &b));
 
-        let a = Packet::from("[[1],[2,3,4]]");
-        let b = Packet::from("[[1],4]");
-        assert_eq!(true, validate_packets(&a, &b));
+        let a = packet("[[1],[2,3,4]]");
+        let b = packet("[[1],4]");
+        assert!(validate_packets(&a, &b));
 
-        let a = Packet::from("[9]");
-        let b = Packet::from("[[8,7,6]]");
-        assert_eq!(false, validate_packets(&a, &b));
+        let a = packet("[9]");
+        let b = packet("[[8,7,6]]");
+        assert!(!validate_packets(&a, &b));
 
-        let a = Packet::from("[[4,4],4,4]");
-        let b = Packet::from("[[4,4],4,4,4]");
-        assert_eq!(true, validate_packets(&a, &b));
+        let a = packet("[[4,4],4,4]");
+        let b = packet("[[4,4],4,4,4]");
+        assert!(validate_packets(&a, &b));
 
-        let a = Packet::from("[7,7,7,7]");
-        let b = Packet::from("[7,7,7]");
-        assert_eq!(false, validate_packets(&a, &b));
+        let a = packet("[7,7,7,7]");
+        let b = packet("[7,7,7]");
+        assert!(!validate_packets(&a, &b));
 
-        let a = Packet::from("[]");
-        let b = Packet::from("[3]");
-        assert_eq!(true, validate_packets(&a, &b));
+        let a = packet("[]");
+        let b = packet("[3]");
+        assert!(validate_packets(&a, &b));
 
-        let a = Packet::from("[[[]]]");
-        let b = Packet::from("[[]]");
-        assert_eq!(false, validate_packets(&a, &b));
+        let a = packet("[[[]]]");
+        let b = packet("[[]]");
+        assert!(!validate_packets(&a, &b));
 
-        let a = Packet::from("[1,[2,[3,[4,[5,6,7]]]],8,9]");
-        let b = Packet::from("[1,[2,[3,[4,[5,6,0]]]],8,9]");
-        assert_eq!(false, validate_packets(&a, &b));
+        let a = packet("[1,[2,[3,[4,[5,6,7]]]],8,9]");
+        let b = packet("[1,[2,[3,[4,[5,6,0]]]],8,9]");
+        assert!(!validate_packets(&a, &b));
     }
 }
\ No newline at end of file
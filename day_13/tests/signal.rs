@@ -0,0 +1,22 @@
+use day_13::{Day13, Packet, PacketData, validate_packets};
+use util::Solution;
+
+#[test]
+fn example() {
+    let packets = Day13::parse(include_str!("../fixtures/example.txt")).unwrap();
+
+    let ordered = packets.chunks(2)
+        .map(|pair| validate_packets(&pair[0], &pair[1]))
+        .collect::<Vec<_>>();
+    assert_eq!(vec![true, true, false, true, false, true, false, false], ordered);
+}
+
+#[test]
+fn packets() {
+    let packet = "[1,[2]]".parse::<Packet>().unwrap();
+
+    assert!(matches!(packet.data()[0], PacketData::Integer(1)));
+    assert!(matches!(&packet.data()[1], PacketData::List(list) if list.len() == 1));
+    assert_eq!("[[1],2]".parse::<Packet>().unwrap(), "[1,2]".parse().unwrap());
+    assert!("[1,2".parse::<Packet>().is_err());
+}
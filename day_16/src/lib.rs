@@ -0,0 +1,78 @@
+//! Day 16: Proboscidea Volcanium - releasing as much pressure as possible by opening valves.
+
+pub mod network;
+pub mod traversal;
+pub mod valve;
+
+use std::fmt::Display;
+
+pub use network::{FloydWarshall, Graph, Network};
+pub use traversal::{Action, most_pressure, most_pressure_with_elephant, traverse_network};
+use util::{NoAnswer, ParseError, Solution};
+pub use valve::Valve;
+
+/// Finds the most pressure that can be released from the [`Network`] alone, then with an elephant's help.
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    const MANIFEST_DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    type Input = Network;
+
+    /// Fails unless there's a valve `AA` to start from, and at most 64 valves with any flow.
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let network = input.parse::<Network>()?;
+
+        let last_line = input.lines().count();
+        if network.find("AA").is_none() {
+            return Err(ParseError::new("", "a valve AA to start from").at(last_line + 1, 1));
+        }
+        let flowing = network.valves().iter().filter(|v| v.flow_rate() > 0).count();
+        if flowing > 64 {
+            return Err(ParseError::new("", format!("at most 64 valves with any flow, not {}", flowing)).at(last_line + 1, 1));
+        }
+
+        Ok(network)
+    }
+
+    fn serialize(network: &Network) -> Option<String> {
+        Some(network.to_string())
+    }
+
+    fn part_1(network: &Network) -> Result<impl Display, NoAnswer> {
+        Ok(most_pressure(network))
+    }
+
+    fn part_2(network: &Network) -> Result<impl Display, NoAnswer> {
+        Ok(most_pressure_with_elephant(network))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::{TestCaseError, proptest};
+    use util::round_trip;
+
+    use super::*;
+
+    #[test]
+    fn fixtures() {
+        util::fixtures::assert_fixtures(util::Puzzle::of::<Day16>());
+    }
+
+    #[test]
+    fn invalid() {
+        let error = Day16::parse("Valve BB has flow rate=1; tunnel leads to valve BB\n").unwrap_err();
+
+        assert_eq!((2, 1), (error.line(), error.column()));
+        assert_eq!("a valve AA to start from", error.expected());
+    }
+
+    proptest! {
+        #[test]
+        fn parse_anything(input in "(Valve (AA|BB) has flow rate=[0-9]; tunnels? leads? to valves? (AA|BB)\n)*") {
+            round_trip::check::<Day16>(&input).map_err(TestCaseError::fail)?;
+        }
+    }
+}
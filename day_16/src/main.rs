@@ -1,21 +1,8 @@
-mod valve;
-mod network;
-mod traversal;
+use std::process::ExitCode;
 
-use std::fs;
+use day_16::Day16;
+use util::Puzzle;
 
-use util::Timer;
-
-fn main() -> std::io::Result<()> {
-    let _timer = Timer::new();
-
-    let input = fs::read_to_string("./day_13/input.txt")?;
-
-    run_part_1(input);
-
-    Ok(())
+fn main() -> ExitCode {
+    util::cli::main(Puzzle::of::<Day16>())
 }
-
-fn run_part_1(input: String) {
-
-}
\ No newline at end of file
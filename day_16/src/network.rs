@@ -1,31 +1,64 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::str::FromStr;
+
+use util::{ParseError, parse_lines, write_lines};
+use util::search;
 
 use crate::valve::Valve;
 
+/// The valves and the tunnels between them.
+#[derive(Debug)]
 pub struct Network {
     valves: Vec<Valve>,
 }
 
+impl FromStr for Network {
+    type Err = ParseError;
+
+    /// Fails unless every valve is named once and every tunnel leads to one of them.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let valves = parse_lines::<Valve>(input)?;
+
+        let mut names = HashSet::new();
+        let lines = input.lines().enumerate().filter(|(_, line)| !line.trim().is_empty());
+        for (valve, (index, line)) in valves.iter().zip(lines.clone()) {
+            if !names.insert(valve.name()) {
+                return Err(ParseError::new(line, format!("valve {} to only be scanned once", valve.name())).at(index + 1, 1));
+            }
+        }
+        for (valve, (index, line)) in valves.iter().zip(lines) {
+            if let Some(tunnel) = valve.tunnels().iter().find(|t| !names.contains(t.as_str())) {
+                return Err(ParseError::new(line, format!("a tunnel to a scanned valve, not {}", tunnel)).at(index + 1, 1));
+            }
+        }
+
+        Ok(Network::new(valves))
+    }
+}
+
+/// One valve per line, like the scan.
+impl fmt::Display for Network {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&write_lines(&self.valves))
+    }
+}
+
 impl Network {
     pub fn new(valves: Vec<Valve>) -> Self {
         Self { valves }
     }
 
-    pub fn from(input: &str) -> Self {
-        let valves = input.split('\n')
-            .filter(|l| !l.trim().is_empty())
-            .map(|l| Valve::from(l.trim()))
-            .collect();
-
-        Network::new(valves)
+    pub fn valves(&self) -> &[Valve] {
+        &self.valves
     }
 
+    /// Whether a tunnel leads straight from `origin` to `destination`.
     pub fn validate_move(&self, origin: &str, destination: &str) -> bool {
         let valve = self.valves.iter().find(|v| v.name() == origin);
 
-        if valve.is_some() {
-            let tunnel = valve.unwrap();
-            let tunnel = tunnel.tunnels().iter().find(|t| destination == *t);
+        if let Some(valve) = valve {
+            let tunnel = valve.tunnels().iter().find(|t| destination == *t);
             tunnel.is_some()
         } else {
             false
@@ -37,14 +70,57 @@ impl Network {
     }
 }
 
+/// Minutes to walk between each pair of valves, by name.
 pub type Graph<'a> = HashMap<(&'a str, &'a str), u32>;
 
 pub trait FloydWarshall {
-    fn run_floyd_warshal(&self) -> Graph;
+    fn run_floyd_warshal(&self) -> Graph<'_>;
 }
 
 impl FloydWarshall for Network {
-    fn run_floyd_warshal(&self) -> Graph {
-        todo!()
+    fn run_floyd_warshal(&self) -> Graph<'_> {
+        let names = self.valves.iter().map(|v| v.name());
+        let paths = search::floyd_warshall(names, |name| {
+            self.find(name).unwrap().tunnels().iter().map(|t| (t.as_str(), 1))
+        });
+
+        paths.iter()
+            .map(|(from, to, cost)| ((*from, *to), cost as u32))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shortest_paths() {
+        let network = include_str!("../fixtures/example.txt").parse::<Network>().unwrap();
+
+        let graph = network.run_floyd_warshal();
+
+        assert_eq!(100, graph.len());
+        assert_eq!(0, graph[&("AA", "AA")]);
+        assert_eq!(1, graph[&("AA", "DD")]);
+        assert_eq!(2, graph[&("AA", "JJ")]);
+        assert_eq!(5, graph[&("AA", "HH")]);
+        assert_eq!(7, graph[&("HH", "JJ")]);
+    }
+
+    #[test]
+    fn round_trip() {
+        let scan = include_str!("../fixtures/example.txt");
+
+        assert_eq!(scan, scan.parse::<Network>().unwrap().to_string());
+    }
+
+    #[test]
+    fn invalid() {
+        let error = "Valve AA has flow rate=0; tunnel leads to valve BB\n".parse::<Network>().unwrap_err();
+        assert_eq!("a tunnel to a scanned valve, not BB", error.expected());
+
+        let error = "Valve AA has flow rate=0; tunnel leads to valve AA\n\nValve AA has flow rate=1; tunnel leads to valve AA".parse::<Network>().unwrap_err();
+        assert_eq!((3, 1), (error.line(), error.column()));
     }
 }
\ No newline at end of file
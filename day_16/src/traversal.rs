@@ -1,12 +1,21 @@
-use crate::network::Network;
+use std::cmp::Reverse;
+use std::collections::HashMap;
 
+use crate::network::{FloydWarshall, Graph, Network};
+
+/// One minute's worth of work: walking to a neighbouring valve or opening the current one.
 pub enum Action<'a> {
     Move(&'a str),
     Open,
 }
 
 const TOTAL_TIME: u32 = 30;
+/// Minutes spent teaching the elephant before it can help
+const ELEPHANT_TRAINING: u32 = 4;
 
+/// Total pressure released over 30 minutes by taking the actions, starting from `AA`.
+///
+/// Panics if an action moves between valves that aren't connected.
 pub fn traverse_network(network: &Network, actions: &[Action]) -> u32 {
     let mut time_remaining = TOTAL_TIME;
     let mut total_pressure_released = 0;
@@ -34,21 +43,83 @@ pub fn traverse_network(network: &Network, actions: &[Action]) -> u32 {
     total_pressure_released
 }
 
-pub fn navigate_network(network: &Network) -> Vec<Action> {
-    let mut actions = vec![];
-
-    let mut time_remaining = TOTAL_TIME;
-    let mut current = network.find("AA").unwrap();
-    let mut visited_tunnels = vec![current.name()];
-    let mut opened_valves = vec![];
+/// The most pressure that can be released in 30 minutes, starting from `AA`.
+pub fn most_pressure(network: &Network) -> u32 {
+    most_pressure_by_opened(network, TOTAL_TIME).into_values().max().unwrap_or(0)
+}
 
-    loop {
-        
+/// The most pressure you and an elephant can release, both starting from `AA` once you've spent 4
+/// of the 30 minutes teaching it.
+///
+/// A valve only releases pressure once, so this is the best pair of routes opening different valves.
+pub fn most_pressure_with_elephant(network: &Network) -> u32 {
+    let mut routes = most_pressure_by_opened(network, TOTAL_TIME - ELEPHANT_TRAINING).into_iter().collect::<Vec<_>>();
+    routes.sort_unstable_by_key(|(_, released)| Reverse(*released));
+
+    let mut most = 0;
+    for (i, (mine, me)) in routes.iter().enumerate() {
+        for (theirs, elephant) in &routes[i..] {
+            // the rest release less, so can't beat what's been found
+            if me + elephant <= most {
+                break;
+            }
+            if mine & theirs == 0 {
+                most = me + elephant;
+            }
+        }
     }
+    most
+}
 
+/// The most pressure released in `minutes` for each set of valves that can be opened in that time.
+///
+/// Only valves with some flow are worth walking to, so this tries each order of opening them,
+/// walking the shortest way between one and the next. The sets are bitmasks over those valves, so
+/// there can be at most 64 of them.
+fn most_pressure_by_opened(network: &Network, minutes: u32) -> HashMap<u64, u32> {
+    let graph = network.run_floyd_warshal();
+    let flowing = network.valves().iter()
+        .filter(|v| v.flow_rate() > 0)
+        .map(|v| (v.name(), v.flow_rate() as u32))
+        .collect::<Vec<_>>();
+
+    let mut most = HashMap::new();
+    open_next(&graph, &flowing, Route { at: "AA", time_remaining: minutes, opened: 0, released: 0 }, &mut most);
+    most
+}
+
+#[derive(Clone, Copy)]
+struct Route<'a> {
+    at: &'a str,
+    time_remaining: u32,
+    opened: u64,
+    released: u32,
+}
 
+fn open_next<'a>(graph: &Graph<'a>, flowing: &[(&'a str, u32)], route: Route<'a>, most: &mut HashMap<u64, u32>) {
+    let released = most.entry(route.opened).or_insert(0);
+    *released = route.released.max(*released);
 
-    actions
+    for (i, (valve, flow_rate)) in flowing.iter().enumerate() {
+        if route.opened & (1 << i) != 0 {
+            continue;
+        }
+        let Some(distance) = graph.get(&(route.at, *valve)) else {
+            continue;
+        };
+        // a minute to open it once there
+        let Some(time_remaining) = route.time_remaining.checked_sub(distance + 1) else {
+            continue;
+        };
+
+        let next = Route {
+            at: valve,
+            time_remaining,
+            opened: route.opened | 1 << i,
+            released: route.released + time_remaining * flow_rate,
+        };
+        open_next(graph, flowing, next, most);
+    }
 }
 
 #[cfg(test)]
@@ -57,20 +128,7 @@ mod tests {
 
     #[test]
     fn traverse() {
-        let input = r"
-Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
-Valve BB has flow rate=13; tunnels lead to valves CC, AA
-Valve CC has flow rate=2; tunnels lead to valves DD, BB
-Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
-Valve EE has flow rate=3; tunnels lead to valves FF, DD
-Valve FF has flow rate=0; tunnels lead to valves EE, GG
-Valve GG has flow rate=0; tunnels lead to valves FF, HH
-Valve HH has flow rate=22; tunnel leads to valve GG
-Valve II has flow rate=0; tunnels lead to valves AA, JJ
-Valve JJ has flow rate=21; tunnel leads to valve II
-        ";
-
-        let network = Network::from(input);
+        let network = include_str!("../fixtures/example.txt").parse::<Network>().unwrap();
 
         let actions = vec![
             Action::Move("DD"),
@@ -103,4 +161,12 @@ Valve JJ has flow rate=21; tunnel leads to valve II
 
         assert_eq!(1651, result);
     }
+
+    #[test]
+    fn most() {
+        let network = include_str!("../fixtures/example.txt").parse::<Network>().unwrap();
+
+        assert_eq!(1651, most_pressure(&network));
+        assert_eq!(1707, most_pressure_with_elephant(&network));
+    }
 }
\ No newline at end of file
@@ -1,6 +1,12 @@
+use std::fmt;
+use std::str::FromStr;
+
 use lazy_static::lazy_static;
 use regex::Regex;
+use util::{ParseError, parse_number};
 
+/// A valve, how much pressure it releases each minute once opened, and the valves its tunnels lead to.
+#[derive(Debug)]
 pub struct Valve {
     name: String,
     flow_rate: u8,
@@ -11,21 +17,38 @@ lazy_static! {
     static ref VALVE: Regex = Regex::new(r"^Valve (?P<name>[A-Z]{2}) has flow rate=(?P<flow_rate>\d+); tunnels? leads? to valves? (?P<tunnels>[A-Z]{2}(?:, [A-Z]{2})*)$").unwrap();
 }
 
-impl Valve {
-    pub fn new(name: String, flow_rate: u8, tunnels: Vec<String>) -> Self {
-        Self { name, flow_rate, tunnels }
-    }
+impl FromStr for Valve {
+    type Err = ParseError;
 
-    pub fn from(input: &str) -> Self {
-        let capture = VALVE.captures(input).expect(&format!("invalid input '{}'", input));
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let capture = VALVE.captures(input.trim())
+            .ok_or_else(|| ParseError::new(input, "a valve like 'Valve AA has flow rate=0; tunnels lead to valves DD, II'"))?;
 
         let name = capture["name"].to_string();
-        let flow_rate = capture["flow_rate"].parse::<u8>().unwrap();
+        let flow_rate = capture.name("flow_rate").unwrap();
+        let flow_rate = parse_number(flow_rate.as_str(), flow_rate.start() + 1, "a flow rate in 0-255")?;
         let tunnels = capture["tunnels"].split(", ")
             .map(|s| s.to_string())
             .collect();
 
-        Valve::new(name, flow_rate, tunnels)
+        Ok(Valve::new(name, flow_rate, tunnels))
+    }
+}
+
+/// The valve's line of the scan, e.g. `Valve HH has flow rate=22; tunnel leads to valve GG`.
+impl fmt::Display for Valve {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let tunnels = match self.tunnels.len() {
+            1 => "tunnel leads to valve",
+            _ => "tunnels lead to valves",
+        };
+        write!(f, "Valve {} has flow rate={}; {} {}", self.name, self.flow_rate, tunnels, self.tunnels.join(", "))
+    }
+}
+
+impl Valve {
+    pub fn new(name: String, flow_rate: u8, tunnels: Vec<String>) -> Self {
+        Self { name, flow_rate, tunnels }
     }
 
     pub fn name(&self) -> &str {
@@ -40,10 +63,9 @@ impl Valve {
         &self.tunnels
     }
 
+    /// Pressure released over the remaining time by opening the valve now.
     pub fn open(&mut self, time_remaining: u8) -> u32 {
-        let total_pressure = (self.flow_rate as u32) * (time_remaining as u32);
-
-        total_pressure
+        (self.flow_rate as u32) * (time_remaining as u32)
     }
 
     pub fn validate_move(&self, target: &str) -> bool {
@@ -54,6 +76,9 @@ impl Valve {
 
 #[cfg(test)]
 mod tests {
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
     use super::*;
 
     #[test]
@@ -65,10 +90,26 @@ mod tests {
 
     #[test]
     fn from() {
-        let valve = Valve::from("Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE");
+        let valve = "Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE".parse::<Valve>().unwrap();
 
         assert_eq!("DD", valve.name);
         assert_eq!(20, valve.flow_rate);
         assert_eq!(vec!["CC", "AA", "EE"], valve.tunnels);
     }
+
+    #[test]
+    fn from_invalid() {
+        assert!("Valve DD has flow rate=20".parse::<Valve>().is_err());
+        assert_eq!(24, "Valve DD has flow rate=999; tunnel leads to valve CC".parse::<Valve>().unwrap_err().column());
+    }
+
+    proptest! {
+        #[test]
+        fn round_trip(name in "[A-Z]{2}", flow_rate: u8, tunnels in vec("[A-Z]{2}", 1..6)) {
+            let valve = Valve::new(name, flow_rate, tunnels);
+            let line = valve.to_string();
+
+            prop_assert_eq!(&line, &line.parse::<Valve>().unwrap().to_string());
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,14 @@
+use day_16::{Action, FloydWarshall, Network, traverse_network};
+
+#[test]
+fn example() {
+    let network = include_str!("../fixtures/example.txt").parse::<Network>().unwrap();
+
+    assert!(network.validate_move("AA", "DD"));
+    assert!(!network.validate_move("AA", "CC"));
+    assert_eq!(20, network.find("DD").unwrap().flow_rate());
+    assert_eq!(3, network.run_floyd_warshal()[&("JJ", "DD")]);
+
+    let released = traverse_network(&network, &[Action::Move("DD"), Action::Open]);
+    assert_eq!(28 * 20, released);
+}
@@ -0,0 +1,172 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use toml::{Table, Value};
+
+use crate::error::ParseError;
+use crate::solution::Part;
+
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// Known-correct answers, read from a TOML file with one `[day_XX]` table per day
+/// holding `part_1` and `part_2` values.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AnswerKey {
+    answers: HashMap<(u8, Part), String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+impl AnswerKey {
+    /// The workspace's `answers.toml`, found next to the day crates.
+    pub fn default_path(manifest_dir: &str) -> PathBuf {
+        Path::new(manifest_dir)
+            .parent()
+            .unwrap_or(Path::new("."))
+            .join(ANSWERS_FILE)
+    }
+
+    pub fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        let text = fs::read_to_string(path)
+            .map_err(|e| format!("failed to read '{}': {}", path.display(), e))?;
+        text.parse::<AnswerKey>()
+            .map_err(|e| format!("invalid '{}': {}", path.display(), e).into())
+    }
+
+    pub fn expected(&self, day: u8, part: Part) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+
+    pub fn check(&self, day: u8, part: Part, answer: &str) -> Verdict {
+        match self.expected(day, part) {
+            None => Verdict::Unknown,
+            Some(expected) if expected.trim_end() == answer.trim_end() => Verdict::Pass,
+            Some(expected) => Verdict::Fail { expected: expected.to_string() },
+        }
+    }
+}
+
+impl FromStr for AnswerKey {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut answers = HashMap::new();
+        for (day_key, parts) in &parse_table(s)? {
+            let day = day_key.strip_prefix("day_")
+                .and_then(|day| day.parse::<u8>().ok())
+                .ok_or_else(|| ParseError::new(day_key, "a [day_XX] table"))?;
+            let Value::Table(parts) = parts else {
+                return Err(ParseError::new(day_key, "a [day_XX] table"));
+            };
+            for (part, answer) in parse_parts(parts, &format!("{}.", day_key))? {
+                answers.insert((day, part), answer);
+            }
+        }
+
+        Ok(AnswerKey { answers })
+    }
+}
+
+pub(crate) fn parse_table(s: &str) -> Result<Table, ParseError> {
+    s.parse::<Table>().map_err(|e| {
+        let offset = e.span().map(|span| span.start).unwrap_or(0);
+        let (line, column) = line_col(s, offset);
+        let text = s.lines().nth(line - 1).unwrap_or("");
+        ParseError::new(text, e.message()).at(line, column)
+    })
+}
+
+/// Reads `part_1` and `part_2` keys, reporting unexpected ones with `prefix` in front of their name.
+pub(crate) fn parse_parts(parts: &Table, prefix: &str) -> Result<Vec<(Part, String)>, ParseError> {
+    parts.iter()
+        .map(|(key, value)| {
+            let part = match key.as_str() {
+                "part_1" => Part::One,
+                "part_2" => Part::Two,
+                _ => return Err(ParseError::new(&format!("{}{}", prefix, key), "part_1 or part_2")),
+            };
+            let answer = match value {
+                Value::String(answer) => answer.clone(),
+                Value::Integer(answer) => answer.to_string(),
+                _ => return Err(ParseError::new(&format!("{}{}", prefix, key), "a string or integer answer")),
+            };
+            Ok((part, answer))
+        })
+        .collect()
+}
+
+fn line_col(text: &str, offset: usize) -> (usize, usize) {
+    let before = &text[..offset.min(text.len())];
+    let line = before.matches('\n').count() + 1;
+    let column = before.len() - before.rfind('\n').map(|i| i + 1).unwrap_or(0) + 1;
+    (line, column)
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Fail { expected } if expected.contains('\n') => write!(f, "FAIL, expected\n{}", expected),
+            Verdict::Fail { expected } => write!(f, "FAIL, expected {}", expected),
+            Verdict::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check() {
+        let key = "
+[day_01]
+part_1 = 67622
+part_2 = \"201491\"
+
+[day_10]
+part_2 = '''
+#..#
+.##.
+'''
+".parse::<AnswerKey>().unwrap();
+
+        assert_eq!(Verdict::Pass, key.check(1, Part::One, "67622"));
+        assert_eq!(Verdict::Pass, key.check(1, Part::Two, "201491"));
+        assert_eq!(Verdict::Fail { expected: "67622".to_string() }, key.check(1, Part::One, "67621"));
+        assert_eq!(Verdict::Pass, key.check(10, Part::Two, "#..#\n.##."));
+        assert_eq!(Verdict::Unknown, key.check(10, Part::One, "15880"));
+        assert_eq!(Verdict::Unknown, key.check(2, Part::One, "1"));
+    }
+
+    #[test]
+    fn invalid() {
+        let error = "[day_01]\npart_3 = 1\n".parse::<AnswerKey>().unwrap_err();
+        assert_eq!("part_1 or part_2", error.expected());
+        assert_eq!("day_01.part_3", error.text());
+
+        let error = "[first]\npart_1 = 1\n".parse::<AnswerKey>().unwrap_err();
+        assert_eq!("a [day_XX] table", error.expected());
+
+        let error = "[day_01]\npart_1 = \n".parse::<AnswerKey>().unwrap_err();
+        assert_eq!(2, error.line());
+        assert_eq!("part_1 = ", error.text());
+    }
+
+    #[test]
+    fn workspace_file() {
+        let path = AnswerKey::default_path(env!("CARGO_MANIFEST_DIR"));
+        let key = AnswerKey::load(&path).unwrap();
+
+        assert_eq!(Some("67622"), key.expected(1, Part::One));
+    }
+}
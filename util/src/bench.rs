@@ -0,0 +1,255 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{self, Write as _};
+use std::fs;
+use std::hint::black_box;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BenchOptions {
+    /// Untimed runs before sampling starts
+    pub warmup: usize,
+    pub iterations: usize,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        BenchOptions { warmup: 2, iterations: 10 }
+    }
+}
+
+impl BenchOptions {
+    pub fn run<T>(&self, mut f: impl FnMut() -> T) -> Stats {
+        for _ in 0..self.warmup {
+            black_box(f());
+        }
+
+        let samples = (0..self.iterations.max(1))
+            .map(|_| {
+                let start = Instant::now();
+                black_box(f());
+                start.elapsed()
+            })
+            .collect::<Vec<_>>();
+
+        Stats::from_samples(samples)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    pub samples: usize,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "cannot summarise zero samples");
+        samples.sort();
+
+        Stats {
+            min: samples[0],
+            median: samples[(samples.len() - 1) / 2],
+            p95: samples[percentile_index(samples.len(), 95)],
+            samples: samples.len(),
+        }
+    }
+}
+
+/// Nearest-rank percentile, as a zero-based index into sorted samples.
+fn percentile_index(len: usize, percentile: usize) -> usize {
+    (len * percentile).div_ceil(100).clamp(1, len) - 1
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Measurement {
+    /// e.g. `day 08/part 1`
+    pub name: String,
+    pub stats: Stats,
+}
+
+/// Median timings from an earlier run, saved as `name,median_ns` lines.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Baseline {
+    medians: HashMap<String, Duration>,
+}
+
+const BASELINE_HEADER: &str = "name,median_ns";
+
+impl Baseline {
+    pub fn from_measurements(measurements: &[Measurement]) -> Self {
+        Baseline {
+            medians: measurements.iter()
+                .map(|m| (m.name.clone(), m.stats.median))
+                .collect(),
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Self, BaselineError> {
+        let mut medians = HashMap::new();
+        for (index, line) in text.lines().enumerate() {
+            if line.trim().is_empty() || line == BASELINE_HEADER {
+                continue;
+            }
+            let nanos = line.rsplit_once(',')
+                .and_then(|(name, nanos)| Some((name, nanos.trim().parse::<u64>().ok()?)));
+            match nanos {
+                Some((name, nanos)) => { medians.insert(name.to_string(), Duration::from_nanos(nanos)); },
+                None => return Err(BaselineError { line: index + 1, text: line.to_string() }),
+            }
+        }
+
+        Ok(Baseline { medians })
+    }
+
+    pub fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        Ok(Baseline::parse(&fs::read_to_string(path)?)?)
+    }
+
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    pub fn median(&self, name: &str) -> Option<Duration> {
+        self.medians.get(name).copied()
+    }
+
+    /// Relative change of the measured median against the baseline, e.g. `0.25` for 25% slower.
+    pub fn change(&self, measurement: &Measurement) -> Option<f64> {
+        let baseline = self.median(&measurement.name)?.as_secs_f64();
+        if baseline == 0.0 {
+            return None;
+        }
+        Some(measurement.stats.median.as_secs_f64() / baseline - 1.0)
+    }
+
+    pub fn regressions<'a>(&self, measurements: &'a [Measurement], threshold: f64) -> Vec<&'a Measurement> {
+        measurements.iter()
+            .filter(|m| self.change(m).is_some_and(|change| change > threshold))
+            .collect()
+    }
+}
+
+impl fmt::Display for Baseline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut names = self.medians.keys().collect::<Vec<_>>();
+        names.sort();
+
+        writeln!(f, "{}", BASELINE_HEADER)?;
+        for name in names {
+            writeln!(f, "{},{}", name, self.medians[name].as_nanos())?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct BaselineError {
+    pub line: usize,
+    pub text: String,
+}
+
+impl fmt::Display for BaselineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: expected name,median_ns but found '{}'", self.line, self.text)
+    }
+}
+
+impl Error for BaselineError {}
+
+pub fn report(measurements: &[Measurement], baseline: Option<&Baseline>) -> String {
+    let width = measurements.iter()
+        .map(|m| m.name.len())
+        .max()
+        .unwrap_or(0)
+        .max("name".len());
+
+    let mut table = String::new();
+    write!(table, "{:<width$}  {:>12}  {:>12}  {:>12}", "name", "min ms", "median ms", "p95 ms").unwrap();
+    if baseline.is_some() {
+        write!(table, "  {:>9}", "change").unwrap();
+    }
+    writeln!(table).unwrap();
+
+    for m in measurements {
+        write!(table, "{:<width$}  {:>12.3}  {:>12.3}  {:>12.3}",
+            m.name, millis(m.stats.min), millis(m.stats.median), millis(m.stats.p95)).unwrap();
+        if let Some(baseline) = baseline {
+            match baseline.change(m) {
+                Some(change) => write!(table, "  {:>+8.1}%", change * 100.0).unwrap(),
+                None => write!(table, "  {:>9}", "new").unwrap(),
+            }
+        }
+        writeln!(table).unwrap();
+    }
+
+    table
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn stats() {
+        let stats = Stats::from_samples((1..=20).rev().map(ms).collect());
+        assert_eq!(ms(1), stats.min);
+        assert_eq!(ms(10), stats.median);
+        assert_eq!(ms(19), stats.p95);
+        assert_eq!(20, stats.samples);
+
+        let single = Stats::from_samples(vec![ms(3)]);
+        assert_eq!((ms(3), ms(3), ms(3)), (single.min, single.median, single.p95));
+    }
+
+    #[test]
+    fn options() {
+        let mut calls = 0;
+        let stats = BenchOptions { warmup: 3, iterations: 5 }.run(|| calls += 1);
+
+        assert_eq!(8, calls);
+        assert_eq!(5, stats.samples);
+    }
+
+    #[test]
+    fn baseline() {
+        let measure = |name: &str, median| Measurement {
+            name: name.to_string(),
+            stats: Stats { min: ms(median), median: ms(median), p95: ms(median), samples: 1 },
+        };
+        let baseline = Baseline::from_measurements(&[measure("day 08/part 1", 10), measure("day 08/parse", 4)]);
+
+        let text = baseline.to_string();
+        assert_eq!("name,median_ns\nday 08/parse,4000000\nday 08/part 1,10000000\n", text);
+        assert_eq!(baseline, Baseline::parse(&text).unwrap());
+
+        let current = [measure("day 08/part 1", 12), measure("day 08/parse", 4), measure("day 12/part 1", 1)];
+        assert_eq!(Some(0.2), baseline.change(&current[0]).map(|c| (c * 100.0).round() / 100.0));
+        assert_eq!(None, baseline.change(&current[2]));
+
+        let regressed = baseline.regressions(&current, 0.1).into_iter().map(|m| m.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(vec!["day 08/part 1"], regressed);
+        assert!(baseline.regressions(&current, 0.25).is_empty());
+
+        assert!(report(&current, Some(&baseline)).contains("new"));
+    }
+
+    #[test]
+    fn invalid_baseline() {
+        assert_eq!(
+            BaselineError { line: 2, text: "day 08/parse".to_string() },
+            Baseline::parse("name,median_ns\nday 08/parse\n").unwrap_err()
+        );
+    }
+}
@@ -0,0 +1,131 @@
+use std::error::Error;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Args, Parser};
+
+use crate::answers::{AnswerKey, Verdict};
+use crate::input;
+use crate::solution::{Part, Puzzle};
+use crate::timer::{Report, Timer};
+
+#[derive(Args, Debug, Default)]
+pub struct RunArgs {
+    /// Only run this part of the puzzle (runs both when omitted)
+    #[arg(short, long)]
+    pub part: Option<Part>,
+
+    /// Puzzle input file, or '-' to read from stdin
+    #[arg(short, long)]
+    pub input: Option<String>,
+
+    /// How to report the time spent parsing and solving
+    #[arg(long, value_enum, default_value_t = Report::Table)]
+    pub timings: Report,
+
+    /// Write the timings to this file instead of printing them (CSV rows are appended)
+    #[arg(long)]
+    pub timings_out: Option<PathBuf>,
+
+    /// Check the answers against answers.toml, failing if any of them differ
+    #[arg(long)]
+    pub verify: bool,
+
+    /// Answers file to verify against (defaults to answers.toml at the workspace root)
+    #[arg(long, requires = "verify")]
+    pub answers: Option<PathBuf>,
+}
+
+impl RunArgs {
+    pub fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(part) => vec![part],
+            None => Part::all().to_vec(),
+        }
+    }
+}
+
+#[derive(Parser, Debug)]
+struct DayCli {
+    #[command(flatten)]
+    run: RunArgs,
+}
+
+/// Entry point shared by every day's binary.
+pub fn main(puzzle: Puzzle) -> ExitCode {
+    let cli = DayCli::parse();
+
+    match run(&puzzle, &cli.run) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Failed to run day {}: {}", puzzle.day(), e);
+            ExitCode::FAILURE
+        },
+    }
+}
+
+pub fn run(puzzle: &Puzzle, args: &RunArgs) -> Result<(), Box<dyn Error>> {
+    let timer = match args.timings_out {
+        Some(_) => Timer::with_report(Report::Quiet),
+        None => Timer::with_report(args.timings),
+    };
+
+    let answers = {
+        let _day = timer.span(&format!("day {:02}", puzzle.day()));
+        let input = timer.time("input", || input::load(args.input.as_deref(), puzzle.day(), puzzle.manifest_dir()))?;
+
+        puzzle.solve(&input, &args.parts(), &timer)?
+    };
+
+    for (part, answer) in &answers {
+        println!("{}", format_answer(puzzle.day(), *part, answer));
+    }
+
+    if let Some(path) = &args.timings_out {
+        timer.write_report(args.timings, path)?;
+    }
+
+    if args.verify {
+        let path = args.answers.clone().unwrap_or_else(|| AnswerKey::default_path(puzzle.manifest_dir()));
+        let key = AnswerKey::load(&path)?;
+
+        let mut failures = 0;
+        for (part, answer) in &answers {
+            let verdict = key.check(puzzle.day(), *part, answer);
+            if matches!(verdict, Verdict::Fail { .. }) {
+                failures += 1;
+            }
+            println!("Day {:02} part {} verify: {}", puzzle.day(), part, verdict);
+        }
+        if failures > 0 {
+            return Err(format!("{} answer(s) did not match '{}'", failures, path.display()).into());
+        }
+    }
+
+    Ok(())
+}
+
+pub fn format_answer(day: u8, part: Part, answer: &str) -> String {
+    if answer.contains('\n') {
+        format!("Day {:02} part {}:\n{}", day, part, answer)
+    } else {
+        format!("Day {:02} part {}: {}", day, part, answer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parts() {
+        assert_eq!(vec![Part::One, Part::Two], RunArgs { part: None, ..Default::default() }.parts());
+        assert_eq!(vec![Part::Two], RunArgs { part: Some(Part::Two), ..Default::default() }.parts());
+    }
+
+    #[test]
+    fn answers() {
+        assert_eq!("Day 09 part 1: 13", format_answer(9, Part::One, "13"));
+        assert_eq!("Day 10 part 2:\n##..\n..##", format_answer(10, Part::Two, "##..\n..##"));
+    }
+}
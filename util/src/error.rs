@@ -0,0 +1,168 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// A malformed piece of puzzle input.
+///
+/// Lines and columns are 1-based and relative to the text handed to the parser,
+/// so parsers working on a single line report line 1 and callers shift it with [`ParseError::offset_lines`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    line: usize,
+    column: usize,
+    text: String,
+    expected: String,
+}
+
+impl ParseError {
+    pub fn new(text: &str, expected: impl Into<String>) -> Self {
+        ParseError {
+            line: 1,
+            column: 1,
+            text: text.to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    pub fn at(mut self, line: usize, column: usize) -> Self {
+        self.line = line;
+        self.column = column;
+        self
+    }
+
+    pub fn at_column(mut self, column: usize) -> Self {
+        self.column = column;
+        self
+    }
+
+    pub fn offset_lines(mut self, lines: usize) -> Self {
+        self.line += lines;
+        self
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: expected {} but found '{}'", self.line, self.column, self.expected, self.text)
+    }
+}
+
+impl Error for ParseError {}
+
+#[cfg(feature = "pest")]
+impl<R: pest::RuleType> From<pest::error::Error<R>> for ParseError {
+    fn from(e: pest::error::Error<R>) -> Self {
+        let (line, column) = match e.line_col {
+            pest::error::LineColLocation::Pos(position) => position,
+            pest::error::LineColLocation::Span(start, _) => start,
+        };
+        let message = e.variant.message();
+        let expected = message.strip_prefix("expected ").unwrap_or(&message);
+
+        ParseError::new(e.line(), expected).at(line, column)
+    }
+}
+
+/// Why a part has no answer for an input that parsed, like a route to a square that can't be reached.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NoAnswer {
+    reason: String,
+}
+
+impl NoAnswer {
+    pub fn new(reason: impl Into<String>) -> Self {
+        NoAnswer { reason: reason.into() }
+    }
+
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+impl fmt::Display for NoAnswer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no answer: {}", self.reason)
+    }
+}
+
+impl Error for NoAnswer {}
+
+/// Parses every non-blank line of `input`, reporting errors against their line in `input`.
+pub fn parse_lines<T>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr<Err = ParseError>,
+{
+    input.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| line.parse::<T>().map_err(|e| e.offset_lines(index)))
+        .collect()
+}
+
+/// Writes one item per line, the opposite of [`parse_lines`].
+pub fn write_lines<T: fmt::Display>(items: &[T]) -> String {
+    items.iter()
+        .map(|item| format!("{}\n", item))
+        .collect()
+}
+
+/// Parses a number found at `column` of a line, reporting `expected` when it is not one.
+pub fn parse_number<T: FromStr>(text: &str, column: usize, expected: &str) -> Result<T, ParseError> {
+    text.parse::<T>()
+        .map_err(|_| ParseError::new(text, expected).at_column(column))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Digit(u8);
+
+    impl FromStr for Digit {
+        type Err = ParseError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let offset = s.len() - s.trim_start().len();
+            parse_number(s.trim(), offset + 1, "a digit").map(Digit)
+        }
+    }
+
+    #[test]
+    fn display() {
+        let error = ParseError::new("U x", "a step count").at(3, 3);
+
+        assert_eq!("line 3, column 3: expected a step count but found 'U x'", error.to_string());
+        assert_eq!("no answer: no elves", NoAnswer::new("no elves").to_string());
+    }
+
+    #[test]
+    fn lines() {
+        assert_eq!(vec![Digit(1), Digit(2)], parse_lines::<Digit>("1\n\n2\n").unwrap());
+
+        let error = parse_lines::<Digit>("1\n\n  x\n").unwrap_err();
+        assert_eq!(3, error.line());
+        assert_eq!(3, error.column());
+        assert_eq!("x", error.text());
+        assert_eq!("a digit", error.expected());
+
+        assert_eq!("1\n2\n", write_lines(&[1, 2]));
+        assert_eq!("", write_lines::<u8>(&[]));
+    }
+}
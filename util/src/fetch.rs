@@ -0,0 +1,455 @@
+//! Fetching puzzle inputs and caching them on disk, so each day is only ever downloaded once.
+//!
+//! The cache writes `day_XX.txt` files, the same layout [`crate::input`] looks for in `AOC_INPUTS`.
+//! It also keeps the time of the last request in `.last_request`, so requests stay spaced out
+//! across runs, even ones running at the same time, and remembers days that aren't unlocked yet in
+//! `day_XX.missing` for a while.
+
+use std::error::Error;
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Session cookie for the puzzle site, copied from a logged in browser.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Overrides where inputs are fetched from, e.g. a local stub server.
+pub const ENDPOINT_VAR: &str = "AOC_ENDPOINT";
+pub const DEFAULT_ENDPOINT: &str = "https://adventofcode.com";
+pub const YEAR: u16 = 2022;
+
+#[derive(Debug)]
+pub enum FetchError {
+    InvalidDay(u8),
+    MissingSession,
+    /// The server answered with something other than 200 OK
+    Status(u16, String),
+    Transport(String),
+    Io(PathBuf, io::Error),
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::InvalidDay(day) => write!(f, "day {} is not in the calendar (1-25)", day),
+            FetchError::MissingSession => write!(f, "no session token - set {}", SESSION_VAR),
+            FetchError::Status(400, url) => write!(f, "'{}' rejected the session token (400)", url),
+            FetchError::Status(404, url) => write!(f, "'{}' is not unlocked yet (404)", url),
+            FetchError::Status(status, url) => write!(f, "'{}' answered {}", url, status),
+            FetchError::Transport(e) => write!(f, "request failed: {}", e),
+            FetchError::Io(path, e) => write!(f, "failed to cache '{}': {}", path.display(), e),
+        }
+    }
+}
+
+impl Error for FetchError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            FetchError::Io(_, e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+pub trait Fetcher {
+    fn fetch(&self, day: u8) -> Result<Fetched, FetchError>;
+}
+
+pub struct Fetched {
+    pub input: String,
+    /// Where the input came from, recorded next to the cached file
+    pub source: String,
+}
+
+/// Cached inputs in `dir`, fetched on first use, waiting at least `min_interval` between requests.
+pub struct InputCache<F> {
+    dir: PathBuf,
+    fetcher: F,
+    min_interval: Duration,
+    retry_missing_after: Duration,
+}
+
+impl<F: Fetcher> InputCache<F> {
+    pub fn new(dir: impl Into<PathBuf>, fetcher: F) -> Self {
+        InputCache {
+            dir: dir.into(),
+            fetcher,
+            min_interval: Duration::from_secs(3),
+            retry_missing_after: Duration::from_secs(15 * 60),
+        }
+    }
+
+    pub fn with_min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = min_interval;
+        self
+    }
+
+    /// How long a day that wasn't unlocked yet (404) is reported missing without asking again.
+    pub fn with_retry_missing_after(mut self, retry_missing_after: Duration) -> Self {
+        self.retry_missing_after = retry_missing_after;
+        self
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn path(&self, day: u8) -> PathBuf {
+        self.dir.join(format!("day_{:02}.txt", day))
+    }
+
+    pub fn is_cached(&self, day: u8) -> bool {
+        self.path(day).is_file()
+    }
+
+    /// The cached input, fetching and caching it first if this is the first time it's needed.
+    pub fn get(&self, day: u8) -> Result<String, FetchError> {
+        if !(1..=25).contains(&day) {
+            return Err(FetchError::InvalidDay(day));
+        }
+        let path = self.path(day);
+        if path.is_file() {
+            return fs::read_to_string(&path).map_err(|e| FetchError::Io(path, e));
+        }
+
+        let missing = path.with_extension("missing");
+        if let Some((url, checked_at)) = read_missing(&missing) {
+            if now().saturating_sub(checked_at) < self.retry_missing_after {
+                return Err(FetchError::Status(404, url));
+            }
+        }
+
+        self.throttle().map_err(|e| FetchError::Io(self.dir.join(LAST_REQUEST), e))?;
+        let fetched = match self.fetcher.fetch(day) {
+            Err(FetchError::Status(404, url)) => {
+                fs::write(&missing, format!("{}\nchecked at {}\n", url, now().as_secs())).map_err(|e| FetchError::Io(missing, e))?;
+                return Err(FetchError::Status(404, url));
+            },
+            fetched => fetched?,
+        };
+        self.store(day, &fetched).map_err(|e| FetchError::Io(path, e))?;
+        let _ = fs::remove_file(missing);
+
+        Ok(fetched.input)
+    }
+
+    /// Waits until `min_interval` has passed since the last request made through this directory,
+    /// by this run or any other, then records this one. Runs take turns through a lock file, so
+    /// two started together can't both see the same last request and skip the wait.
+    fn throttle(&self) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        let _lock = Lock::acquire(self.dir.join(LOCK), self.min_interval + STALE_LOCK)?;
+
+        let path = self.dir.join(LAST_REQUEST);
+        let last = fs::read_to_string(&path).ok()
+            .and_then(|millis| millis.trim().parse::<u64>().ok())
+            .map(Duration::from_millis);
+        if let Some(last) = last {
+            let wait = self.min_interval.saturating_sub(now().saturating_sub(last));
+            if !wait.is_zero() {
+                thread::sleep(wait);
+            }
+        }
+
+        // rounded up, so the next run never waits less than it should
+        fs::write(path, now().as_nanos().div_ceil(1_000_000).to_string())
+    }
+
+    fn store(&self, day: u8, fetched: &Fetched) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+
+        // write then rename so an interrupted fetch never leaves a partial input behind
+        let path = self.path(day);
+        let partial = path.with_extension("txt.partial");
+        fs::write(&partial, &fetched.input)?;
+        fs::rename(&partial, &path)?;
+
+        fs::write(path.with_extension("source"), format!("{}\nfetched at {}\n", fetched.source, now().as_secs()))
+    }
+}
+
+const LAST_REQUEST: &str = ".last_request";
+const LOCK: &str = ".last_request.lock";
+/// How much longer than `min_interval` a lock can be held before it's taken to be left behind by a
+/// run that was killed while it waited.
+const STALE_LOCK: Duration = Duration::from_secs(60);
+
+/// A lock file, removed again when this is dropped.
+struct Lock(PathBuf);
+
+impl Lock {
+    fn acquire(path: PathBuf, stale_after: Duration) -> io::Result<Lock> {
+        loop {
+            match OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(_) => return Ok(Lock(path)),
+                Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {
+                    let held_for = fs::metadata(&path)
+                        .and_then(|m| m.modified())
+                        .map(|modified| SystemTime::now().duration_since(modified).unwrap_or_default());
+                    match held_for {
+                        Ok(held_for) if held_for > stale_after => {
+                            let _ = fs::remove_file(&path);
+                        },
+                        _ => thread::sleep(Duration::from_millis(20)),
+                    }
+                },
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+impl Drop for Lock {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.0);
+    }
+}
+
+/// Time since the epoch, which unlike an `Instant` means the same thing in the next run.
+fn now() -> Duration {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default()
+}
+
+/// The URL that wasn't found and when, from a `day_XX.missing` file.
+fn read_missing(path: &Path) -> Option<(String, Duration)> {
+    let text = fs::read_to_string(path).ok()?;
+    let mut lines = text.lines();
+    let url = lines.next()?.to_string();
+    let checked_at = lines.next()?.strip_prefix("checked at ")?.parse().ok()?;
+
+    Some((url, Duration::from_secs(checked_at)))
+}
+
+#[cfg(feature = "fetch")]
+pub use http::HttpFetcher;
+
+#[cfg(feature = "fetch")]
+mod http {
+    use std::env;
+
+    use super::*;
+
+    /// Downloads inputs with a session cookie. The [`InputCache`] spaces the requests out.
+    pub struct HttpFetcher {
+        endpoint: String,
+        session: String,
+    }
+
+    impl HttpFetcher {
+        pub fn new(endpoint: &str, session: &str) -> Self {
+            HttpFetcher {
+                endpoint: endpoint.trim_end_matches('/').to_string(),
+                session: session.trim().to_string(),
+            }
+        }
+
+        /// Reads the session from `AOC_SESSION` and the endpoint from `AOC_ENDPOINT`, if set.
+        pub fn from_env() -> Result<Self, FetchError> {
+            let session = env::var(SESSION_VAR).map_err(|_| FetchError::MissingSession)?;
+            let endpoint = env::var(ENDPOINT_VAR).unwrap_or_else(|_| DEFAULT_ENDPOINT.to_string());
+
+            Ok(HttpFetcher::new(&endpoint, &session))
+        }
+
+        pub fn url(&self, day: u8) -> String {
+            format!("{}/{}/day/{}/input", self.endpoint, YEAR, day)
+        }
+    }
+
+    impl Fetcher for HttpFetcher {
+        fn fetch(&self, day: u8) -> Result<Fetched, FetchError> {
+            if self.session.is_empty() {
+                return Err(FetchError::MissingSession);
+            }
+
+            let url = self.url(day);
+            let response = ureq::get(&url)
+                .set("Cookie", &format!("session={}", self.session))
+                .set("User-Agent", "advent_of_code_2022 input cache")
+                .call();
+            let input = match response {
+                Ok(response) => response.into_string().map_err(|e| FetchError::Transport(e.to_string()))?,
+                Err(ureq::Error::Status(status, _)) => return Err(FetchError::Status(status, url)),
+                Err(e) => return Err(FetchError::Transport(e.to_string())),
+            };
+
+            Ok(Fetched { input, source: url })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::time::Instant;
+
+    use super::*;
+
+    struct Counting {
+        calls: Cell<u32>,
+    }
+
+    fn counting() -> Counting {
+        Counting { calls: Cell::new(0) }
+    }
+
+    impl Fetcher for Counting {
+        fn fetch(&self, day: u8) -> Result<Fetched, FetchError> {
+            self.calls.set(self.calls.get() + 1);
+            match day {
+                13 => Err(FetchError::Status(404, "stub".to_string())),
+                _ => Ok(Fetched { input: format!("input {}\n", day), source: format!("stub/{}", day) }),
+            }
+        }
+    }
+
+    fn scratch(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc_fetch_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn caches() {
+        let dir = scratch("cache");
+        let cache = InputCache::new(&dir, counting()).with_min_interval(Duration::ZERO);
+
+        assert!(!cache.is_cached(1));
+        assert_eq!("input 1\n", cache.get(1).unwrap());
+        assert_eq!("input 1\n", cache.get(1).unwrap());
+        assert_eq!(1, cache.fetcher.calls.get());
+        assert!(cache.is_cached(1));
+        assert!(fs::read_to_string(dir.join("day_01.source")).unwrap().starts_with("stub/1\n"));
+
+        assert!(matches!(cache.get(13), Err(FetchError::Status(404, _))));
+        assert!(!cache.is_cached(13));
+        assert!(matches!(cache.get(26), Err(FetchError::InvalidDay(26))));
+        assert_eq!(2, cache.fetcher.calls.get());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn missing() {
+        let dir = scratch("missing");
+        let cache = InputCache::new(&dir, counting()).with_min_interval(Duration::ZERO);
+
+        assert!(matches!(cache.get(13), Err(FetchError::Status(404, _))));
+        assert!(matches!(cache.get(13), Err(FetchError::Status(404, _))));
+        assert_eq!(1, cache.fetcher.calls.get());
+        assert!(fs::read_to_string(dir.join("day_13.missing")).unwrap().starts_with("stub\nchecked at "));
+
+        // a later run asks again once the wait is over
+        let later = InputCache::new(&dir, counting()).with_min_interval(Duration::ZERO).with_retry_missing_after(Duration::ZERO);
+        assert!(matches!(later.get(13), Err(FetchError::Status(404, _))));
+        assert_eq!(1, later.fetcher.calls.get());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn throttles_across_runs() {
+        let dir = scratch("throttle");
+        let interval = Duration::from_millis(200);
+
+        let start = Instant::now();
+        InputCache::new(&dir, counting()).with_min_interval(interval).get(1).unwrap();
+        InputCache::new(&dir, counting()).with_min_interval(interval).get(2).unwrap();
+        assert!(start.elapsed() >= interval);
+        assert!(fs::read_to_string(dir.join(LAST_REQUEST)).unwrap().parse::<u64>().is_ok());
+        assert!(!dir.join(LOCK).exists());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn throttles_runs_at_the_same_time() {
+        let dir = scratch("concurrent");
+        let interval = Duration::from_millis(200);
+
+        let start = Instant::now();
+        thread::scope(|s| {
+            for day in 1..=2 {
+                let dir = &dir;
+                s.spawn(move || InputCache::new(dir, counting()).with_min_interval(interval).get(day).unwrap());
+            }
+        });
+        assert!(start.elapsed() >= interval);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn stale_lock() {
+        let dir = scratch("stale");
+        fs::create_dir_all(&dir).unwrap();
+        let lock = fs::File::create(dir.join(LOCK)).unwrap();
+        lock.set_modified(UNIX_EPOCH).unwrap();
+
+        let cache = InputCache::new(&dir, counting()).with_min_interval(Duration::ZERO);
+        assert_eq!("input 1\n", cache.get(1).unwrap());
+        assert!(!dir.join(LOCK).exists());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[cfg(feature = "fetch")]
+    #[test]
+    fn http_stub() {
+        use std::io::{BufRead, BufReader, Write};
+        use std::net::TcpListener;
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint = format!("http://{}", listener.local_addr().unwrap());
+        let server = std::thread::spawn(move || {
+            let mut requests = vec![];
+            for stream in listener.incoming().take(3) {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut head = vec![];
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                    head.push(line.trim().to_string());
+                }
+                let authorised = head.iter().any(|h| h.eq_ignore_ascii_case("cookie: session=secret"));
+                let (status, body) = match (authorised, head[0].as_str()) {
+                    (false, _) => ("400 Bad Request", "log in".to_string()),
+                    (true, "GET /2022/day/1/input HTTP/1.1") => ("200 OK", "1000\n2000\n".to_string()),
+                    (true, _) => ("404 Not Found", "not yet".to_string()),
+                };
+                write!(stream, "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", status, body.len(), body).unwrap();
+                requests.push(head[0].clone());
+            }
+            requests
+        });
+
+        let dir = scratch("http");
+        let cache = InputCache::new(&dir, HttpFetcher::new(&endpoint, "secret")).with_min_interval(Duration::from_millis(100));
+
+        let start = Instant::now();
+        assert_eq!("1000\n2000\n", cache.get(1).unwrap());
+        assert_eq!("1000\n2000\n", cache.get(1).unwrap());
+        assert!(matches!(cache.get(2), Err(FetchError::Status(404, _))));
+        assert!(start.elapsed() >= Duration::from_millis(100));
+
+        let rejected = InputCache::new(&dir, HttpFetcher::new(&endpoint, "wrong")).with_min_interval(Duration::ZERO);
+        assert!(matches!(rejected.get(3), Err(FetchError::Status(400, _))));
+        assert!(matches!(
+            InputCache::new(&dir, HttpFetcher::new(&endpoint, " ")).with_min_interval(Duration::ZERO).get(4),
+            Err(FetchError::MissingSession)
+        ));
+
+        assert_eq!(vec![
+            "GET /2022/day/1/input HTTP/1.1",
+            "GET /2022/day/2/input HTTP/1.1",
+            "GET /2022/day/3/input HTTP/1.1",
+        ], server.join().unwrap());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
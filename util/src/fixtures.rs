@@ -0,0 +1,102 @@
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::answers::{parse_parts, parse_table};
+use crate::solution::{Part, Puzzle};
+use crate::timer::{Report, Timer};
+
+pub const FIXTURES_DIR: &str = "fixtures";
+
+/// A sample input, `fixtures/<name>.txt`, with its expected answers in `fixtures/<name>.toml`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Fixture {
+    pub name: String,
+    pub input: String,
+    pub expected: Vec<(Part, String)>,
+}
+
+impl Fixture {
+    pub fn path(manifest_dir: &str, name: &str) -> PathBuf {
+        Path::new(manifest_dir).join(FIXTURES_DIR).join(format!("{}.txt", name))
+    }
+}
+
+/// Every fixture of a day crate, sorted by name.
+pub fn load(manifest_dir: &str) -> Result<Vec<Fixture>, Box<dyn Error>> {
+    let dir = Path::new(manifest_dir).join(FIXTURES_DIR);
+    let mut inputs = fs::read_dir(&dir)
+        .map_err(|e| format!("failed to read '{}': {}", dir.display(), e))?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+    inputs.retain(|path| path.extension().is_some_and(|ext| ext == "txt"));
+    inputs.sort();
+
+    inputs.into_iter()
+        .map(|path| {
+            let name = path.file_stem().unwrap().to_string_lossy().to_string();
+            let answers_path = path.with_extension("toml");
+            let answers = fs::read_to_string(&answers_path)
+                .map_err(|e| format!("failed to read '{}': {}", answers_path.display(), e))?;
+            let expected = parse_table(&answers)
+                .and_then(|table| parse_parts(&table, ""))
+                .map_err(|e| format!("invalid '{}': {}", answers_path.display(), e))?;
+
+            Ok(Fixture { name, input: fs::read_to_string(&path)?, expected })
+        })
+        .collect()
+}
+
+/// Solves every fixture of the puzzle's crate and panics with a list of the answers that differ.
+pub fn assert_fixtures(puzzle: Puzzle) {
+    let fixtures = load(puzzle.manifest_dir()).unwrap_or_else(|e| panic!("{}", e));
+    assert!(!fixtures.is_empty(), "no fixtures in {}/{}", puzzle.manifest_dir(), FIXTURES_DIR);
+
+    let timer = Timer::with_report(Report::Quiet);
+    let mut failures = vec![];
+    for fixture in fixtures {
+        let parts = fixture.expected.iter().map(|(part, _)| *part).collect::<Vec<_>>();
+        let answers = match puzzle.solve(&fixture.input, &parts, &timer) {
+            Ok(answers) => answers,
+            Err(e) => {
+                failures.push(format!("{}: {}", fixture.name, e));
+                continue;
+            },
+        };
+        for ((part, expected), (_, answer)) in fixture.expected.iter().zip(answers) {
+            if expected.trim_end() != answer.trim_end() {
+                failures.push(format!("{} part {}: expected {} but found {}", fixture.name, part, expected, answer));
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "day {:02} fixtures failed:\n{}", puzzle.day(), failures.join("\n"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn load_dir() {
+        let dir = std::env::temp_dir().join(format!("aoc_fixtures_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join(FIXTURES_DIR)).unwrap();
+        fs::write(dir.join("fixtures/b.txt"), "2\n").unwrap();
+        fs::write(dir.join("fixtures/b.toml"), "part_2 = '''\n#.\n.#\n'''\n").unwrap();
+        fs::write(dir.join("fixtures/a.txt"), "1\n").unwrap();
+        fs::write(dir.join("fixtures/a.toml"), "part_1 = 1\n").unwrap();
+        fs::write(dir.join("fixtures/notes.md"), "ignored").unwrap();
+
+        let fixtures = load(dir.to_str().unwrap()).unwrap();
+        assert_eq!(vec![
+            Fixture { name: "a".to_string(), input: "1\n".to_string(), expected: vec![(Part::One, "1".to_string())] },
+            Fixture { name: "b".to_string(), input: "2\n".to_string(), expected: vec![(Part::Two, "#.\n.#\n".to_string())] },
+        ], fixtures);
+
+        fs::remove_file(dir.join("fixtures/a.toml")).unwrap();
+        assert!(load(dir.to_str().unwrap()).is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
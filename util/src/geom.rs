@@ -0,0 +1,262 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A position or offset on a grid, with `x` growing to the right and `y` growing down
+/// so that it matches the order rows appear in puzzle input.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Point { x, y }
+    }
+
+    pub fn manhattan(&self, other: &Point) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Distance when diagonal steps count the same as orthogonal ones.
+    pub fn chebyshev(&self, other: &Point) -> u64 {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// Each coordinate reduced to -1, 0 or 1.
+    pub fn signum(&self) -> Point {
+        Point::new(self.x.signum(), self.y.signum())
+    }
+
+    pub fn step(&self, direction: Direction) -> Point {
+        *self + direction.offset()
+    }
+
+    /// The 4 orthogonal neighbours, clockwise from up.
+    pub fn neighbours_4(&self) -> [Point; 4] {
+        Direction::ALL.map(|d| self.step(d))
+    }
+
+    /// All 8 surrounding neighbours, clockwise from up.
+    pub fn neighbours_8(&self) -> [Point; 8] {
+        SURROUNDING.map(|offset| *self + offset)
+    }
+}
+
+const SURROUNDING: [Point; 8] = [
+    Point::new(0, -1), Point::new(1, -1), Point::new(1, 0), Point::new(1, 1),
+    Point::new(0, 1), Point::new(-1, 1), Point::new(-1, 0), Point::new(-1, -1),
+];
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, factor: i64) -> Point {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl From<(i64, i64)> for Point {
+    fn from((x, y): (i64, i64)) -> Self {
+        Point::new(x, y)
+    }
+}
+
+impl From<Point> for (i64, i64) {
+    fn from(point: Point) -> Self {
+        (point.x, point.y)
+    }
+}
+
+impl From<Direction> for Point {
+    fn from(direction: Direction) -> Self {
+        direction.offset()
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Clockwise from up.
+    pub const ALL: [Direction; 4] = [Direction::Up, Direction::Right, Direction::Down, Direction::Left];
+
+    pub fn offset(&self) -> Point {
+        match self {
+            Direction::Up => Point::new(0, -1),
+            Direction::Right => Point::new(1, 0),
+            Direction::Down => Point::new(0, 1),
+            Direction::Left => Point::new(-1, 0),
+        }
+    }
+
+    pub fn turn_right(&self) -> Direction {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn turn_left(&self) -> Direction {
+        self.opposite().turn_right()
+    }
+
+    pub fn opposite(&self) -> Direction {
+        self.turn_right().turn_right()
+    }
+
+    pub fn is_horizontal(&self) -> bool {
+        matches!(self, Direction::Left | Direction::Right)
+    }
+
+    /// `U`, `R`, `D` or `L`
+    pub fn letter(&self) -> char {
+        match self {
+            Direction::Up => 'U',
+            Direction::Right => 'R',
+            Direction::Down => 'D',
+            Direction::Left => 'L',
+        }
+    }
+
+    /// `^`, `>`, `v` or `<`
+    pub fn arrow(&self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+        }
+    }
+}
+
+/// Fails unless the point is a single orthogonal step.
+impl TryFrom<Point> for Direction {
+    type Error = Point;
+
+    fn try_from(offset: Point) -> Result<Self, Self::Error> {
+        Direction::ALL.into_iter()
+            .find(|d| d.offset() == offset)
+            .ok_or(offset)
+    }
+}
+
+/// Accepts both `U`/`R`/`D`/`L` and arrows.
+impl TryFrom<char> for Direction {
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'U' | '^' => Ok(Direction::Up),
+            'R' | '>' => Ok(Direction::Right),
+            'D' | 'v' => Ok(Direction::Down),
+            'L' | '<' => Ok(Direction::Left),
+            _ => Err(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let a = Point::new(3, -2);
+        let b = Point::new(-1, 4);
+
+        assert_eq!(Point::new(2, 2), a + b);
+        assert_eq!(Point::new(4, -6), a - b);
+        assert_eq!(Point::new(-3, 2), -a);
+        assert_eq!(Point::new(9, -6), a * 3);
+        assert_eq!(Point::new(1, -1), a.signum());
+        assert_eq!(10, a.manhattan(&b));
+        assert_eq!(6, a.chebyshev(&b));
+
+        let mut c = a;
+        c += b;
+        c -= a;
+        assert_eq!(b, c);
+
+        assert_eq!(Point::new(1, 2), (1, 2).into());
+        assert_eq!((1, 2), Point::new(1, 2).into());
+    }
+
+    #[test]
+    fn directions() {
+        assert_eq!(Point::new(2, 2), Point::new(2, 3).step(Direction::Up));
+        assert_eq!(Direction::Left, Direction::Up.turn_left());
+        assert_eq!(Direction::Up, Direction::Left.turn_right());
+        assert_eq!(Direction::Down, Direction::Up.opposite());
+        assert!(Direction::Right.is_horizontal());
+
+        for direction in Direction::ALL {
+            assert_eq!(Ok(direction), Direction::try_from(direction.offset()));
+            assert_eq!(Ok(direction), Direction::try_from(direction.arrow()));
+            assert_eq!(Ok(direction), Direction::try_from(direction.letter()));
+            assert_eq!(direction.offset(), -direction.opposite().offset());
+        }
+        assert_eq!(Err(Point::new(1, 1)), Direction::try_from(Point::new(1, 1)));
+        assert_eq!(Ok(Direction::Down), Direction::try_from('D'));
+        assert_eq!(Err('x'), Direction::try_from('x'));
+    }
+
+    #[test]
+    fn neighbours() {
+        let point = Point::new(5, 5);
+
+        assert_eq!([Point::new(5, 4), Point::new(6, 5), Point::new(5, 6), Point::new(4, 5)], point.neighbours_4());
+        assert!(point.neighbours_8().iter().all(|n| n.chebyshev(&point) == 1));
+    }
+}
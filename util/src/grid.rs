@@ -0,0 +1,251 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::error::ParseError;
+use crate::geom::Point;
+
+/// Cells addressed by [`Point`], so `(0, 0)` is the top left cell.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid { cells: vec![fill; width * height], width, height }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from cells in row order; panics unless they fill whole rows.
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Self {
+        assert!(width > 0 || cells.is_empty(), "a grid with cells needs a width");
+        assert_eq!(0, cells.len() % width.max(1), "{} cells do not fill rows of {}", cells.len(), width);
+
+        let height = cells.len().checked_div(width).unwrap_or(0);
+        Grid { cells, width, height }
+    }
+
+    /// Parses one row per non-blank line, converting each character with `cell`, which gets the
+    /// character's position and returns what it expected on failure. Lines are trimmed, but the
+    /// reported column still counts from the start of the line.
+    pub fn parse(text: &str, mut cell: impl FnMut(Point, char) -> Result<T, String>) -> Result<Self, ParseError> {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+
+        for (index, line) in text.lines().enumerate() {
+            let indent = line.len() - line.trim_start().len();
+            let row = line.trim();
+            if row.is_empty() {
+                continue;
+            }
+
+            let row_width = row.chars().count();
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(ParseError::new(line, format!("a row of {} cells", width)).at(index + 1, indent + 1));
+                },
+                Some(_) => {},
+            }
+
+            for (x, c) in row.chars().enumerate() {
+                let value = cell(Point::new(x as i64, height as i64), c)
+                    .map_err(|expected| ParseError::new(line, expected).at(index + 1, indent + x + 1))?;
+                cells.push(value);
+            }
+            height += 1;
+        }
+
+        Ok(Grid { cells, width: width.unwrap_or(0), height })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.x >= 0 && point.y >= 0 && (point.x as usize) < self.width && (point.y as usize) < self.height
+    }
+
+    /// Index of the position in row order, if it is inside the grid.
+    pub fn index(&self, point: Point) -> Option<usize> {
+        self.contains(point)
+            .then(|| point.y as usize * self.width + point.x as usize)
+    }
+
+    pub fn point(&self, index: usize) -> Point {
+        Point::new((index % self.width) as i64, (index / self.width) as i64)
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.index(point).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.index(point).map(|i| &mut self.cells[i])
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.cells.iter()
+            .enumerate()
+            .map(|(i, cell)| (self.point(i), cell))
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        (0..self.cells.len()).map(|i| self.point(i))
+    }
+
+    /// Neighbours up, right, down and left of the point that are inside the grid.
+    pub fn neighbours_4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        point.neighbours_4().into_iter().filter(|p| self.contains(*p))
+    }
+
+    /// All surrounding neighbours of the point that are inside the grid, clockwise from up.
+    pub fn neighbours_8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        point.neighbours_8().into_iter().filter(|p| self.contains(*p))
+    }
+
+    pub fn row(&self, y: usize) -> impl Iterator<Item = &T> + '_ {
+        self.cells[y * self.width..(y + 1) * self.width].iter()
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> + '_ {
+        self.cells.iter().skip(x).step_by(self.width.max(1)).take(self.height)
+    }
+
+    /// Cells from the one after `from` onwards, moving by `step` until leaving the grid.
+    pub fn ray(&self, from: Point, step: impl Into<Point>) -> impl Iterator<Item = (Point, &T)> + '_ {
+        let step = step.into();
+        assert_ne!(Point::ORIGIN, step, "a ray needs a non-zero step");
+        std::iter::successors(Some(from), move |p| Some(*p + step))
+            .skip(1)
+            .map_while(|p| self.get(p).map(|cell| (p, cell)))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { cells: self.cells.iter().map(f).collect(), width: self.width, height: self.height }
+    }
+
+    /// One line of characters per row.
+    pub fn render(&self, mut f: impl FnMut(Point, &T) -> char) -> String {
+        let mut text = String::with_capacity((self.width + 1) * self.height);
+        for (i, cell) in self.cells.iter().enumerate() {
+            if i > 0 && i % self.width == 0 {
+                text.push('\n');
+            }
+            text.push(f(self.point(i), cell));
+        }
+        text
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{} is outside a {}x{} grid", point, self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{} is outside a {}x{} grid", point, width, height))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in 0..self.height {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in self.row(y) {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geom::Direction;
+
+    fn p(x: i64, y: i64) -> Point {
+        Point::new(x, y)
+    }
+
+    fn digits(text: &str) -> Result<Grid<u32>, ParseError> {
+        Grid::parse(text, |_, c| c.to_digit(10).ok_or_else(|| "a digit".to_string()))
+    }
+
+    #[test]
+    fn parse() {
+        let grid = digits("\n  123\r\n  456\n\n").unwrap();
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(6, grid[p(2, 1)]);
+        assert_eq!(None, grid.get(p(3, 0)));
+        assert_eq!(None, grid.get(p(0, -1)));
+        assert_eq!("123\n456", grid.to_string());
+
+        let error = digits("123\n4x6").unwrap_err();
+        assert_eq!((2, 2, "a digit"), (error.line(), error.column(), error.expected()));
+
+        let error = digits("123\n  4567").unwrap_err();
+        assert_eq!((2, 3, "a row of 3 cells"), (error.line(), error.column(), error.expected()));
+
+        assert!(digits("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = Grid::new(3, 3, 0);
+
+        assert_eq!(vec![p(1, 0), p(2, 1), p(1, 2), p(0, 1)], grid.neighbours_4(p(1, 1)).collect::<Vec<_>>());
+        assert_eq!(vec![p(1, 0), p(0, 1)], grid.neighbours_4(p(0, 0)).collect::<Vec<_>>());
+        assert_eq!(8, grid.neighbours_8(p(1, 1)).count());
+        assert_eq!(vec![p(2, 1), p(1, 2), p(1, 1)], grid.neighbours_8(p(2, 2)).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn lines() {
+        let grid = Grid::from_cells(3, (1..=9).collect());
+
+        assert_eq!(vec![4, 5, 6], grid.row(1).copied().collect::<Vec<_>>());
+        assert_eq!(vec![3, 6, 9], grid.column(2).copied().collect::<Vec<_>>());
+        assert_eq!(vec![(p(1, 1), &5), (p(2, 2), &9)], grid.ray(p(0, 0), (1, 1)).collect::<Vec<_>>());
+        assert_eq!(0, grid.ray(p(0, 0), Direction::Left).count());
+        assert_eq!(p(2, 1), grid.point(5));
+        assert_eq!(Some(5), grid.index(p(2, 1)));
+    }
+
+    #[test]
+    fn render() {
+        let mut grid = Grid::new(2, 2, '.');
+        grid[p(1, 0)] = '#';
+
+        assert_eq!(".#\n..", grid.to_string());
+        assert_eq!("1#\n..", grid.render(|point, c| if point == Point::ORIGIN { '1' } else { *c }));
+        assert_eq!(".#\n..", grid.map(|c| c.to_string()).to_string());
+    }
+}
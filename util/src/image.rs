@@ -0,0 +1,321 @@
+//! Writing grids and sets of points out as PPM or PNG images, for results too big to read as text.
+//!
+//! There's no compression: the PNG writer stores the pixels as they are, which keeps it short and
+//! free of dependencies at the cost of files about the size of a PPM.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::geom::Point;
+use crate::grid::Grid;
+
+pub type Rgb = [u8; 3];
+
+pub const BLACK: Rgb = [0, 0, 0];
+pub const WHITE: Rgb = [255, 255, 255];
+
+/// Colours spread over a range of values, blending between neighbouring stops.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Palette(pub &'static [Rgb]);
+
+impl Palette {
+    /// Sea level up to snow: dark green, yellow-green, brown, grey, white.
+    pub const ELEVATION: Palette = Palette(&[[24, 84, 40], [150, 190, 80], [140, 100, 50], [150, 150, 150], [255, 255, 255]]);
+    /// Short to tall trees: pale green to deep forest green.
+    pub const FOREST: Palette = Palette(&[[220, 240, 190], [110, 180, 90], [20, 80, 30]]);
+    /// Cold to hot: dark blue, red, yellow.
+    pub const HEAT: Palette = Palette(&[[20, 20, 90], [200, 40, 40], [255, 230, 80]]);
+    pub const GREYS: Palette = Palette(&[BLACK, WHITE]);
+
+    /// The colour `t` of the way along, where 0 is the first stop and 1 the last.
+    pub fn at(&self, t: f64) -> Rgb {
+        let stops = self.0;
+        if stops.len() < 2 {
+            return stops.first().copied().unwrap_or(BLACK);
+        }
+        let position = t.clamp(0.0, 1.0) * (stops.len() - 1) as f64;
+        let index = (position as usize).min(stops.len() - 2);
+        let fraction = position - index as f64;
+
+        let (from, to) = (stops[index], stops[index + 1]);
+        [0, 1, 2].map(|c| (from[c] as f64 + (to[c] as f64 - from[c] as f64) * fraction).round() as u8)
+    }
+
+    /// The colour for `value` when the palette spans `min..=max`.
+    pub fn scale(&self, value: i64, min: i64, max: i64) -> Rgb {
+        if max <= min {
+            return self.at(0.0);
+        }
+        self.at((value - min) as f64 / (max - min) as f64)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, background: Rgb) -> Self {
+        Image { width, height, pixels: vec![background; width * height] }
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut colour: impl FnMut(Point) -> Rgb) -> Self {
+        let pixels = (0..height * width).map(|i| colour(Point::new((i % width) as i64, (i / width) as i64))).collect();
+        Image { width, height, pixels }
+    }
+
+    /// A pixel for each cell of the grid.
+    pub fn from_grid<T>(grid: &Grid<T>, mut colour: impl FnMut(Point, &T) -> Rgb) -> Self {
+        Image { width: grid.width(), height: grid.height(), pixels: grid.iter().map(|(p, cell)| colour(p, cell)).collect() }
+    }
+
+    /// Just big enough to hold every point, each in its own colour over `background`. Points that
+    /// come later are drawn over earlier ones at the same place.
+    pub fn from_points(points: impl IntoIterator<Item = (Point, Rgb)>, background: Rgb) -> Self {
+        let points = points.into_iter().collect::<Vec<_>>();
+        let Some((first, _)) = points.first() else {
+            return Image::new(0, 0, background);
+        };
+
+        let (mut min, mut max) = (*first, *first);
+        for (p, _) in &points {
+            min = Point::new(min.x.min(p.x), min.y.min(p.y));
+            max = Point::new(max.x.max(p.x), max.y.max(p.y));
+        }
+        let mut image = Image::new((max.x - min.x + 1) as usize, (max.y - min.y + 1) as usize, background);
+        for (p, colour) in points {
+            image.set(p - min, colour);
+        }
+        image
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Every pixel, a row at a time from the top left.
+    pub fn pixels(&self) -> &[Rgb] {
+        &self.pixels
+    }
+
+    pub fn get(&self, point: Point) -> Option<Rgb> {
+        self.index(point).map(|i| self.pixels[i])
+    }
+
+    /// Colours the pixel, ignoring points outside the image.
+    pub fn set(&mut self, point: Point, colour: Rgb) {
+        if let Some(i) = self.index(point) {
+            self.pixels[i] = colour;
+        }
+    }
+
+    fn index(&self, point: Point) -> Option<usize> {
+        let in_bounds = (0..self.width as i64).contains(&point.x) && (0..self.height as i64).contains(&point.y);
+        in_bounds.then(|| point.y as usize * self.width + point.x as usize)
+    }
+
+    /// Each pixel blown up into a `factor` by `factor` square, so small grids can be seen.
+    pub fn scaled(&self, factor: usize) -> Image {
+        let mut image = Image::new(self.width * factor, self.height * factor, BLACK);
+        for y in 0..image.height {
+            for x in 0..image.width {
+                image.pixels[y * image.width + x] = self.pixels[(y / factor) * self.width + x / factor];
+            }
+        }
+        image
+    }
+
+    /// A binary (`P6`) PPM.
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut bytes = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        bytes.extend(self.pixels.iter().flatten());
+        bytes
+    }
+
+    /// An 8-bit RGB PNG.
+    pub fn to_png(&self) -> Vec<u8> {
+        let mut header = vec![];
+        header.extend((self.width as u32).to_be_bytes());
+        header.extend((self.height as u32).to_be_bytes());
+        // bit depth 8, colour type RGB, then the default compression, filter and interlace methods
+        header.extend([8, 2, 0, 0, 0]);
+
+        // each row starts with the filter type, 0 for none
+        let mut raw = Vec::with_capacity(self.height * (self.width * 3 + 1));
+        for row in self.pixels.chunks(self.width.max(1)).take(self.height) {
+            raw.push(0);
+            raw.extend(row.iter().flatten());
+        }
+
+        let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+        write_chunk(&mut png, b"IHDR", &header);
+        write_chunk(&mut png, b"IDAT", &zlib_stored(&raw));
+        write_chunk(&mut png, b"IEND", &[]);
+        png
+    }
+
+    /// Writes the image as a PNG or PPM, going by the file's extension.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let bytes = match path.extension().and_then(|e| e.to_str()) {
+            Some("png") => self.to_png(),
+            Some("ppm") => self.to_ppm(),
+            _ => return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("{} should end in .png or .ppm", path.display()))),
+        };
+        fs::write(path, bytes)
+    }
+}
+
+fn write_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend((data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend(kind);
+    png.extend(data);
+    let crc = crc32(&png[start..]);
+    png.extend(crc.to_be_bytes());
+}
+
+/// A zlib stream holding `data` in uncompressed deflate blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    const MAX_BLOCK: usize = 65535;
+
+    // deflate with a 32K window and no preset dictionary, with the check bits making it a multiple of 31
+    let mut stream = vec![0x78, 0x01];
+    let mut blocks = data.chunks(MAX_BLOCK).peekable();
+    if blocks.peek().is_none() {
+        stream.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        stream.push(blocks.peek().is_none() as u8);
+        let length = block.len() as u16;
+        stream.extend(length.to_le_bytes());
+        stream.extend((!length).to_le_bytes());
+        stream.extend(block);
+    }
+    stream.extend(adler32(data).to_be_bytes());
+    stream
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for byte in bytes {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 { (crc >> 1) ^ 0xedb8_8320 } else { crc >> 1 };
+        }
+    }
+    !crc
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    const MOD: u32 = 65521;
+
+    let (mut a, mut b) = (1u32, 0u32);
+    for byte in bytes {
+        a = (a + *byte as u32) % MOD;
+        b = (b + a) % MOD;
+    }
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The data back out of a stream of stored blocks, checking the framing along the way.
+    fn unstore(stream: &[u8]) -> Vec<u8> {
+        assert_eq!(0, u16::from_be_bytes([stream[0], stream[1]]) % 31);
+        let mut data = vec![];
+        let mut at = 2;
+        loop {
+            let last = stream[at] == 1;
+            let length = u16::from_le_bytes([stream[at + 1], stream[at + 2]]);
+            assert_eq!(!length, u16::from_le_bytes([stream[at + 3], stream[at + 4]]));
+            at += 5;
+            data.extend(&stream[at..at + length as usize]);
+            at += length as usize;
+            if last {
+                break;
+            }
+        }
+        assert_eq!(adler32(&data).to_be_bytes(), stream[at..]);
+        data
+    }
+
+    #[test]
+    fn checksums() {
+        assert_eq!(0xae42_6082, crc32(b"IEND"));
+        assert_eq!(0xcbf4_3926, crc32(b"123456789"));
+        assert_eq!(0x11e6_0398, adler32(b"Wikipedia"));
+    }
+
+    #[test]
+    fn palette() {
+        let palette = Palette(&[BLACK, [200, 100, 0], WHITE]);
+
+        assert_eq!(BLACK, palette.at(-1.0));
+        assert_eq!([100, 50, 0], palette.at(0.25));
+        assert_eq!([200, 100, 0], palette.scale(5, 0, 10));
+        assert_eq!(WHITE, palette.at(1.0));
+        assert_eq!(BLACK, palette.scale(3, 3, 3));
+    }
+
+    #[test]
+    fn points() {
+        let points = [(Point::new(-1, 2), BLACK), (Point::new(1, 3), WHITE), (Point::new(-1, 2), WHITE)];
+        let image = Image::from_points(points, BLACK);
+
+        assert_eq!((3, 2), (image.width(), image.height()));
+        assert_eq!(Some(WHITE), image.get(Point::new(0, 0)));
+        assert_eq!(Some(BLACK), image.get(Point::new(1, 0)));
+        assert_eq!(Some(WHITE), image.get(Point::new(2, 1)));
+        assert_eq!(None, image.get(Point::new(3, 1)));
+
+        let scaled = image.scaled(2);
+        assert_eq!((6, 4), (scaled.width(), scaled.height()));
+        assert_eq!(Some(WHITE), scaled.get(Point::new(1, 1)));
+        assert_eq!(Some(BLACK), scaled.get(Point::new(2, 1)));
+        assert_eq!(0, Image::from_points([], BLACK).width());
+    }
+
+    #[test]
+    fn ppm() {
+        let grid = Grid::from_cells(2, vec![0, 1, 2, 3]);
+        let image = Image::from_grid(&grid, |_, n| [*n; 3]);
+
+        assert_eq!(b"P6\n2 2\n255\n\0\0\0\x01\x01\x01\x02\x02\x02\x03\x03\x03".to_vec(), image.to_ppm());
+        assert_eq!(image, Image::from_fn(2, 2, |p| [(p.y * 2 + p.x) as u8; 3]));
+    }
+
+    #[test]
+    fn png() {
+        let mut image = Image::new(2, 1, BLACK);
+        image.set(Point::new(1, 0), [1, 2, 3]);
+        let png = image.to_png();
+
+        assert_eq!(b"\x89PNG\r\n\x1a\n", &png[..8]);
+        assert_eq!(b"\0\0\0\x0dIHDR\0\0\0\x02\0\0\0\x01\x08\x02\0\0\0", &png[8..29]);
+        assert_eq!(b"\0\0\0\0IEND\xae\x42\x60\x82", &png[png.len() - 12..]);
+
+        let idat = &png[33..png.len() - 12];
+        let length = u32::from_be_bytes(idat[..4].try_into().unwrap()) as usize;
+        assert_eq!(b"IDAT", &idat[4..8]);
+        assert_eq!(vec![0, 0, 0, 0, 1, 2, 3], unstore(&idat[8..8 + length]));
+        assert_eq!(crc32(&idat[4..8 + length]).to_be_bytes(), idat[8 + length..]);
+    }
+
+    #[test]
+    fn large() {
+        let data = (0..150_000).map(|i| (i % 251) as u8).collect::<Vec<_>>();
+
+        assert_eq!(data, unstore(&zlib_stored(&data)));
+        assert_eq!(Vec::<u8>::new(), unstore(&zlib_stored(&[])));
+    }
+}
@@ -0,0 +1,159 @@
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Directory holding one `day_XX.txt` file per day, checked before the crate's own `input.txt`.
+pub const INPUTS_DIR_VAR: &str = "AOC_INPUTS";
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound(Vec<PathBuf>),
+    Io(InputSource, io::Error),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::NotFound(searched) => {
+                write!(f, "no puzzle input found - looked for")?;
+                for path in searched {
+                    write!(f, " '{}'", path.display())?;
+                }
+                write!(f, " (pass a path, '-' for stdin, or set {})", INPUTS_DIR_VAR)
+            },
+            InputError::Io(InputSource::Stdin, e) => write!(f, "failed to read puzzle input from stdin: {}", e),
+            InputError::Io(InputSource::File(path), e) => write!(f, "failed to read puzzle input '{}': {}", path.display(), e),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::NotFound(_) => None,
+            InputError::Io(_, e) => Some(e),
+        }
+    }
+}
+
+/// Picks where a day's input comes from, in order of preference:
+/// the path given on the command line (`-` meaning stdin), `day_XX.txt` in the inputs directory,
+/// and finally `input.txt` next to the day's `Cargo.toml`.
+pub fn resolve(path: Option<&str>, inputs_dir: Option<&Path>, day: u8, manifest_dir: &Path) -> Result<InputSource, InputError> {
+    match path {
+        Some("-") => return Ok(InputSource::Stdin),
+        Some(path) => {
+            let path = PathBuf::from(path);
+            return if path.is_file() {
+                Ok(InputSource::File(path))
+            } else {
+                Err(InputError::NotFound(vec![path]))
+            };
+        },
+        None => {},
+    }
+
+    let mut searched = vec![];
+    if let Some(inputs_dir) = inputs_dir {
+        searched.push(inputs_dir.join(format!("day_{:02}.txt", day)));
+    }
+    searched.push(manifest_dir.join("input.txt"));
+
+    match searched.iter().find(|p| p.is_file()) {
+        Some(path) => Ok(InputSource::File(path.clone())),
+        None => Err(InputError::NotFound(searched)),
+    }
+}
+
+pub fn read(source: InputSource) -> Result<String, InputError> {
+    match source {
+        InputSource::Stdin => {
+            let mut input = String::new();
+            match io::stdin().read_to_string(&mut input) {
+                Ok(_) => Ok(input),
+                Err(e) => Err(InputError::Io(InputSource::Stdin, e)),
+            }
+        },
+        InputSource::File(path) => match fs::read_to_string(&path) {
+            Ok(input) => Ok(input),
+            Err(e) => Err(InputError::Io(InputSource::File(path), e)),
+        },
+    }
+}
+
+/// Resolves and reads a day's input, taking the inputs directory from the environment.
+pub fn load(path: Option<&str>, day: u8, manifest_dir: &str) -> Result<String, InputError> {
+    let inputs_dir = env::var_os(INPUTS_DIR_VAR).map(PathBuf::from);
+    let source = resolve(path, inputs_dir.as_deref(), day, Path::new(manifest_dir))?;
+
+    read(source)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scratch(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc_input_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn stdin() {
+        assert_eq!(InputSource::Stdin, resolve(Some("-"), None, 1, Path::new(".")).unwrap());
+    }
+
+    #[test]
+    fn explicit_path() {
+        let dir = scratch("explicit");
+        let file = dir.join("sample.txt");
+        fs::write(&file, "1\n2\n").unwrap();
+
+        let source = resolve(file.to_str(), None, 1, &dir).unwrap();
+        assert_eq!(InputSource::File(file.clone()), source);
+        assert_eq!("1\n2\n", read(source).unwrap());
+
+        let missing = dir.join("missing.txt");
+        match resolve(missing.to_str(), None, 1, &dir) {
+            Err(InputError::NotFound(searched)) => assert_eq!(vec![missing], searched),
+            other => panic!("expected a missing input, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn inputs_dir_before_manifest() {
+        let inputs = scratch("inputs");
+        let manifest = scratch("manifest");
+        fs::write(manifest.join("input.txt"), "crate").unwrap();
+
+        assert_eq!(InputSource::File(manifest.join("input.txt")), resolve(None, Some(&inputs), 9, &manifest).unwrap());
+
+        fs::write(inputs.join("day_09.txt"), "shared").unwrap();
+
+        assert_eq!(InputSource::File(inputs.join("day_09.txt")), resolve(None, Some(&inputs), 9, &manifest).unwrap());
+    }
+
+    #[test]
+    fn nothing_found() {
+        let inputs = scratch("nothing_inputs");
+        let manifest = scratch("nothing_manifest");
+
+        let error = resolve(None, Some(&inputs), 12, &manifest).unwrap_err();
+        match &error {
+            InputError::NotFound(searched) => assert_eq!(vec![inputs.join("day_12.txt"), manifest.join("input.txt")], *searched),
+            other => panic!("expected a missing input, got {:?}", other),
+        }
+        assert!(error.to_string().contains(INPUTS_DIR_VAR));
+    }
+}
@@ -0,0 +1,71 @@
+//! Just enough JSON for the machine-readable reports: objects, arrays, strings and numbers, written
+//! straight to text.
+
+use std::fmt::{self, Display, Write as _};
+
+/// A JSON object, with its fields in the order they're added.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Object {
+    fields: Vec<String>,
+}
+
+impl Object {
+    pub fn new() -> Self {
+        Object::default()
+    }
+
+    /// Adds a field whose value is already JSON, like a number, an [`array`] or another object.
+    pub fn field(mut self, key: &str, value: impl Display) -> Self {
+        self.fields.push(format!("{}:{}", string(key), value));
+        self
+    }
+
+    /// Adds a field holding `value` as a JSON string.
+    pub fn text(self, key: &str, value: &str) -> Self {
+        self.field(key, string(value))
+    }
+}
+
+impl Display for Object {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{{}}}", self.fields.join(","))
+    }
+}
+
+/// The values, already JSON, as a JSON array.
+pub fn array<T: Display>(values: impl IntoIterator<Item = T>) -> String {
+    let values = values.into_iter().map(|v| v.to_string()).collect::<Vec<_>>();
+    format!("[{}]", values.join(","))
+}
+
+/// `value` quoted and escaped as a JSON string.
+pub fn string(value: &str) -> String {
+    let mut escaped = String::from("\"");
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            c if c.is_control() => write!(escaped, "\\u{:04x}", c as u32).unwrap(),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn write() {
+        let object = Object::new()
+            .text("name", "say \"hi\"\n")
+            .field("count", 3)
+            .field("seconds", 0.5)
+            .field("items", array([Object::new().field("a", 1), Object::new()]));
+
+        assert_eq!(r#"{"name":"say \"hi\"\u000a","count":3,"seconds":0.5,"items":[{"a":1},{}]}"#, object.to_string());
+        assert_eq!("[]", array::<u8>([]));
+    }
+}
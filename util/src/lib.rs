@@ -1,3 +1,26 @@
+pub mod answers;
+pub mod bench;
+pub mod cli;
+pub mod fetch;
+pub mod fixtures;
+pub mod geom;
+pub mod grid;
+mod error;
+pub mod image;
+pub mod input;
+pub mod json;
+pub mod rng;
+pub mod round_trip;
+pub mod search;
+mod solution;
 mod timer;
+pub mod viz;
 
-pub use timer::Timer;
\ No newline at end of file
+pub use error::{NoAnswer, ParseError, parse_lines, parse_number, write_lines};
+/// [`fetch`], also at `util::inputs`.
+pub use fetch as inputs;
+pub use geom::{Direction, Point};
+pub use grid::Grid;
+pub use rng::Rng;
+pub use solution::{Answers, Part, Puzzle, Solution};
+pub use timer::{Report, Span, SpanRecord, Timer};
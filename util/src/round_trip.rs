@@ -0,0 +1,71 @@
+use crate::solution::Solution;
+
+/// Checks a day's parser against `input`: malformed input has to come back as an error rather than
+/// a panic, and input that parses has to be the same after it's serialized and parsed again.
+///
+/// Returns whether `input` parsed, or what went wrong with the round trip.
+pub fn check<S: Solution>(input: &str) -> Result<bool, String> {
+    let Ok(parsed) = S::parse(input) else {
+        return Ok(false);
+    };
+    let Some(written) = S::serialize(&parsed) else {
+        return Ok(true);
+    };
+
+    let reparsed = S::parse(&written)
+        .map_err(|e| format!("serialized input doesn't parse, {}:\n{}", e, written))?;
+    let rewritten = S::serialize(&reparsed).unwrap_or_default();
+    if rewritten != written {
+        return Err(format!("serialized input changed after parsing it again:\n{}\nbecame\n{}", written, rewritten));
+    }
+
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Display;
+
+    use super::*;
+    use crate::{NoAnswer, ParseError, parse_number};
+
+    /// Numbers separated by commas, written back out with `separator` between them.
+    struct Numbers<const SEPARATOR: char>;
+
+    impl<const SEPARATOR: char> Solution for Numbers<SEPARATOR> {
+        const DAY: u8 = 25;
+        const MANIFEST_DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+        type Input = Vec<u32>;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            input.split(',').map(|n| parse_number(n, 1, "a number")).collect()
+        }
+
+        fn serialize(input: &Self::Input) -> Option<String> {
+            Some(input.iter().map(|n| n.to_string()).collect::<Vec<_>>().join(&SEPARATOR.to_string()))
+        }
+
+        fn part_1(input: &Self::Input) -> Result<impl Display, NoAnswer> {
+            Ok(input.len())
+        }
+
+        fn part_2(input: &Self::Input) -> Result<impl Display, NoAnswer> {
+            Ok(input.len())
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        assert_eq!(Ok(true), check::<Numbers<','>>("1,02,3"));
+        assert_eq!(Ok(false), check::<Numbers<','>>("1,two,3"));
+    }
+
+    #[test]
+    fn broken_serializer() {
+        let error = check::<Numbers<';'>>("1,2,3").unwrap_err();
+
+        assert!(error.starts_with("serialized input doesn't parse"));
+        assert!(error.ends_with("1;2;3"));
+    }
+}
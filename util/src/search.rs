@@ -0,0 +1,288 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+/// Costs and parents of every node a search reached, starting from one or more sources.
+#[derive(Clone, Debug)]
+pub struct Search<N> {
+    costs: HashMap<N, u64>,
+    parents: HashMap<N, N>,
+    goal: Option<N>,
+}
+
+impl<N: Clone + Eq + Hash> Search<N> {
+    fn new() -> Self {
+        Search { costs: HashMap::new(), parents: HashMap::new(), goal: None }
+    }
+
+    pub fn cost(&self, node: &N) -> Option<u64> {
+        self.costs.get(node).copied()
+    }
+
+    /// The first node that satisfied the goal, which is also the cheapest one.
+    pub fn goal(&self) -> Option<&N> {
+        self.goal.as_ref()
+    }
+
+    pub fn reached(&self) -> impl Iterator<Item = (&N, u64)> + '_ {
+        self.costs.iter().map(|(node, cost)| (node, *cost))
+    }
+
+    /// Nodes from the source the search came from up to and including `to`.
+    pub fn path(&self, to: &N) -> Option<Vec<N>> {
+        if !self.costs.contains_key(to) {
+            return None;
+        }
+
+        let mut path = vec![to.clone()];
+        while let Some(parent) = self.parents.get(path.last().unwrap()) {
+            path.push(parent.clone());
+        }
+        path.reverse();
+
+        Some(path)
+    }
+
+    pub fn goal_path(&self) -> Option<Vec<N>> {
+        self.path(self.goal.as_ref()?)
+    }
+}
+
+/// Breadth first search where every step costs 1, stopping at the first node that satisfies `is_goal`.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Search<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search::new();
+    let mut frontier = VecDeque::new();
+    for start in starts {
+        if !search.costs.contains_key(&start) {
+            search.costs.insert(start.clone(), 0);
+            frontier.push_back(start);
+        }
+    }
+
+    while let Some(current) = frontier.pop_front() {
+        if is_goal(&current) {
+            search.goal = Some(current);
+            break;
+        }
+        let cost = search.costs[&current] + 1;
+        for next in neighbours(&current) {
+            if !search.costs.contains_key(&next) {
+                search.costs.insert(next.clone(), cost);
+                search.parents.insert(next.clone(), current.clone());
+                frontier.push_back(next);
+            }
+        }
+    }
+
+    search
+}
+
+/// Cheapest-first search over `(neighbour, cost)` edges.
+pub fn dijkstra<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Search<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    astar(starts, neighbours, |_| 0, is_goal)
+}
+
+/// Dijkstra guided by a `heuristic` that must never overestimate the remaining cost to a goal.
+pub fn astar<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> u64,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Search<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    let mut search = Search::new();
+    // the heap holds indexes into `nodes` so that nodes don't need to be ordered
+    let mut nodes = vec![];
+    let mut frontier = BinaryHeap::new();
+    for start in starts {
+        if !search.costs.contains_key(&start) {
+            search.costs.insert(start.clone(), 0);
+            frontier.push((Reverse(heuristic(&start)), Reverse(0), nodes.len()));
+            nodes.push(start);
+        }
+    }
+
+    while let Some((_, Reverse(cost), index)) = frontier.pop() {
+        let current = nodes[index].clone();
+        if cost > search.costs[&current] {
+            continue;
+        }
+        if is_goal(&current) {
+            search.goal = Some(current);
+            break;
+        }
+        for (next, step) in neighbours(&current) {
+            let next_cost = cost + step;
+            if search.costs.get(&next).is_none_or(|known| next_cost < *known) {
+                search.costs.insert(next.clone(), next_cost);
+                search.parents.insert(next.clone(), current.clone());
+                frontier.push((Reverse(next_cost + heuristic(&next)), Reverse(next_cost), nodes.len()));
+                nodes.push(next);
+            }
+        }
+    }
+
+    search
+}
+
+/// Shortest path costs between every pair of nodes.
+#[derive(Clone, Debug)]
+pub struct AllPairs<N> {
+    nodes: Vec<N>,
+    index: HashMap<N, usize>,
+    costs: Vec<Option<u64>>,
+}
+
+impl<N: Eq + Hash> AllPairs<N> {
+    pub fn cost(&self, from: &N, to: &N) -> Option<u64> {
+        let from = *self.index.get(from)?;
+        let to = *self.index.get(to)?;
+        self.costs[from * self.nodes.len() + to]
+    }
+
+    /// Every reachable `(from, to, cost)`, including each node to itself.
+    pub fn iter(&self) -> impl Iterator<Item = (&N, &N, u64)> + '_ {
+        let n = self.nodes.len();
+        self.costs.iter()
+            .enumerate()
+            .filter_map(move |(i, cost)| cost.map(|cost| (&self.nodes[i / n], &self.nodes[i % n], cost)))
+    }
+}
+
+/// Floyd-Warshall over the given nodes; edges to nodes outside of them are ignored.
+pub fn floyd_warshall<N, I>(nodes: impl IntoIterator<Item = N>, mut neighbours: impl FnMut(&N) -> I) -> AllPairs<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    let nodes = nodes.into_iter().collect::<Vec<_>>();
+    let index = nodes.iter()
+        .enumerate()
+        .map(|(i, node)| (node.clone(), i))
+        .collect::<HashMap<_, _>>();
+    let n = nodes.len();
+
+    let mut costs = vec![None; n * n];
+    for (from, node) in nodes.iter().enumerate() {
+        costs[from * n + from] = Some(0);
+        for (next, cost) in neighbours(node) {
+            if let Some(to) = index.get(&next) {
+                let edge = &mut costs[from * n + to];
+                *edge = Some(edge.map_or(cost, |known: u64| known.min(cost)));
+            }
+        }
+    }
+
+    for via in 0..n {
+        for from in 0..n {
+            let Some(first) = costs[from * n + via] else {
+                continue;
+            };
+            for to in 0..n {
+                if let Some(second) = costs[via * n + to] {
+                    let cost = &mut costs[from * n + to];
+                    if cost.is_none_or(|known| first + second < known) {
+                        *cost = Some(first + second);
+                    }
+                }
+            }
+        }
+    }
+
+    AllPairs { nodes, index, costs }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a - b - c
+    // |       |
+    // d - - - e   with the a-d-e-c route costing 1 per edge and a-b, b-c costing 5
+    fn weighted(node: &char) -> Vec<(char, u64)> {
+        match node {
+            'a' => vec![('b', 5), ('d', 1)],
+            'b' => vec![('a', 5), ('c', 5)],
+            'c' => vec![('b', 5), ('e', 1)],
+            'd' => vec![('a', 1), ('e', 1)],
+            'e' => vec![('d', 1), ('c', 1)],
+            _ => vec![],
+        }
+    }
+
+    fn unweighted(node: &char) -> Vec<char> {
+        weighted(node).into_iter().map(|(n, _)| n).collect()
+    }
+
+    #[test]
+    fn breadth_first() {
+        let search = bfs(['a'], unweighted, |n| *n == 'c');
+
+        assert_eq!(Some(&'c'), search.goal());
+        assert_eq!(Some(2), search.cost(&'c'));
+        assert_eq!(Some(vec!['a', 'b', 'c']), search.goal_path());
+
+        let search = bfs(['a'], unweighted, |_| false);
+        assert_eq!(5, search.reached().count());
+        assert_eq!(None, search.goal());
+        assert_eq!(None, bfs(['a'], unweighted, |_| false).path(&'z'));
+    }
+
+    #[test]
+    fn cheapest_first() {
+        let search = dijkstra(['a'], weighted, |n| *n == 'c');
+
+        assert_eq!(Some(3), search.cost(&'c'));
+        assert_eq!(Some(vec!['a', 'd', 'e', 'c']), search.goal_path());
+
+        let search = astar(['a'], weighted, |n| if *n == 'c' { 0 } else { 1 }, |n| *n == 'c');
+        assert_eq!(Some(vec!['a', 'd', 'e', 'c']), search.goal_path());
+    }
+
+    #[test]
+    fn multi_source() {
+        let search = bfs(['b', 'e'], unweighted, |n| *n == 'a');
+
+        assert_eq!(Some(1), search.cost(&'a'));
+        assert_eq!(Some(0), search.cost(&'e'));
+        assert_eq!(Some(vec!['b', 'a']), search.goal_path());
+
+        let search = dijkstra(['b', 'e'], weighted, |_| false);
+        assert_eq!(Some(2), search.cost(&'a'));
+        assert_eq!(Some(vec!['e', 'd', 'a']), search.path(&'a'));
+    }
+
+    #[test]
+    fn all_pairs() {
+        let paths = floyd_warshall(['a', 'b', 'c', 'd', 'e'], weighted);
+
+        assert_eq!(Some(3), paths.cost(&'a', &'c'));
+        assert_eq!(Some(6), paths.cost(&'b', &'d'));
+        assert_eq!(Some(0), paths.cost(&'e', &'e'));
+        assert_eq!(None, paths.cost(&'a', &'z'));
+        assert_eq!(25, paths.iter().count());
+
+        let disconnected = floyd_warshall(['a', 'c'], weighted);
+        assert_eq!(None, disconnected.cost(&'a', &'c'));
+        assert_eq!(2, disconnected.iter().count());
+    }
+}
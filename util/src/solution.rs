@@ -0,0 +1,295 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+
+use clap::ValueEnum;
+
+use crate::bench::{BenchOptions, Measurement};
+use crate::error::{NoAnswer, ParseError};
+use crate::image::Image;
+use crate::rng::Rng;
+use crate::timer::Timer;
+use crate::viz::Viewer;
+
+pub trait Solution {
+    const DAY: u8;
+    /// Directory of the day's crate, where its `input.txt` is found by default.
+    const MANIFEST_DIR: &'static str;
+
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    /// Fails for inputs that parse but can't be answered, like finding the biggest of no numbers.
+    fn part_1(input: &Self::Input) -> Result<impl Display, NoAnswer>;
+    fn part_2(input: &Self::Input) -> Result<impl Display, NoAnswer>;
+
+    /// A random but valid puzzle input, roughly `scale` items of whatever the input is a list of.
+    /// Days without a generator can't be stress tested.
+    fn generate(_rng: &mut Rng, _scale: usize) -> Option<String> {
+        None
+    }
+
+    /// Writes a parsed input back out in the puzzle's format, so that parsing it again gives the same input.
+    fn serialize(_input: &Self::Input) -> Option<String> {
+        None
+    }
+
+    /// Plays the day's simulation on `viewer` a frame at a time, returning false if it has none to watch.
+    fn animate(_input: &Self::Input, _viewer: &mut Viewer) -> bool {
+        false
+    }
+
+    /// A picture of the day's result, for when it's too big to make sense of as text.
+    fn image(_input: &Self::Input) -> Option<Image> {
+        None
+    }
+
+    fn solve(input: &Self::Input, part: Part) -> Result<String, NoAnswer> {
+        match part {
+            Part::One => Self::part_1(input).map(|answer| answer.to_string()),
+            Part::Two => Self::part_2(input).map(|answer| answer.to_string()),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, ValueEnum)]
+pub enum Part {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+}
+
+impl Part {
+    pub fn all() -> [Part; 2] {
+        [Part::One, Part::Two]
+    }
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+pub type Answers = Vec<(Part, String)>;
+
+type SolveFn = fn(&str, &[Part], &Timer) -> Result<Answers, Box<dyn Error>>;
+type BenchFn = fn(&str, &[Part], &BenchOptions) -> Result<Vec<Measurement>, ParseError>;
+type GenerateFn = fn(&mut Rng, usize) -> Option<String>;
+type AnimateFn = fn(&str, &mut Viewer) -> Result<bool, ParseError>;
+type ImageFn = fn(&str) -> Result<Option<Image>, ParseError>;
+
+#[derive(Clone, Copy)]
+pub struct Puzzle {
+    day: u8,
+    manifest_dir: &'static str,
+    solve: SolveFn,
+    bench: BenchFn,
+    generate: GenerateFn,
+    animate: AnimateFn,
+    image: ImageFn,
+}
+
+impl Puzzle {
+    pub fn of<S: Solution>() -> Self {
+        Puzzle {
+            day: S::DAY,
+            manifest_dir: S::MANIFEST_DIR,
+            solve: solve::<S>,
+            bench: bench::<S>,
+            generate: S::generate,
+            animate: animate::<S>,
+            image: image::<S>,
+        }
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    pub fn manifest_dir(&self) -> &'static str {
+        self.manifest_dir
+    }
+
+    /// Parses the input once and solves each of the requested parts against it,
+    /// timing the `parse` and `part N` phases as spans of `timer`.
+    pub fn solve(&self, input: &str, parts: &[Part], timer: &Timer) -> Result<Answers, Box<dyn Error>> {
+        (self.solve)(input, parts, timer)
+    }
+
+    /// Repeatedly times parsing and each of the requested parts, named `day XX/parse` and `day XX/part N`.
+    pub fn bench(&self, input: &str, parts: &[Part], options: &BenchOptions) -> Result<Vec<Measurement>, ParseError> {
+        (self.bench)(input, parts, options)
+    }
+
+    /// Generates an input from `seed`, or `None` if the day has no generator.
+    pub fn generate(&self, seed: u64, scale: usize) -> Option<String> {
+        (self.generate)(&mut Rng::new(seed), scale)
+    }
+
+    /// Parses the input and plays the day's simulation on `viewer`, returning false if it has none.
+    pub fn animate(&self, input: &str, viewer: &mut Viewer) -> Result<bool, ParseError> {
+        (self.animate)(input, viewer)
+    }
+
+    /// Parses the input and draws the day's result, or `None` if it has no picture.
+    pub fn image(&self, input: &str) -> Result<Option<Image>, ParseError> {
+        (self.image)(input)
+    }
+}
+
+fn solve<S: Solution>(input: &str, parts: &[Part], timer: &Timer) -> Result<Answers, Box<dyn Error>> {
+    let parsed = timer.time("parse", || S::parse(input))?;
+
+    let mut answers = vec![];
+    for part in parts {
+        let answer = timer.time(&format!("part {}", part), || S::solve(&parsed, *part))?;
+        answers.push((*part, answer));
+    }
+
+    Ok(answers)
+}
+
+fn animate<S: Solution>(input: &str, viewer: &mut Viewer) -> Result<bool, ParseError> {
+    let parsed = S::parse(input)?;
+
+    Ok(S::animate(&parsed, viewer))
+}
+
+fn image<S: Solution>(input: &str) -> Result<Option<Image>, ParseError> {
+    let parsed = S::parse(input)?;
+
+    Ok(S::image(&parsed))
+}
+
+fn bench<S: Solution>(input: &str, parts: &[Part], options: &BenchOptions) -> Result<Vec<Measurement>, ParseError> {
+    let parsed = S::parse(input)?;
+
+    let mut measurements = vec![Measurement {
+        name: format!("day {:02}/parse", S::DAY),
+        stats: options.run(|| S::parse(input)),
+    }];
+    for part in parts {
+        measurements.push(Measurement {
+            name: format!("day {:02}/part {}", S::DAY, part),
+            stats: options.run(|| S::solve(&parsed, *part)),
+        });
+    }
+
+    Ok(measurements)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::timer::Report;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 25;
+        const MANIFEST_DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+        type Input = Vec<u32>;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            input.split(',').filter(|n| !n.is_empty()).map(|n| crate::parse_number(n, 1, "a number")).collect()
+        }
+
+        fn part_1(input: &Self::Input) -> Result<impl Display, NoAnswer> {
+            Ok(input.iter().sum::<u32>())
+        }
+
+        fn part_2(input: &Self::Input) -> Result<impl Display, NoAnswer> {
+            input.iter().max().map(|m| format!("max {}", m)).ok_or_else(|| NoAnswer::new("no numbers"))
+        }
+
+        fn generate(rng: &mut Rng, scale: usize) -> Option<String> {
+            let numbers = (0..scale).map(|_| rng.below(100).to_string()).collect::<Vec<_>>();
+            Some(numbers.join(","))
+        }
+
+        fn serialize(input: &Self::Input) -> Option<String> {
+            Some(input.iter().map(|n| n.to_string()).collect::<Vec<_>>().join(","))
+        }
+    }
+
+    #[test]
+    fn dispatch() {
+        let puzzle = Puzzle::of::<Sum>();
+        let timer = Timer::with_report(Report::Quiet);
+
+        assert_eq!(25, puzzle.day());
+        assert_eq!(
+            vec![(Part::One, "6".to_string()), (Part::Two, "max 3".to_string())],
+            puzzle.solve("1,2,3", &Part::all(), &timer).unwrap()
+        );
+        assert_eq!(vec![(Part::Two, "max 7".to_string())], puzzle.solve("7", &[Part::Two], &timer).unwrap());
+
+        let spans = timer.records().into_iter().map(|s| s.name).collect::<Vec<_>>();
+        assert_eq!(vec!["parse", "part 1", "part 2", "parse", "part 2"], spans);
+    }
+
+    #[test]
+    fn parse_failure() {
+        let puzzle = Puzzle::of::<Sum>();
+
+        assert!(puzzle.solve("1,two,3", &Part::all(), &Timer::with_report(Report::Quiet)).is_err());
+        assert!(puzzle.bench("1,two,3", &Part::all(), &BenchOptions::default()).is_err());
+    }
+
+    #[test]
+    fn no_answer() {
+        let puzzle = Puzzle::of::<Sum>();
+        let timer = Timer::with_report(Report::Quiet);
+
+        assert_eq!(vec![(Part::One, "0".to_string())], puzzle.solve("", &[Part::One], &timer).unwrap());
+        assert_eq!("no answer: no numbers", puzzle.solve("", &Part::all(), &timer).unwrap_err().to_string());
+    }
+
+    #[test]
+    fn generate() {
+        let puzzle = Puzzle::of::<Sum>();
+        let input = puzzle.generate(3, 5).unwrap();
+
+        assert_eq!(5, input.split(',').count());
+        assert_eq!(Some(input), puzzle.generate(3, 5));
+        assert!(puzzle.solve(&puzzle.generate(4, 5).unwrap(), &Part::all(), &Timer::with_report(Report::Quiet)).is_ok());
+    }
+
+    #[test]
+    fn animate() {
+        let puzzle = Puzzle::of::<Sum>();
+        let mut viewer = Viewer::offscreen();
+
+        assert_eq!(Ok(false), puzzle.animate("1,2,3", &mut viewer));
+        assert!(puzzle.animate("1,two,3", &mut viewer).is_err());
+        assert_eq!(0, viewer.frames_shown());
+    }
+
+    #[test]
+    fn image() {
+        let puzzle = Puzzle::of::<Sum>();
+
+        assert_eq!(Ok(None), puzzle.image("1,2,3"));
+        assert!(puzzle.image("1,two,3").is_err());
+    }
+
+    #[test]
+    fn bench() {
+        let puzzle = Puzzle::of::<Sum>();
+        let options = BenchOptions { warmup: 0, iterations: 3 };
+
+        let measurements = puzzle.bench("1,2,3", &[Part::Two], &options).unwrap();
+        let names = measurements.iter().map(|m| m.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(vec!["day 25/parse", "day 25/part 2"], names);
+        assert!(measurements.iter().all(|m| m.stats.samples == 3));
+    }
+}
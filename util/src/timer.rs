@@ -1,23 +1,265 @@
-use std::time::{Instant};
+use std::cell::RefCell;
+use std::fmt::Write as _;
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use clap::ValueEnum;
+
+use crate::json::{self, Object};
+
+/// What a [`Timer`] prints to stderr when it is dropped, keeping stdout for the answers.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum Report {
+    Quiet,
+    #[default]
+    Table,
+    Json,
+    Csv,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SpanRecord {
+    pub name: String,
+    /// Names of the enclosing spans and this one, joined by '/'
+    pub path: String,
+    pub depth: usize,
+    pub elapsed: Duration,
+}
 
 pub struct Timer {
     start: Instant,
+    report: Report,
+    spans: RefCell<Vec<SpanRecord>>,
+    open: RefCell<Vec<usize>>,
+}
+
+/// Measures a named span until it is dropped; spans opened while it is alive are nested under it.
+pub struct Span<'a> {
+    timer: &'a Timer,
+    index: usize,
+    start: Instant,
 }
 
 impl Timer {
     pub fn new() -> Self {
-        Timer { start: Instant::now() }
+        Timer::with_report(Report::Table)
+    }
+
+    pub fn with_report(report: Report) -> Self {
+        Timer {
+            start: Instant::now(),
+            report,
+            spans: RefCell::new(vec![]),
+            open: RefCell::new(vec![]),
+        }
+    }
+
+    pub fn span(&self, name: &str) -> Span<'_> {
+        let mut spans = self.spans.borrow_mut();
+        let mut open = self.open.borrow_mut();
+
+        let path = match open.last() {
+            Some(parent) => format!("{}/{}", spans[*parent].path, name),
+            None => name.to_string(),
+        };
+        let index = spans.len();
+        spans.push(SpanRecord { name: name.to_string(), path, depth: open.len(), elapsed: Duration::ZERO });
+        open.push(index);
+
+        Span { timer: self, index, start: Instant::now() }
+    }
+
+    pub fn time<T>(&self, name: &str, f: impl FnOnce() -> T) -> T {
+        let _span = self.span(name);
+        f()
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.start.elapsed()
+    }
+
+    /// Completed and still open spans, in the order they were started.
+    pub fn records(&self) -> Vec<SpanRecord> {
+        self.spans.borrow().clone()
+    }
+
+    pub fn summary(&self) -> String {
+        let spans = self.spans.borrow();
+        let width = spans.iter()
+            .map(|s| s.depth * 2 + s.name.len())
+            .max()
+            .unwrap_or(0)
+            .max("span".len());
+
+        let mut table = String::new();
+        if !spans.is_empty() {
+            writeln!(table, "{:<width$}  {:>12}", "span", "seconds").unwrap();
+            for span in spans.iter() {
+                let name = format!("{}{}", "  ".repeat(span.depth), span.name);
+                writeln!(table, "{:<width$}  {:>12.6}", name, span.elapsed.as_secs_f64()).unwrap();
+            }
+        }
+        write!(table, "Completed in {} seconds", self.elapsed().as_secs_f64()).unwrap();
+
+        table
+    }
+
+    pub fn to_json(&self) -> String {
+        let spans = self.spans.borrow()
+            .iter()
+            .map(|s| Object::new()
+                .text("name", &s.name)
+                .text("path", &s.path)
+                .field("depth", s.depth)
+                .field("seconds", s.elapsed.as_secs_f64()))
+            .collect::<Vec<_>>();
+
+        Object::new()
+            .field("timestamp", timestamp())
+            .field("total_seconds", self.elapsed().as_secs_f64())
+            .field("spans", json::array(spans))
+            .to_string()
+    }
+
+    pub const CSV_HEADER: &'static str = "timestamp,path,depth,seconds";
+
+    /// One row per span plus a `total` row, without the header.
+    pub fn to_csv_rows(&self) -> String {
+        let timestamp = timestamp();
+        let mut rows = String::new();
+        for span in self.spans.borrow().iter() {
+            writeln!(rows, "{},{},{},{}", timestamp, csv_field(&span.path), span.depth, span.elapsed.as_secs_f64()).unwrap();
+        }
+        writeln!(rows, "{},total,0,{}", timestamp, self.elapsed().as_secs_f64()).unwrap();
+
+        rows
+    }
+
+    pub fn render(&self, report: Report) -> Option<String> {
+        match report {
+            Report::Quiet => None,
+            Report::Table => Some(self.summary()),
+            Report::Json => Some(self.to_json()),
+            Report::Csv => Some(format!("{}\n{}", Timer::CSV_HEADER, self.to_csv_rows().trim_end())),
+        }
+    }
+
+    /// Writes the timings to a file, appending CSV rows so a history builds up across runs.
+    pub fn write_report(&self, report: Report, path: &Path) -> io::Result<()> {
+        match report {
+            Report::Csv => {
+                let new_file = !path.exists();
+                let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+                if new_file {
+                    writeln!(file, "{}", Timer::CSV_HEADER)?;
+                }
+                write!(file, "{}", self.to_csv_rows())
+            },
+            report => match self.render(report) {
+                Some(output) => std::fs::write(path, output + "\n"),
+                None => Ok(()),
+            },
+        }
     }
 }
 
 impl Default for Timer {
-	fn default() -> Self {
-		Self::new()
-	}
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Drop for Timer {
     fn drop(&mut self) {
-        println!("Completed in {} seconds", self.start.elapsed().as_secs_f64());
+        if let Some(output) = self.render(self.report) {
+            eprintln!("{}", output);
+        }
+    }
+}
+
+impl Drop for Span<'_> {
+    fn drop(&mut self) {
+        let mut spans = self.timer.spans.borrow_mut();
+        spans[self.index].elapsed = self.start.elapsed();
+
+        let mut open = self.timer.open.borrow_mut();
+        if let Some(position) = open.iter().rposition(|i| *i == self.index) {
+            open.truncate(position);
+        }
+    }
+}
+
+fn timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nested_spans() {
+        let timer = Timer::with_report(Report::Quiet);
+        {
+            let _day = timer.span("day 09");
+            timer.time("parse", || ());
+            {
+                let _part = timer.span("part 1");
+                timer.time("simulate", || ());
+            }
+        }
+        timer.time("after", || ());
+
+        let paths = timer.records().into_iter().map(|s| (s.path, s.depth)).collect::<Vec<_>>();
+        assert_eq!(vec![
+            ("day 09".to_string(), 0),
+            ("day 09/parse".to_string(), 1),
+            ("day 09/part 1".to_string(), 1),
+            ("day 09/part 1/simulate".to_string(), 2),
+            ("after".to_string(), 0),
+        ], paths);
+    }
+
+    #[test]
+    fn reports() {
+        let timer = Timer::with_report(Report::Quiet);
+        timer.time("say \"hi\", twice", || ());
+
+        let summary = timer.summary();
+        assert!(summary.starts_with("span"));
+        assert!(summary.contains("say \"hi\", twice"));
+        assert!(summary.ends_with("seconds"));
+
+        let json = timer.to_json();
+        assert!(json.contains("\"path\":\"say \\\"hi\\\", twice\""));
+        assert!(json.contains("\"depth\":0"));
+
+        let csv = timer.render(Report::Csv).unwrap();
+        let lines = csv.lines().collect::<Vec<_>>();
+        assert_eq!(Timer::CSV_HEADER, lines[0]);
+        assert!(lines[1].contains(",\"say \"\"hi\"\", twice\",0,"));
+        assert!(lines[2].contains(",total,0,"));
+
+        assert_eq!(None, timer.render(Report::Quiet));
+    }
+
+    #[test]
+    fn no_spans() {
+        let timer = Timer::with_report(Report::Quiet);
+
+        assert!(timer.summary().starts_with("Completed in"));
+    }
+}
@@ -0,0 +1,361 @@
+//! Drawing a simulation in the terminal one frame at a time, so a day can be watched as it runs.
+//!
+//! Days build a [`Frame`] at each step worth seeing and hand it to a [`Viewer`], which clears the
+//! screen, draws it and waits for the next one according to the frame rate, or for a key while paused.
+
+use std::io::{self, BufRead, Write};
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::thread;
+use std::time::Duration;
+
+use crate::geom::Point;
+use crate::grid::Grid;
+
+const CLEAR_SCREEN: &str = "\x1b[H\x1b[2J";
+const RESET: &str = "\x1b[0m";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Colour {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Grey,
+    Rgb(u8, u8, u8),
+}
+
+impl Colour {
+    /// The ANSI escape that switches the text to this colour.
+    fn escape(&self) -> String {
+        let code = match self {
+            Colour::Red => "31",
+            Colour::Green => "32",
+            Colour::Yellow => "33",
+            Colour::Blue => "34",
+            Colour::Magenta => "35",
+            Colour::Cyan => "36",
+            Colour::White => "97",
+            Colour::Grey => "90",
+            Colour::Rgb(r, g, b) => return format!("\x1b[38;2;{};{};{}m", r, g, b),
+        };
+        format!("\x1b[{}m", code)
+    }
+}
+
+/// A character in a frame, in the terminal's own colour unless it has one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cell {
+    pub c: char,
+    pub colour: Option<Colour>,
+}
+
+impl Cell {
+    pub fn new(c: char, colour: Colour) -> Self {
+        Cell { c, colour: Some(colour) }
+    }
+}
+
+impl From<char> for Cell {
+    fn from(c: char) -> Self {
+        Cell { c, colour: None }
+    }
+}
+
+/// One picture of a simulation: rows of cells with a caption underneath.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Frame {
+    rows: Vec<Vec<Cell>>,
+    caption: String,
+}
+
+impl Frame {
+    pub fn new() -> Self {
+        Frame::default()
+    }
+
+    pub fn from_grid<T>(grid: &Grid<T>, mut cell: impl FnMut(Point, &T) -> Cell) -> Self {
+        let mut frame = Frame::new();
+        for y in 0..grid.height() {
+            frame.push_row((0..grid.width()).map(|x| {
+                let point = Point::new(x as i64, y as i64);
+                cell(point, &grid[point])
+            }));
+        }
+        frame
+    }
+
+    /// The `width` by `height` part of the plane whose top left corner is `top_left`, for
+    /// simulations that don't keep to a grid.
+    pub fn window(top_left: Point, width: usize, height: usize, mut cell: impl FnMut(Point) -> Cell) -> Self {
+        let mut frame = Frame::new();
+        for y in 0..height as i64 {
+            frame.push_row((0..width as i64).map(|x| cell(top_left + Point::new(x, y))));
+        }
+        frame
+    }
+
+    pub fn push_row<C: Into<Cell>>(&mut self, row: impl IntoIterator<Item = C>) {
+        self.rows.push(row.into_iter().map(Into::into).collect());
+    }
+
+    /// Adds a row for each line of `text`.
+    pub fn push_text(&mut self, text: &str, colour: Option<Colour>) {
+        for line in text.lines() {
+            self.push_row(line.chars().map(|c| Cell { c, colour }));
+        }
+    }
+
+    pub fn with_caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = caption.into();
+        self
+    }
+
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// The frame as lines of text, coloured with ANSI escapes when `ansi` is set.
+    pub fn render(&self, ansi: bool) -> String {
+        let mut text = String::new();
+        for row in &self.rows {
+            let mut current = None;
+            for cell in row {
+                if ansi && cell.colour != current {
+                    text += cell.colour.map_or(RESET.to_string(), |c| c.escape()).as_str();
+                    current = cell.colour;
+                }
+                text.push(cell.c);
+            }
+            if current.is_some() {
+                text += RESET;
+            }
+            text.push('\n');
+        }
+        if !self.caption.is_empty() {
+            text += &self.caption;
+            text.push('\n');
+        }
+        text
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct VizOptions {
+    /// Frames shown each second while playing, as fast as possible when 0
+    pub fps: f64,
+    /// Start paused on the first frame
+    pub paused: bool,
+    /// Clear the screen between frames and draw colours, rather than printing plain frames one after another
+    pub ansi: bool,
+}
+
+impl Default for VizOptions {
+    fn default() -> Self {
+        VizOptions { fps: 10.0, paused: false, ansi: true }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Playback {
+    Playing,
+    Paused,
+    /// Quit; any frames shown after this are skipped
+    Stopped,
+}
+
+/// Shows frames one after another, taking commands a line at a time: while playing, Enter pauses;
+/// while paused, Enter steps to the next frame, `p` plays again and `q` quits.
+pub struct Viewer {
+    options: VizOptions,
+    out: Box<dyn Write>,
+    commands: Receiver<String>,
+    playback: Playback,
+    shown: usize,
+}
+
+impl Viewer {
+    pub fn new(options: VizOptions, out: Box<dyn Write>, commands: Receiver<String>) -> Self {
+        let playback = if options.paused { Playback::Paused } else { Playback::Playing };
+        Viewer { options, out, commands, playback, shown: 0 }
+    }
+
+    /// Draws to stdout and reads commands from stdin.
+    pub fn terminal(options: VizOptions) -> Self {
+        let (sender, commands) = mpsc::channel();
+        thread::spawn(move || {
+            for line in io::stdin().lock().lines().map_while(Result::ok) {
+                if sender.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+        Viewer::new(options, Box::new(io::stdout()), commands)
+    }
+
+    /// Draws nowhere, as fast as it can, for running a simulation's frames without watching them.
+    pub fn offscreen() -> Self {
+        let (_, commands) = mpsc::channel();
+        Viewer::new(VizOptions { fps: 0.0, paused: false, ansi: false }, Box::new(io::sink()), commands)
+    }
+
+    pub fn playback(&self) -> Playback {
+        self.playback
+    }
+
+    pub fn frames_shown(&self) -> usize {
+        self.shown
+    }
+
+    /// Draws the frame, then waits until it's time for the next one.
+    pub fn show(&mut self, frame: &Frame) {
+        self.take_commands();
+        if self.playback == Playback::Stopped {
+            return;
+        }
+
+        self.shown += 1;
+        let mut text = String::new();
+        if self.options.ansi {
+            text += CLEAR_SCREEN;
+        }
+        text += &frame.render(self.options.ansi);
+        if self.playback == Playback::Paused {
+            text += "paused - Enter steps, p plays, q quits\n";
+        }
+        if !self.options.ansi {
+            text.push('\n');
+        }
+        // a closed terminal just means nobody is watching any more
+        if self.out.write_all(text.as_bytes()).and_then(|_| self.out.flush()).is_err() {
+            self.playback = Playback::Stopped;
+        }
+
+        match self.playback {
+            Playback::Playing if self.options.fps > 0.0 => thread::sleep(Duration::from_secs_f64(1.0 / self.options.fps)),
+            Playback::Paused => self.wait(),
+            _ => {},
+        }
+    }
+
+    /// Commands typed while playing, up to the first one that pauses or quits.
+    fn take_commands(&mut self) {
+        while self.playback == Playback::Playing {
+            match self.commands.try_recv() {
+                Ok(command) => self.command(&command, Playback::Paused),
+                Err(TryRecvError::Empty | TryRecvError::Disconnected) => break,
+            }
+        }
+    }
+
+    /// Blocks while paused until told to step, play or quit.
+    fn wait(&mut self) {
+        match self.commands.recv() {
+            Ok(command) => self.command(&command, Playback::Paused),
+            // nothing left to read commands from, so carry on by itself
+            Err(_) => self.playback = Playback::Playing,
+        }
+    }
+
+    fn command(&mut self, command: &str, otherwise: Playback) {
+        self.playback = match command.trim() {
+            "q" => Playback::Stopped,
+            "p" => Playback::Playing,
+            _ => otherwise,
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::*;
+
+    /// Output the test can still read after giving it to a viewer.
+    #[derive(Clone, Default)]
+    struct Screen(Rc<RefCell<Vec<u8>>>);
+
+    impl Write for Screen {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.borrow_mut().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl Screen {
+        fn text(&self) -> String {
+            String::from_utf8(self.0.borrow().clone()).unwrap()
+        }
+    }
+
+    fn viewer(options: VizOptions, commands: &[&str]) -> (Viewer, Screen) {
+        let (sender, receiver) = mpsc::channel();
+        for command in commands {
+            sender.send(command.to_string()).unwrap();
+        }
+        let screen = Screen::default();
+        (Viewer::new(options, Box::new(screen.clone()), receiver), screen)
+    }
+
+    #[test]
+    fn render() {
+        let mut frame = Frame::new().with_caption("step 1");
+        frame.push_row(['a', 'b']);
+        frame.push_row([Cell::new('c', Colour::Red), 'd'.into()]);
+
+        assert_eq!("ab\ncd\nstep 1\n", frame.render(false));
+        assert_eq!("ab\n\x1b[31mc\x1b[0md\nstep 1\n", frame.render(true));
+        assert_eq!("\x1b[38;2;1;2;3mx\x1b[0m\n", Frame::window(Point::ORIGIN, 1, 1, |_| Cell::new('x', Colour::Rgb(1, 2, 3))).render(true));
+    }
+
+    #[test]
+    fn window() {
+        let frame = Frame::window(Point::new(-1, -1), 3, 2, |p| if p == Point::ORIGIN { '#' } else { '.' }.into());
+
+        assert_eq!("...\n.#.\n", frame.render(false));
+    }
+
+    #[test]
+    fn plays() {
+        let options = VizOptions { fps: 0.0, paused: false, ansi: false };
+        let (mut viewer, screen) = viewer(options, &[]);
+
+        viewer.show(&Frame::new().with_caption("one"));
+        viewer.show(&Frame::new().with_caption("two"));
+
+        assert_eq!(2, viewer.frames_shown());
+        assert_eq!("one\n\ntwo\n\n", screen.text());
+    }
+
+    #[test]
+    fn steps() {
+        let options = VizOptions { fps: 0.0, paused: true, ansi: true };
+        let (mut viewer, screen) = viewer(options, &["", "q"]);
+
+        for caption in ["one", "two", "three"] {
+            viewer.show(&Frame::new().with_caption(caption));
+        }
+
+        assert_eq!(Playback::Stopped, viewer.playback());
+        assert_eq!(2, viewer.frames_shown());
+        assert_eq!(2, screen.text().matches("paused").count());
+        assert!(!screen.text().contains("three"));
+    }
+
+    #[test]
+    fn pauses() {
+        let options = VizOptions { fps: 0.0, paused: false, ansi: false };
+        let (mut viewer, screen) = viewer(options, &["", "p"]);
+
+        viewer.show(&Frame::new().with_caption("one"));
+        assert_eq!(Playback::Playing, viewer.playback());
+        assert!(screen.text().contains("paused"));
+    }
+}